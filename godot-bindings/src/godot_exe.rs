@@ -20,6 +20,7 @@ use std::process::{Command, Output};
 // OUT_DIR would be standing to reason, but it's an unspecified path that cannot be referenced by CI.
 // const GODOT_VERSION_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/gen/godot_version.txt");
 const JSON_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/gen/extension_api.json");
+const DOCS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/gen/doc");
 
 pub fn load_gdextension_json(watch: &mut StopWatch) -> String {
     let json_path = Path::new(JSON_PATH);
@@ -48,6 +49,34 @@ pub fn load_gdextension_json(watch: &mut StopWatch) -> String {
     result
 }
 
+/// Dumps the XML class reference of the Godot binary, returning the directory that contains it.
+pub fn dump_class_docs(watch: &mut StopWatch) -> PathBuf {
+    let docs_path = Path::new(DOCS_PATH);
+
+    let godot_bin = locate_godot_binary();
+    rerun_on_changed(&godot_bin);
+    watch.record("locate_godot");
+
+    fs::create_dir_all(docs_path)
+        .unwrap_or_else(|_| panic!("create directory '{}'", docs_path.display()));
+    println!(
+        "Dump Godot class reference to dir '{}'...",
+        docs_path.display()
+    );
+
+    // Writes XML files to `doc/classes` (and module-specific directories) below the given path.
+    let mut cmd = Command::new(&godot_bin);
+    cmd.current_dir(docs_path)
+        .arg("--headless")
+        .arg("--doctool")
+        .arg(docs_path);
+
+    execute(cmd, "dump Godot class reference");
+    watch.record("dump_class_docs");
+
+    docs_path.to_path_buf()
+}
+
 pub fn write_gdextension_headers(
     inout_h_path: &Path,
     out_rs_path: &Path,
@@ -6,7 +6,7 @@
 
 pub(crate) mod watch;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub use watch::StopWatch;
 
@@ -69,6 +69,10 @@ mod custom {
     pub(crate) fn get_godot_version() -> GodotVersion {
         godot_exe::read_godot_version(&godot_exe::locate_godot_binary())
     }
+
+    pub(crate) fn dump_class_docs(watch: &mut StopWatch) -> PathBuf {
+        godot_exe::dump_class_docs(watch)
+    }
 }
 
 #[cfg(feature = "custom-godot")]
@@ -131,6 +135,50 @@ pub fn clear_dir(dir: &Path, watch: &mut StopWatch) {
     std::fs::create_dir_all(dir).unwrap_or_else(|e| panic!("failed to create dir: {e}"));
 }
 
+/// Returns the directory containing Godot's XML class reference, if configured.
+///
+/// `GODOT4_DOCS_DIR` can point to any directory with class XML files, e.g. `doc/classes` in a Godot source checkout.
+/// With the `custom-godot` feature, setting `GODOT4_DOCTOOL` instead dumps the class reference of the Godot binary via `--doctool`.
+/// The class reference is optional; without it, generated APIs are documented with links to the online docs only.
+pub fn locate_class_docs(_watch: &mut StopWatch) -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed=GODOT4_DOCS_DIR");
+    if let Ok(dir) = std::env::var("GODOT4_DOCS_DIR") {
+        println!("Found GODOT4_DOCS_DIR with path to class reference: '{dir}'");
+        println!("cargo:rerun-if-changed={dir}");
+        return Some(PathBuf::from(dir));
+    }
+
+    #[cfg(feature = "custom-godot")]
+    {
+        println!("cargo:rerun-if-env-changed=GODOT4_DOCTOOL");
+        if std::env::var("GODOT4_DOCTOOL").is_ok() {
+            return Some(dump_class_docs(_watch));
+        }
+    }
+
+    None
+}
+
+/// Returns the engine classes to generate, if restricted by the user through `GODOT4_CODEGEN_CLASSES`.
+///
+/// Entries are separated by commas or whitespace; they are not validated here. `None` means no restriction.
+pub fn selected_codegen_classes() -> Option<Vec<String>> {
+    println!("cargo:rerun-if-env-changed=GODOT4_CODEGEN_CLASSES");
+    let list = std::env::var("GODOT4_CODEGEN_CLASSES").ok()?;
+
+    let classes: Vec<String> = list
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|entry| !entry.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    println!(
+        "Found GODOT4_CODEGEN_CLASSES with {} entries",
+        classes.len()
+    );
+    Some(classes)
+}
+
 pub fn emit_godot_version_cfg() {
     let GodotVersion {
         major,
@@ -140,7 +188,7 @@ pub fn emit_godot_version_cfg() {
     } = get_godot_version();
 
     // Start at 1; checking for "since/before 4.0" makes no sense
-    let max = 2;
+    let max = 3;
     for m in 1..=minor {
         println!(r#"cargo:rustc-cfg=since_api="{major}.{m}""#);
     }
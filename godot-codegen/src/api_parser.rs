@@ -71,8 +71,8 @@ pub struct Class {
     pub constants: Option<Vec<ClassConstant>>,
     pub enums: Option<Vec<Enum>>,
     pub methods: Option<Vec<ClassMethod>>,
-    // pub properties: Option<Vec<Property>>,
-    // pub signals: Option<Vec<Signal>>,
+    pub properties: Option<Vec<Property>>,
+    pub signals: Option<Vec<Signal>>,
 }
 
 #[derive(DeJson)]
@@ -145,20 +145,20 @@ pub struct Member {
     pub type_: String,
 }
 
-#[derive(DeJson)]
+#[derive(DeJson, Clone)]
 pub struct Property {
     #[nserde(rename = "type")]
-    type_: String,
-    name: String,
-    setter: String,
-    getter: String,
-    index: i32, // can be -1
+    pub type_: String,
+    pub name: String,
+    pub setter: Option<String>, // absent for read-only properties
+    pub getter: Option<String>, // absent for write-only properties
+    pub index: Option<i32>,     // only present for indexed properties
 }
 
-#[derive(DeJson)]
+#[derive(DeJson, Clone)]
 pub struct Signal {
-    name: String,
-    arguments: Option<Vec<MethodArg>>,
+    pub name: String,
+    pub arguments: Option<Vec<MethodArg>>,
 }
 
 #[derive(DeJson)]
@@ -8,6 +8,7 @@
 
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
+use std::collections::HashSet;
 use std::path::Path;
 
 use crate::api_parser::*;
@@ -19,8 +20,9 @@ use crate::util::{
     MethodTableKey, NativeStructuresField,
 };
 use crate::{
-    codegen_special_cases, special_cases, util, Context, GeneratedBuiltin, GeneratedBuiltinModule,
-    GeneratedClass, GeneratedClassModule, ModName, RustTy, SubmitFn, TyName,
+    codegen_special_cases, doc_converter, special_cases, util, Context, GeneratedBuiltin,
+    GeneratedBuiltinModule, GeneratedClass, GeneratedClassModule, ModName, RustTy, SubmitFn,
+    TyName,
 };
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -176,6 +178,8 @@ struct FnSignature<'a> {
     qualifier: FnQualifier,
     params: Vec<FnParam>,
     return_value: FnReturn,
+    /// Description from Godot's class reference, if available.
+    doc: Option<String>,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -362,6 +366,7 @@ fn make_class_doc(
     base_ident_opt: Option<Ident>,
     has_notification_enum: bool,
     has_sidecar_module: bool,
+    description: Option<String>,
 ) -> String {
     let TyName { rust_ty, godot_ty } = class_name;
 
@@ -393,6 +398,12 @@ fn make_class_doc(
 
     let trait_name = class_name.virtual_trait_name();
 
+    // Description from Godot's class reference, if available.
+    let description = match description {
+        Some(description) => format!("{description}\n\n"),
+        None => String::new(),
+    };
+
     format!(
         "Godot class `{godot_ty}.`\n\n\
         \
@@ -403,6 +414,7 @@ fn make_class_doc(
         * [`{trait_name}`][crate::engine::{trait_name}]: virtual methods\n\
         {notify_line}\
         \n\n\
+        {description}\
         See also [Godot docs for `{godot_ty}`]({online_link}).\n\n",
     )
 }
@@ -534,6 +546,11 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
 
     let enums = make_enums(option_as_slice(&class.enums), class_name, ctx);
     let constants = make_constants(option_as_slice(&class.constants), class_name, ctx);
+    let SignalDefinitions {
+        accessor: signals_accessor,
+        collection: signals_collection,
+    } = make_signals(class, class_name, ctx);
+    let properties = make_properties(class, class_name, ctx);
     let inherits_macro = format_ident!("inherits_transitive_{}", class_name.rust_ty);
 
     let (exportable_impl, exportable_macro_impl) = if ctx.is_exportable(class_name) {
@@ -578,13 +595,17 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
     // Associated "sidecar" module is made public if there are other symbols related to the class, which are not
     // in top-level godot::engine module (notification enums are not in the sidecar, but in godot::engine::notify).
     // This checks if token streams (i.e. code) is empty.
-    let has_sidecar_module = !enums.is_empty() || !builders.is_empty();
+    let has_sidecar_module = !enums.is_empty()
+        || !builders.is_empty()
+        || !signals_collection.is_empty()
+        || !properties.is_empty();
 
     let class_doc = make_class_doc(
         class_name,
         base_ident_opt,
         notification_enum.is_some(),
         has_sidecar_module,
+        doc_converter::make_class_description(class_name, ctx),
     );
     let module_doc = make_module_doc(class_name);
     let virtual_trait = make_virtual_methods_trait(
@@ -631,6 +652,7 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
             impl #class_name {
                 #constructor
                 #methods
+                #signals_accessor
                 #notify_methods
                 #constants
             }
@@ -675,6 +697,8 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
 
         #builders
         #enums
+        #signals_collection
+        #properties
     };
     // note: TypePtr -> ObjectPtr conversion OK?
 
@@ -1093,8 +1117,26 @@ fn make_builtin_methods(
     FnDefinitions::expand(definitions)
 }
 
-fn make_enums(enums: &[Enum], _class_name: &TyName, _ctx: &Context) -> TokenStream {
-    let definitions = enums.iter().map(util::make_enum_definition);
+fn make_enums(enums: &[Enum], class_name: &TyName, ctx: &mut Context) -> TokenStream {
+    let definitions = enums.iter().map(|enum_| {
+        // In the class reference, enumerators are listed among the constants.
+        let enumerator_docs = enum_
+            .values
+            .iter()
+            .filter_map(|enumerator| {
+                let doc = doc_converter::make_member_doc(
+                    class_name,
+                    &enumerator.name,
+                    |doc| &doc.constants,
+                    ctx,
+                )?;
+                Some((enumerator.name.clone(), doc))
+            })
+            .collect();
+
+        util::make_documented_enum_definition(enum_, &enumerator_docs)
+    });
+    let definitions: Vec<TokenStream> = definitions.collect();
 
     quote! {
         #( #definitions )*
@@ -1103,16 +1145,315 @@ fn make_enums(enums: &[Enum], _class_name: &TyName, _ctx: &Context) -> TokenStre
 
 fn make_constants(
     constants: &[ClassConstant],
-    _class_name: &TyName,
-    _ctx: &Context,
+    class_name: &TyName,
+    ctx: &mut Context,
 ) -> TokenStream {
-    let definitions = constants.iter().map(util::make_constant_definition);
+    let definitions = constants.iter().map(|constant| {
+        let doc =
+            doc_converter::make_member_doc(class_name, &constant.name, |doc| &doc.constants, ctx);
+        let doc_attr = make_doc_attr(doc.as_deref());
+        let definition = util::make_constant_definition(constant);
+
+        quote! {
+            #doc_attr
+            #definition
+        }
+    });
+    let definitions: Vec<TokenStream> = definitions.collect();
 
     quote! {
         #( #definitions )*
     }
 }
 
+struct SignalDefinitions {
+    /// `signals()` method, part of the class' `impl` block.
+    accessor: TokenStream,
+
+    /// `XySignals` struct, part of the sidecar module.
+    collection: TokenStream,
+}
+
+/// Generates typed signal accessors for classes that declare their own signals.
+///
+/// Classes without own signals reach `signals()` of their base class through `Deref`. Likewise, each signal collection dereferences
+/// to the collection of the nearest base class with signals, so that inherited signals remain accessible.
+fn make_signals(class: &Class, class_name: &TyName, ctx: &mut Context) -> SignalDefinitions {
+    if !ctx.has_own_signals(class_name) {
+        return SignalDefinitions {
+            accessor: TokenStream::new(),
+            collection: TokenStream::new(),
+        };
+    }
+
+    let rust_ty = &class_name.rust_ty;
+    let collection_name = format_ident!("{}Signals", rust_ty);
+
+    let signal_fns: Vec<TokenStream> = option_as_slice(&class.signals)
+        .iter()
+        .filter_map(|signal| make_signal_fn(signal, class_name, ctx))
+        .collect();
+
+    let (base_field, base_init, object_getter, deref_impl, base_doc);
+    match ctx.nearest_base_with_signals(class_name) {
+        Some(base) => {
+            let base_mod = ModName::from_godot(&base.godot_ty);
+            let base_collection = format_ident!("{}Signals", base.rust_ty);
+            let base_path = quote! { crate::engine::#base_mod::#base_collection };
+
+            base_field = quote! { base: #base_path };
+            base_init = quote! { base: #base_path::from_object(object) };
+            object_getter = quote! { self.base.object() };
+            deref_impl = quote! {
+                impl std::ops::Deref for #collection_name {
+                    type Target = #base_path;
+
+                    fn deref(&self) -> &Self::Target {
+                        &self.base
+                    }
+                }
+            };
+            base_doc = format!(
+                "\n\nDereferences to [`{base_collection}`][crate::engine::{base_mod}::{base_collection}], \
+                so that inherited signals are available as well.",
+                base_mod = base_mod.rust_mod,
+            );
+        }
+        None => {
+            base_field = quote! { object: Gd<Object> };
+            base_init = quote! { object };
+            object_getter = quote! { &self.object };
+            deref_impl = TokenStream::new();
+            base_doc = String::new();
+        }
+    }
+
+    let collection_doc = format!(
+        "Typed signals of [`{rust_ty}`][crate::engine::{rust_ty}], returned by \
+        [`{rust_ty}::signals()`][crate::engine::{rust_ty}::signals].{base_doc}"
+    );
+
+    let accessor = quote! {
+        /// Returns the typed signals of this object, including inherited ones.
+        pub fn signals(&self) -> #collection_name {
+            // SAFETY: `object_ptr` refers to a live object for as long as `self` is accessible.
+            let object = unsafe { Gd::<Object>::from_obj_sys(self.object_ptr) };
+            #collection_name::from_object(object)
+        }
+    };
+
+    let collection = quote! {
+        #[doc = #collection_doc]
+        pub struct #collection_name {
+            #base_field
+        }
+
+        impl #collection_name {
+            pub(crate) fn from_object(object: Gd<Object>) -> Self {
+                Self { #base_init }
+            }
+
+            pub(crate) fn object(&self) -> &Gd<Object> {
+                #object_getter
+            }
+
+            #( #signal_fns )*
+        }
+
+        #deref_impl
+    };
+
+    SignalDefinitions {
+        accessor,
+        collection,
+    }
+}
+
+fn make_signal_fn(signal: &Signal, class_name: &TyName, ctx: &mut Context) -> Option<TokenStream> {
+    if !is_signal_fn_generated(signal, ctx) {
+        return None;
+    }
+
+    let args = option_as_slice(&signal.arguments);
+
+    let param_tys = args.iter().map(|arg| {
+        match to_rust_type(&arg.type_, arg.meta.as_ref(), ctx) {
+            // Like return values, objects passed by Godot may be null.
+            RustTy::EngineClass { tokens, .. } => quote! { Option<#tokens> },
+            other => quote! { #other },
+        }
+    });
+    let param_tys: Vec<TokenStream> = param_tys.collect();
+
+    let signal_name = &signal.name;
+    let fn_name = safe_ident(signal_name);
+    let param_names: Vec<&str> = args.iter().map(|arg| arg.name.as_str()).collect();
+    let mut doc = format!(
+        "Signal `{signal_name}({params})` of [`{class}`][crate::engine::{class}].",
+        params = param_names.join(", "),
+        class = class_name.rust_ty,
+    );
+    if let Some(description) =
+        doc_converter::make_member_doc(class_name, signal_name, |doc| &doc.signals, ctx)
+    {
+        doc = format!("{doc}\n\n{description}");
+    }
+
+    Some(quote! {
+        #[doc = #doc]
+        pub fn #fn_name(&self) -> TypedSignal<( #( #param_tys, )* )> {
+            TypedSignal::new(self.object(), #signal_name)
+        }
+    })
+}
+
+/// Whether a typed signal function is generated for the signal, as part of the class's `XySignals` collection.
+pub(crate) fn is_signal_fn_generated(signal: &Signal, ctx: &mut Context) -> bool {
+    // TypedSignal is implemented for tuples of up to 8 parameters.
+    option_as_slice(&signal.arguments).len() <= 8
+        && !codegen_special_cases::is_signal_excluded(signal, ctx)
+}
+
+/// Generates the `properties` module with one [`PropertyName`] constant per property that the class declares.
+///
+/// The module re-exports the constants of the nearest base class with properties, so that e.g. `node_2d::properties::VISIBLE`
+/// refers to the property declared by `CanvasItem`. It is generated for every class, even if it only re-exports or is empty.
+fn make_properties(class: &Class, class_name: &TyName, ctx: &mut Context) -> TokenStream {
+    let base_reexport = ctx.nearest_base_with_properties(class_name).map(|base| {
+        let base_mod = ModName::from_godot(&base.godot_ty);
+        quote! { pub use crate::engine::#base_mod::properties::*; }
+    });
+
+    let mut const_names = HashSet::new();
+    let constants: Vec<TokenStream> = option_as_slice(&class.properties)
+        .iter()
+        .filter_map(|property| {
+            // Different Godot names may map to the same constant name; keep the first one.
+            let const_name = to_property_const_name(&property.name);
+            if !const_names.insert(const_name.clone()) {
+                return None;
+            }
+
+            make_property_const(property, &const_name, class_name, ctx)
+        })
+        .collect();
+
+    let rust_ty = &class_name.rust_ty;
+    let module_doc = if constants.is_empty() && base_reexport.is_none() {
+        format!("[`{rust_ty}`][crate::engine::{rust_ty}] has no properties, neither declared nor inherited.")
+    } else {
+        let inherited_note = if constants.is_empty() {
+            " The class declares no properties of its own; all of them are inherited."
+        } else {
+            ""
+        };
+
+        format!(
+            "Names of the properties of [`{rust_ty}`][crate::engine::{rust_ty}], including inherited ones.{inherited_note}\n\n\
+            Can be used with [`Gd::get_property()`][crate::obj::Gd::get_property] and \
+            [`Gd::set_property()`][crate::obj::Gd::set_property], or converted to `StringName`/`NodePath`."
+        )
+    };
+
+    // Only needed for the types of the class's own constants.
+    let super_import = (!constants.is_empty()).then(|| quote! { use super::*; });
+
+    quote! {
+        #[doc = #module_doc]
+        pub mod properties {
+            #super_import
+            #base_reexport
+
+            #( #constants )*
+        }
+    }
+}
+
+fn make_property_const(
+    property: &Property,
+    const_name: &str,
+    class_name: &TyName,
+    ctx: &mut Context,
+) -> Option<TokenStream> {
+    let ty = &property.type_;
+
+    let value_ty = if !is_single_property_type(ty) {
+        quote! { Variant }
+    } else if !is_property_const_generated(property, ctx) {
+        return None;
+    } else {
+        match to_rust_type(ty, None, ctx) {
+            // Like return values, objects stored in properties may be null.
+            RustTy::EngineClass { tokens, .. } => quote! { Option<#tokens> },
+            other => quote! { #other },
+        }
+    };
+
+    let property_name = &property.name;
+    let const_ident = ident(const_name);
+    let accessors = match (&property.getter, &property.setter) {
+        (Some(getter), Some(setter)) => format!("`{getter}()`/`{setter}()`"),
+        (Some(getter), None) => format!("`{getter}()`, read-only"),
+        (None, Some(setter)) => format!("`{setter}()`, write-only"),
+        (None, None) => "no accessors".to_string(),
+    };
+    let mut doc = format!(
+        "Property `{property_name}: {ty}` of [`{class}`][crate::engine::{class}] ({accessors}).",
+        class = class_name.rust_ty,
+    );
+    if let Some(description) =
+        doc_converter::make_member_doc(class_name, property_name, |doc| &doc.members, ctx)
+    {
+        doc = format!("{doc}\n\n{description}");
+    }
+
+    Some(quote! {
+        #[doc = #doc]
+        pub const #const_ident: crate::builtin::meta::PropertyName<crate::engine::#class_name, #value_ty> =
+            crate::builtin::meta::PropertyName::new(#property_name);
+    })
+}
+
+/// Whether a constant is generated for the property in the class's `properties` module.
+pub(crate) fn is_property_const_generated(property: &Property, ctx: &mut Context) -> bool {
+    // Values of properties without a single type are exchanged as Variant, so their type cannot be excluded.
+    !is_single_property_type(&property.type_)
+        || !codegen_special_cases::is_property_excluded(property, ctx)
+}
+
+/// Properties of resource type may list several allowed classes, e.g. "CanvasItemMaterial,ShaderMaterial", or carry other hints.
+/// Their type cannot be expressed as a single Rust type, so values are exchanged as Variant.
+fn is_single_property_type(ty: &str) -> bool {
+    let plain_ty = ["typedarray::", "enum::", "bitfield::"]
+        .iter()
+        .find_map(|prefix| ty.strip_prefix(prefix))
+        .unwrap_or(ty);
+
+    plain_ty
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Converts a Godot property name such as `theme_override_colors/font_color` to `THEME_OVERRIDE_COLORS_FONT_COLOR`.
+pub(crate) fn to_property_const_name(property_name: &str) -> String {
+    let mut const_name: String = property_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if const_name.starts_with(|c: char| c.is_ascii_digit()) {
+        const_name.insert(0, '_');
+    }
+
+    const_name
+}
+
 /// Depending on the built-in class, adds custom constructors and methods.
 fn make_special_builtin_methods(class_name: &TyName, _ctx: &Context) -> TokenStream {
     if class_name.godot_ty == "Array" {
@@ -1231,6 +1572,7 @@ fn make_class_method_definition(
             qualifier: FnQualifier::for_method(method.is_const, method.is_static),
             params: FnParam::new_range(&method.arguments, ctx),
             return_value: FnReturn::new(&method.return_value, ctx),
+            doc: doc_converter::make_member_doc(class_name, &method.name, |doc| &doc.methods, ctx),
         },
         &FnCode {
             receiver,
@@ -1316,6 +1658,7 @@ fn make_builtin_method_definition(
             // They are not public-facing and need more involved implementation (lifetimes etc). Also reduces number of symbols in API.
             params: FnParam::new_range_no_defaults(&method.arguments, ctx),
             return_value: FnReturn::new(&return_value, ctx),
+            doc: None,
         },
         &FnCode {
             receiver,
@@ -1372,6 +1715,12 @@ pub(crate) fn make_utility_function_definition(
             qualifier: FnQualifier::Global,
             params: FnParam::new_range(&function.arguments, ctx),
             return_value: FnReturn::new(&return_value, ctx),
+            doc: doc_converter::make_member_doc(
+                &TyName::from_godot("@GlobalScope"),
+                &function.name,
+                |doc| &doc.methods,
+                ctx,
+            ),
         },
         &FnCode {
             receiver: FnReceiver::global_function(),
@@ -1384,6 +1733,13 @@ pub(crate) fn make_utility_function_definition(
     definition.into_functions_only()
 }
 
+fn make_doc_attr(doc: Option<&str>) -> TokenStream {
+    match doc {
+        Some(doc) => quote! { #[doc = #doc] },
+        None => TokenStream::new(),
+    }
+}
+
 fn make_vis(is_private: bool) -> TokenStream {
     if is_private {
         quote! { pub(crate) }
@@ -1416,6 +1772,7 @@ fn make_function_definition(sig: &FnSignature, code: &FnCode) -> FnDefinition {
     };
 
     let [params, param_types, arg_names] = make_params_exprs(&sig.params);
+    let doc_attr = make_doc_attr(sig.doc.as_deref());
 
     let godot_fn_name_str = sig.function_name;
     let primary_fn_name = if has_default_params {
@@ -1442,6 +1799,7 @@ fn make_function_definition(sig: &FnSignature, code: &FnCode) -> FnDefinition {
         // Virtual functions
 
         quote! {
+            #doc_attr
             #safety_doc
             #maybe_unsafe fn #primary_fn_name(
                 #receiver_param
@@ -1458,6 +1816,7 @@ fn make_function_definition(sig: &FnSignature, code: &FnCode) -> FnDefinition {
 
         // TODO use Result instead of panic on error
         quote! {
+            #doc_attr
             #safety_doc
             #vis #maybe_unsafe fn #primary_fn_name(
                 #receiver_param
@@ -1488,6 +1847,7 @@ fn make_function_definition(sig: &FnSignature, code: &FnCode) -> FnDefinition {
         };
 
         quote! {
+            #doc_attr
             #safety_doc
             #vis #maybe_unsafe fn #primary_fn_name(
                 #receiver_param
@@ -1589,7 +1949,9 @@ fn make_function_definition_with_defaults(
         }
     };
 
+    let doc_attr = make_doc_attr(sig.doc.as_deref());
     let functions = quote! {
+        #doc_attr
         #[inline]
         #vis fn #simple_fn_name(
             #receiver_param
@@ -1600,6 +1962,7 @@ fn make_function_definition_with_defaults(
             ).done()
         }
 
+        #doc_attr
         #[inline]
         #vis fn #extended_fn_name(
             #receiver_param
@@ -1830,7 +2193,7 @@ fn make_virtual_methods_trait(
 ) -> TokenStream {
     let trait_name = ident(trait_name);
 
-    let virtual_method_fns = make_all_virtual_methods(class, all_base_names, ctx);
+    let virtual_method_fns = make_all_virtual_methods(class, class_name, all_base_names, ctx);
     let special_virtual_methods = special_virtual_methods(notification_enum_name);
 
     let trait_doc = make_virtual_trait_doc(class_name);
@@ -1848,7 +2211,10 @@ fn make_virtual_methods_trait(
 
 fn special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
     quote! {
-        #[doc(hidden)]
+        /// Registers additional class members at runtime, through a [`ClassBuilder`][crate::builder::ClassBuilder].
+        ///
+        /// Called once, when the class is registered with Godot. Members declared here are added after those generated by
+        /// `#[func]`, `#[var]`, `#[signal]` etc.
         fn register_class(builder: &mut crate::builder::ClassBuilder<Self>) {
             unimplemented!()
         }
@@ -1889,7 +2255,11 @@ fn special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
     }
 }
 
-fn make_virtual_method(method: &ClassMethod, ctx: &mut Context) -> TokenStream {
+fn make_virtual_method(
+    method: &ClassMethod,
+    class_name: &TyName,
+    ctx: &mut Context,
+) -> TokenStream {
     let method_name = virtual_method_name(method);
 
     // Virtual methods are never static.
@@ -1905,6 +2275,7 @@ fn make_virtual_method(method: &ClassMethod, ctx: &mut Context) -> TokenStream {
             qualifier: FnQualifier::for_method(method.is_const, method.is_static),
             params: FnParam::new_range(&method.arguments, ctx),
             return_value: FnReturn::new(&method.return_value, ctx),
+            doc: doc_converter::make_member_doc(class_name, &method.name, |doc| &doc.methods, ctx),
         },
         &FnCode {
             receiver: make_receiver(false, method.is_const, TokenStream::new()),
@@ -1920,35 +2291,37 @@ fn make_virtual_method(method: &ClassMethod, ctx: &mut Context) -> TokenStream {
 
 fn make_all_virtual_methods(
     class: &Class,
+    class_name: &TyName,
     all_base_names: &[TyName],
     ctx: &mut Context,
 ) -> Vec<TokenStream> {
+    // Declaring class is kept along with each method, to look up its documentation.
     let mut all_virtuals = vec![];
-    let mut extend_virtuals = |class| {
+    let mut extend_virtuals = |class, declaring_class: &TyName| {
         all_virtuals.extend(
             get_methods_in_class(class)
                 .iter()
                 .filter(|m| m.is_virtual)
-                .cloned(),
+                .map(|m| (m.clone(), declaring_class.clone())),
         );
     };
 
     // Get virtuals defined on the current class.
-    extend_virtuals(class);
+    extend_virtuals(class, class_name);
 
     // Add virtuals from superclasses.
     for base in all_base_names {
         let superclass = ctx.get_engine_class(base);
-        extend_virtuals(superclass);
+        extend_virtuals(superclass, base);
     }
 
     all_virtuals
         .into_iter()
-        .filter_map(|method| {
+        .filter_map(|(method, declaring_class)| {
             if codegen_special_cases::is_method_excluded(&method, true, ctx) {
                 None
             } else {
-                Some(make_virtual_method(&method, ctx))
+                Some(make_virtual_method(&method, &declaring_class, ctx))
             }
         })
         .collect()
@@ -1961,7 +2334,7 @@ fn get_methods_in_class(class: &Class) -> &[ClassMethod] {
     }
 }
 
-fn virtual_method_name(class_method: &ClassMethod) -> &str {
+pub(crate) fn virtual_method_name(class_method: &ClassMethod) -> &str {
     // Matching the C++ convention, we remove the leading underscore
     // from virtual method names.
     let method_name = class_method
@@ -4,11 +4,51 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-//! Codegen-dependent exclusions. Can be removed if feature `codegen-full` is removed.
+//! Codegen-dependent exclusions, depending on feature `codegen-full` and the user-selected class subset (`GODOT4_CODEGEN_CLASSES`).
 
-use crate::api_parser::{BuiltinClassMethod, ClassMethod, UtilityFunction};
-use crate::context::Context;
-use crate::{special_cases, TyName};
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use crate::api_parser::{
+    BuiltinClassMethod, Class, ClassMethod, ExtensionApi, Property, Signal, UtilityFunction,
+};
+use crate::context::{Context, InheritanceTree};
+use crate::util::option_as_slice;
+use crate::{special_cases, util, ModName, RustTy, TyName};
+
+/// Classes selected through `GODOT4_CODEGEN_CLASSES`, including all classes reachable from them. Overrides feature `codegen-full`.
+static USER_SELECTED_CLASSES: OnceLock<HashSet<String>> = OnceLock::new();
+
+/// Classes that godot-core itself relies on or that the `godot` prelude re-exports; always generated when the user selects a subset.
+///
+/// Interface traits (`I*`) are generated along with their class; hand-written extensions such as `PackedSceneExt` rely on the class.
+const CORE_REQUIRED_CLASSES: &[&str] = &[
+    "AudioStreamPlayer",
+    "Camera2D",
+    "Camera3D",
+    "Control",
+    "EditorInspectorPlugin",
+    "EditorInterface",
+    "EditorPlugin",
+    "Engine",
+    "FileAccess",
+    "GDExtensionManager",
+    "Input",
+    "Node",
+    "Node2D",
+    "Node3D",
+    "OS",
+    "Object",
+    "PackedScene",
+    "ProjectSettings",
+    "RefCounted",
+    "Resource",
+    "ResourceLoader",
+    "SceneTree",
+    "SceneTreeTimer",
+    "Texture2D",
+    "Theme",
+];
 
 pub(crate) fn is_builtin_method_excluded(method: &BuiltinClassMethod) -> bool {
     // TODO Fall back to varcall (recent addition in GDExtension API).
@@ -16,20 +56,29 @@ pub(crate) fn is_builtin_method_excluded(method: &BuiltinClassMethod) -> bool {
     method.is_vararg
 }
 
-#[cfg(not(feature = "codegen-full"))]
 pub(crate) fn is_class_excluded(class: &str) -> bool {
+    match USER_SELECTED_CLASSES.get() {
+        Some(selected) => !selected.contains(class),
+        None => is_class_excluded_by_feature(class),
+    }
+}
+
+#[cfg(not(feature = "codegen-full"))]
+fn is_class_excluded_by_feature(class: &str) -> bool {
     !SELECTED_CLASSES.contains(&class)
 }
 
 #[cfg(feature = "codegen-full")]
-pub(crate) fn is_class_excluded(_class: &str) -> bool {
+fn is_class_excluded_by_feature(_class: &str) -> bool {
     false
 }
 
-#[cfg(not(feature = "codegen-full"))]
-fn is_type_excluded(ty: &str, ctx: &mut Context) -> bool {
-    use crate::{util, RustTy};
+/// Whether all classes are generated, i.e. no type can be excluded.
+fn is_codegen_full() -> bool {
+    USER_SELECTED_CLASSES.get().is_none() && cfg!(feature = "codegen-full")
+}
 
+fn is_type_excluded(ty: &str, ctx: &mut Context) -> bool {
     fn is_rust_type_excluded(ty: &RustTy) -> bool {
         match ty {
             RustTy::BuiltinIdent(_) => false,
@@ -45,6 +94,10 @@ fn is_type_excluded(ty: &str, ctx: &mut Context) -> bool {
             RustTy::EngineClass { inner_class, .. } => is_class_excluded(&inner_class.to_string()),
         }
     }
+
+    if is_codegen_full() {
+        return false;
+    }
     is_rust_type_excluded(&util::to_rust_type(ty, None, ctx))
 }
 
@@ -53,17 +106,8 @@ pub(crate) fn is_method_excluded(
     is_virtual_impl: bool,
     ctx: &mut Context,
 ) -> bool {
-    let is_arg_or_return_excluded = |ty: &str, _ctx: &mut Context| {
-        let class_deleted = special_cases::is_class_deleted(&TyName::from_godot(ty));
-
-        #[cfg(not(feature = "codegen-full"))]
-        {
-            class_deleted || is_type_excluded(ty, _ctx)
-        }
-        #[cfg(feature = "codegen-full")]
-        {
-            class_deleted
-        }
+    let is_arg_or_return_excluded = |ty: &str, ctx: &mut Context| {
+        special_cases::is_class_deleted(&TyName::from_godot(ty)) || is_type_excluded(ty, ctx)
     };
 
     // Exclude if return type contains an excluded type.
@@ -89,12 +133,22 @@ pub(crate) fn is_method_excluded(
     false
 }
 
-#[cfg(feature = "codegen-full")]
-pub(crate) fn is_function_excluded(_function: &UtilityFunction, _ctx: &mut Context) -> bool {
-    false
+pub(crate) fn is_signal_excluded(signal: &Signal, ctx: &mut Context) -> bool {
+    signal.arguments.as_ref().map_or(false, |args| {
+        args.iter()
+            .any(|arg| is_member_type_excluded(arg.type_.as_str(), ctx))
+    })
+}
+
+pub(crate) fn is_property_excluded(property: &Property, ctx: &mut Context) -> bool {
+    is_member_type_excluded(property.type_.as_str(), ctx)
+}
+
+/// Whether a signal parameter or property type refers to a class that is not generated.
+fn is_member_type_excluded(ty: &str, ctx: &mut Context) -> bool {
+    special_cases::is_class_deleted(&TyName::from_godot(ty)) || is_type_excluded(ty, ctx)
 }
 
-#[cfg(not(feature = "codegen-full"))]
 pub(crate) fn is_function_excluded(function: &UtilityFunction, ctx: &mut Context) -> bool {
     function
         .return_type
@@ -106,6 +160,105 @@ pub(crate) fn is_function_excluded(function: &UtilityFunction, ctx: &mut Context
         })
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// User-selected classes
+
+/// Selects the classes to generate if the user restricted them with `GODOT4_CODEGEN_CLASSES`; otherwise does nothing.
+///
+/// The selection is closed over inheritance and method signatures: for every selected class, all its base classes and all classes
+/// appearing in parameter or return types (including enums and typed arrays) are selected as well. This ensures that the generated
+/// API of the selected classes is complete. Must be called before any class is checked for exclusion.
+pub(crate) fn register_selected_classes(api: &ExtensionApi, inheritance_tree: &InheritanceTree) {
+    let Some(requested) = godot_bindings::selected_codegen_classes() else {
+        return;
+    };
+
+    let classes_by_name: HashMap<&str, &Class> = api
+        .classes
+        .iter()
+        .map(|class| (class.name.as_str(), class))
+        .collect();
+
+    let mut pending: Vec<String> = CORE_REQUIRED_CLASSES
+        .iter()
+        .map(|class| class.to_string())
+        .collect();
+
+    for entry in requested.iter() {
+        let class = api
+            .classes
+            .iter()
+            .find(|class| {
+                class.name == *entry
+                    || TyName::from_godot(&class.name).rust_ty == entry
+                    || ModName::from_godot(&class.name).rust_mod == entry
+            })
+            .unwrap_or_else(|| {
+                panic!("GODOT4_CODEGEN_CLASSES: `{entry}` is neither an engine class nor module")
+            });
+
+        pending.push(class.name.clone());
+    }
+
+    let mut selected = HashSet::new();
+    while let Some(class_name) = pending.pop() {
+        if selected.contains(&class_name) {
+            continue;
+        }
+
+        let ty_name = TyName::from_godot(&class_name);
+        pending.extend(
+            inheritance_tree
+                .collect_all_bases(&ty_name)
+                .into_iter()
+                .map(|base| base.godot_ty),
+        );
+
+        if let Some(class) = classes_by_name.get(class_name.as_str()) {
+            for method in option_as_slice(&class.methods) {
+                let return_ty = method.return_value.as_ref().map(|ret| ret.type_.as_str());
+                let param_tys = option_as_slice(&method.arguments)
+                    .iter()
+                    .map(|arg| arg.type_.as_str());
+
+                let referenced = return_ty
+                    .into_iter()
+                    .chain(param_tys)
+                    .map(referenced_class_name)
+                    .filter(|name| classes_by_name.contains_key(name) && !selected.contains(*name));
+
+                pending.extend(referenced.map(str::to_string));
+            }
+        }
+
+        selected.insert(class_name);
+    }
+
+    println!(
+        "Selected {} of {} engine classes for codegen",
+        selected.len(),
+        api.classes.len()
+    );
+
+    // Only the first registration counts; the environment does not change during a build.
+    let _ = USER_SELECTED_CLASSES.set(selected);
+}
+
+/// For a Godot type such as `Node`, `enum::Node.ProcessMode` or `typedarray::Node`, returns the name of the class it refers to.
+///
+/// The result is not necessarily a class (e.g. `int` or global enums); the caller checks this.
+pub(crate) fn referenced_class_name(ty: &str) -> &str {
+    let ty = ty.strip_prefix("typedarray::").unwrap_or(ty);
+
+    match ty
+        .strip_prefix("enum::")
+        .or_else(|| ty.strip_prefix("bitfield::"))
+    {
+        Some(qualified) => qualified.split_once('.').map_or("", |(class, _)| class),
+        None => ty,
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Allowed-classes
 
@@ -127,9 +280,13 @@ const SELECTED_CLASSES: &[&str] = &[
     "CollisionObject2D",
     "CollisionShape2D",
     "Control",
+    "EditorInspectorPlugin",
+    "EditorInterface",
     "EditorPlugin",
+    "EditorProperty",
     "Engine",
     "FileAccess",
+    "GDExtensionManager",
     "HTTPRequest",
     "Image",
     "ImageTextureLayered",
@@ -150,6 +307,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "PathFollow2D",
     "PhysicsBody2D",
     "PrimitiveMesh",
+    "ProjectSettings",
     "RefCounted",
     "RenderingServer",
     "Resource",
@@ -157,13 +315,16 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "RigidBody2D",
     "SceneTree",
+    "SceneTreeTimer",
     "Sprite2D",
     "SpriteFrames",
     "TextServer",
     "TextServerExtension",
     "Texture",
+    "Texture2D",
     "Texture2DArray",
     "TextureLayered",
+    "Theme",
     "Time",
     "Timer",
     "Window",
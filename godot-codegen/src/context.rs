@@ -5,6 +5,7 @@
  */
 
 use crate::api_parser::{BuiltinClass, BuiltinClassMethod, Class, ClassConstant, ClassMethod};
+use crate::doc_parser::{ClassDoc, ClassDocs};
 use crate::util::{option_as_slice, MethodTableKey};
 use crate::{codegen_special_cases, special_cases, util, ExtensionApi, GodotTy, RustTy, TyName};
 use proc_macro2::{Ident, TokenStream};
@@ -21,14 +22,21 @@ pub(crate) struct Context<'a> {
     cached_rust_types: HashMap<GodotTy, RustTy>,
     notifications_by_class: HashMap<TyName, Vec<(Ident, i32)>>,
     notification_enum_names_by_class: HashMap<TyName, NotificationEnum>,
+    classes_with_signals: HashSet<TyName>,
+    classes_with_properties: HashSet<TyName>,
+    class_docs: ClassDocs,
     method_table_indices: HashMap<MethodTableKey, usize>,
     method_table_next_index: HashMap<String, usize>,
 }
 
 impl<'a> Context<'a> {
-    pub fn build_from_api(api: &'a ExtensionApi) -> Self {
+    pub fn build_from_api(api: &'a ExtensionApi, class_docs: ClassDocs) -> Self {
         let mut ctx = Self::default();
 
+        // Must happen before any class is checked for deletion.
+        special_cases::register_experimental_classes(class_docs.experimental_classes());
+        ctx.class_docs = class_docs;
+
         for class in api.singletons.iter() {
             ctx.singletons.insert(class.name.as_str());
         }
@@ -50,6 +58,18 @@ impl<'a> Context<'a> {
             ctx.native_structures_types.insert(ty_name);
         }
 
+        // Populate derived-to-base relations. Includes excluded classes, as they are needed to determine the selected ones.
+        for class in api.classes.iter() {
+            if let Some(base) = class.inherits.as_ref() {
+                let class_name = TyName::from_godot(&class.name);
+                ctx.inheritance_tree
+                    .insert(class_name, TyName::from_godot(base));
+            }
+        }
+
+        // Must happen before any class is checked for exclusion.
+        codegen_special_cases::register_selected_classes(api, &ctx.inheritance_tree);
+
         for class in api.classes.iter() {
             let class_name = TyName::from_godot(&class.name);
 
@@ -61,11 +81,8 @@ impl<'a> Context<'a> {
             println!("-- add engine class {}", class_name.description());
             ctx.engine_classes.insert(class_name.clone(), class);
 
-            // Populate derived-to-base relations
             if let Some(base) = class.inherits.as_ref() {
-                let base_name = TyName::from_godot(base);
-                println!("  -- inherits {}", base_name.description());
-                ctx.inheritance_tree.insert(class_name.clone(), base_name);
+                println!("  -- inherits {}", TyName::from_godot(base).description());
             }
 
             // Populate notification constants (first, only for classes that declare them themselves).
@@ -80,6 +97,16 @@ impl<'a> Context<'a> {
                 option_as_slice(&class.methods),
                 &mut ctx,
             );
+
+            // Populate classes declaring their own signals (inherited signals are accessed through the base class).
+            if !option_as_slice(&class.signals).is_empty() {
+                ctx.classes_with_signals.insert(class_name.clone());
+            }
+
+            // Same for properties.
+            if !option_as_slice(&class.properties).is_empty() {
+                ctx.classes_with_properties.insert(class_name);
+            }
         }
 
         // Populate remaining notification enum names, by copying the one to nearest base class that has at least 1 notification.
@@ -206,6 +233,16 @@ impl<'a> Context<'a> {
         self.engine_classes.get(class_name).unwrap()
     }
 
+    /// Whether this is an engine class that is part of the codegen (not excluded by minimal codegen).
+    pub fn is_engine_class(&self, class_name: &TyName) -> bool {
+        self.engine_classes.contains_key(class_name)
+    }
+
+    /// Entry of Godot's class reference for this class (also `@GlobalScope`), if the class reference is available.
+    pub fn class_doc(&self, class_name: &TyName) -> Option<&ClassDoc> {
+        self.class_docs.get(&class_name.godot_ty)
+    }
+
     // Private, because initialized in constructor. Ensures deterministic assignment.
     fn register_table_index(&mut self, key: MethodTableKey) -> usize {
         let key_category = key.category();
@@ -259,6 +296,32 @@ impl<'a> Context<'a> {
         &self.inheritance_tree
     }
 
+    /// Whether the class itself declares at least one signal.
+    pub fn has_own_signals(&self, class_name: &TyName) -> bool {
+        self.classes_with_signals.contains(class_name)
+    }
+
+    /// Returns the nearest base class (excluding the class itself) that declares signals.
+    pub fn nearest_base_with_signals(&self, class_name: &TyName) -> Option<TyName> {
+        self.inheritance_tree
+            .collect_all_bases(class_name)
+            .into_iter()
+            .find(|base| self.has_own_signals(base))
+    }
+
+    /// Whether the class itself declares at least one property.
+    pub fn has_own_properties(&self, class_name: &TyName) -> bool {
+        self.classes_with_properties.contains(class_name)
+    }
+
+    /// Returns the nearest base class (excluding the class itself) that declares properties.
+    pub fn nearest_base_with_properties(&self, class_name: &TyName) -> Option<TyName> {
+        self.inheritance_tree
+            .collect_all_bases(class_name)
+            .into_iter()
+            .find(|base| self.has_own_properties(base))
+    }
+
     pub fn find_rust_type(&'a self, ty: &GodotTy) -> Option<&'a RustTy> {
         self.cached_rust_types.get(ty)
     }
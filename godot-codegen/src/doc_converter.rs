@@ -0,0 +1,451 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Converts descriptions from Godot's class reference (BBCode) to rustdoc Markdown.
+
+use crate::class_generator::{
+    is_property_const_generated, is_signal_fn_generated, to_property_const_name,
+    virtual_method_name,
+};
+use crate::doc_parser::MemberDoc;
+use crate::util::{ident, safe_ident};
+use crate::{special_cases, Context, ModName, TyName};
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Codegen integration
+
+/// Class-level documentation: brief description and full description, converted to Markdown.
+pub(crate) fn make_class_description(class_name: &TyName, ctx: &mut Context) -> Option<String> {
+    let class_doc = ctx.class_doc(class_name)?;
+    let text = join_paragraphs(&class_doc.brief_description, &class_doc.description);
+
+    let converted = to_rustdoc(&text, Some(class_name), ctx);
+    (!converted.is_empty()).then_some(converted)
+}
+
+/// Documentation of a method, property, signal or constant, converted to Markdown.
+///
+/// `select` picks the member table of the class doc, e.g. `|doc| &doc.methods`.
+pub(crate) fn make_member_doc(
+    class_name: &TyName,
+    godot_member_name: &str,
+    select: impl FnOnce(&crate::doc_parser::ClassDoc) -> &std::collections::HashMap<String, MemberDoc>,
+    ctx: &mut Context,
+) -> Option<String> {
+    let member = select(ctx.class_doc(class_name)?)
+        .get(godot_member_name)?
+        .clone();
+
+    let mut text = to_rustdoc(&member.description, Some(class_name), ctx);
+    for (is_marked, note) in [
+        (member.is_deprecated, "*Deprecated in Godot.*"),
+        (
+            member.is_experimental,
+            "*Experimental in Godot; may change or be removed.*",
+        ),
+    ] {
+        if is_marked {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+            text.push_str(note);
+        }
+    }
+
+    (!text.is_empty()).then_some(text)
+}
+
+/// Converts a BBCode description, resolving cross-references to generated Rust items.
+///
+/// `class_name` is the class the description belongs to; unqualified references such as `[method add_child]` are resolved against it.
+pub(crate) fn to_rustdoc(bbcode: &str, class_name: Option<&TyName>, ctx: &mut Context) -> String {
+    bbcode_to_markdown(bbcode, &mut |kind, target| {
+        resolve_link(kind, target, class_name, ctx)
+    })
+}
+
+fn join_paragraphs(first: &str, second: &str) -> String {
+    match (first.is_empty(), second.is_empty()) {
+        (_, true) => first.to_string(),
+        (true, false) => second.to_string(),
+        (false, false) => format!("{first}\n{second}"),
+    }
+}
+
+/// Returns the Rust path for a BBCode reference, or `None` if it cannot be linked (not generated, or no Rust counterpart).
+fn resolve_link(
+    kind: &str,
+    target: &str,
+    current_class: Option<&TyName>,
+    ctx: &mut Context,
+) -> Option<String> {
+    // Qualified member references use `Class.member`; unqualified ones refer to the current class.
+    let split_member = |target: &str| match target.rsplit_once('.') {
+        Some((class, member)) => Some((TyName::from_godot(class), member.to_string())),
+        None => current_class.map(|class| (class.clone(), target.to_string())),
+    };
+
+    match kind {
+        "class" => resolve_class(target, ctx),
+        "method" => {
+            let (class_name, method_name) = split_member(target)?;
+            if !is_generated_class(&class_name, ctx) {
+                return None;
+            }
+
+            let method = ctx
+                .get_engine_class(&class_name)
+                .methods
+                .as_ref()?
+                .iter()
+                .find(|m| m.name == method_name)?
+                .clone();
+
+            if method.is_virtual {
+                let trait_name = class_name.virtual_trait_name();
+                let rust_name = virtual_method_name(&method);
+                Some(format!("crate::engine::{trait_name}::{rust_name}"))
+            } else if special_cases::is_deleted(&class_name, &method, ctx)
+                || special_cases::is_private(&class_name, &method.name)
+            {
+                None
+            } else {
+                let rust_name = safe_ident(special_cases::maybe_renamed(&class_name, &method.name));
+                Some(format!(
+                    "crate::engine::{}::{rust_name}",
+                    class_name.rust_ty
+                ))
+            }
+        }
+        "enum" => {
+            let (class_name, enum_name) = split_member(target)?;
+            if !is_generated_class(&class_name, ctx) {
+                return None;
+            }
+
+            let class = ctx.get_engine_class(&class_name);
+            let has_enum = class
+                .enums
+                .as_ref()
+                .map_or(false, |enums| enums.iter().any(|e| e.name == enum_name));
+
+            has_enum.then(|| {
+                let module = ModName::from_godot(&class_name.godot_ty).rust_mod;
+                format!("crate::engine::{module}::{}", ident(&enum_name))
+            })
+        }
+        "member" => {
+            let (class_name, property_name) = split_member(target)?;
+            if !is_generated_class(&class_name, ctx) {
+                return None;
+            }
+
+            let property = ctx
+                .get_engine_class(&class_name)
+                .properties
+                .as_ref()?
+                .iter()
+                .find(|p| p.name == property_name)?
+                .clone();
+
+            is_property_const_generated(&property, ctx).then(|| {
+                let module = ModName::from_godot(&class_name.godot_ty).rust_mod;
+                let const_name = to_property_const_name(&property.name);
+                format!("crate::engine::{module}::properties::{const_name}")
+            })
+        }
+        "signal" => {
+            let (class_name, signal_name) = split_member(target)?;
+            if !is_generated_class(&class_name, ctx) {
+                return None;
+            }
+
+            let signal = ctx
+                .get_engine_class(&class_name)
+                .signals
+                .as_ref()?
+                .iter()
+                .find(|s| s.name == signal_name)?
+                .clone();
+
+            is_signal_fn_generated(&signal, ctx).then(|| {
+                let module = ModName::from_godot(&class_name.godot_ty).rust_mod;
+                format!(
+                    "crate::engine::{module}::{}Signals::{}",
+                    class_name.rust_ty,
+                    safe_ident(&signal.name)
+                )
+            })
+        }
+        "constant" => {
+            let (class_name, constant_name) = split_member(target)?;
+            if !is_generated_class(&class_name, ctx) {
+                return None;
+            }
+
+            // Constants are either associated constants of the class, or enumerators of one of its enums.
+            let class = ctx.get_engine_class(&class_name);
+            let is_constant = class.constants.as_ref().map_or(false, |constants| {
+                constants.iter().any(|c| c.name == constant_name)
+            });
+
+            if is_constant {
+                // Notification constants are not public; they are exposed through the notification enum.
+                return (!constant_name.starts_with("NOTIFICATION_")).then(|| {
+                    format!(
+                        "crate::engine::{}::{}",
+                        class_name.rust_ty,
+                        ident(&constant_name)
+                    )
+                });
+            }
+
+            let enum_ = class.enums.as_ref()?.iter().find(|e| {
+                e.values
+                    .iter()
+                    .any(|enumerator| enumerator.name == constant_name)
+            })?;
+
+            let module = ModName::from_godot(&class_name.godot_ty).rust_mod;
+            Some(format!(
+                "crate::engine::{module}::{}::{}",
+                ident(&enum_.name),
+                ident(&constant_name)
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn resolve_class(godot_name: &str, ctx: &Context) -> Option<String> {
+    let builtin = match godot_name {
+        // Primitives and Nil have no dedicated page.
+        "bool" | "int" | "float" | "Nil" => return None,
+        "Variant" => Some("Variant"),
+        "String" => Some("GString"),
+        "AABB" => Some("Aabb"),
+        "RID" => Some("Rid"),
+        _ if ctx.is_builtin(godot_name) => Some(godot_name),
+        _ => None,
+    };
+
+    if let Some(builtin) = builtin {
+        return Some(format!("crate::builtin::{builtin}"));
+    }
+
+    let class_name = TyName::from_godot(godot_name);
+    is_generated_class(&class_name, ctx).then(|| format!("crate::engine::{}", class_name.rust_ty))
+}
+
+fn is_generated_class(class_name: &TyName, ctx: &Context) -> bool {
+    ctx.is_engine_class(class_name) && !special_cases::is_class_deleted(class_name)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// BBCode conversion
+
+/// Converts Godot's BBCode dialect to Markdown.
+///
+/// References like `[Node]`, `[method Node.add_child]` or `[member Node2D.position]` are passed to `resolve` as `(kind, target)`, e.g. `("class", "Node")` or
+/// `("method", "Node.add_child")`. If it returns a Rust path, the reference becomes an intra-doc link; otherwise it is rendered as code.
+pub(crate) fn bbcode_to_markdown(
+    bbcode: &str,
+    resolve: &mut dyn FnMut(&str, &str) -> Option<String>,
+) -> String {
+    let mut out = String::with_capacity(bbcode.len());
+    let mut rest = bbcode.trim();
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '\n' => {
+                // Every line is a paragraph in Godot's docs; indentation stems from the XML file.
+                push_paragraph_break(&mut out);
+                rest = rest.trim_start();
+                continue;
+            }
+            '[' => {}
+            '<' | '*' | '\\' => {
+                out.push('\\');
+                out.push(c);
+                rest = &rest[1..];
+                continue;
+            }
+            _ => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+        }
+
+        let Some(close) = rest.find(']') else {
+            out.push_str("\\[");
+            rest = &rest[1..];
+            continue;
+        };
+
+        let tag = &rest[1..close];
+        rest = &rest[close + 1..];
+
+        let (tag_name, argument) = match tag.split_once([' ', '=']) {
+            Some((name, argument)) => (name, argument),
+            None => (tag, ""),
+        };
+
+        match tag_name {
+            "b" | "/b" => out.push_str("**"),
+            "i" | "/i" => out.push('*'),
+            "s" | "/s" => out.push_str("~~"),
+            "u" | "/u" | "center" | "/center" | "color" | "/color" | "font" | "/font"
+            | "font_size" | "/font_size" | "/url" => {}
+            "br" => push_paragraph_break(&mut out),
+            "lb" => out.push_str("\\["),
+            "rb" => out.push_str("\\]"),
+
+            "code" | "kbd" => {
+                let (content, after) = take_until(rest, &format!("[/{tag_name}]"));
+                push_inline_code(&mut out, &unescape_brackets(content));
+                rest = after;
+            }
+            "codeblock" => {
+                let (content, after) = take_until(rest, "[/codeblock]");
+                push_code_block(&mut out, content);
+                rest = after;
+            }
+            "codeblocks" => {
+                let (content, after) = take_until(rest, "[/codeblocks]");
+                // Only the GDScript variant is kept (C# examples are mostly equivalent).
+                let code = ["gdscript", "csharp"].iter().find_map(|lang| {
+                    let start = content.find(&format!("[{lang}"))?;
+                    let start = start + content[start..].find(']')? + 1;
+                    let (code, _) = take_until(&content[start..], &format!("[/{lang}]"));
+                    Some(code)
+                });
+                push_code_block(&mut out, code.unwrap_or(content));
+                rest = after;
+            }
+            "url" => {
+                let (text, after) = take_until(rest, "[/url]");
+                if argument.is_empty() {
+                    out.push_str(&format!("<{text}>"));
+                } else {
+                    out.push_str(&format!("[{text}]({argument})"));
+                }
+                rest = after;
+            }
+            "img" => {
+                let (_, after) = take_until(rest, "[/img]");
+                rest = after;
+            }
+
+            "param" => push_inline_code(&mut out, argument),
+            "method" | "enum" | "member" | "signal" | "constant" => {
+                let display = match argument.rsplit_once('.') {
+                    Some((_, member)) => member,
+                    None => argument,
+                };
+                let display = if tag_name == "method" {
+                    format!("{}()", display.strip_prefix('_').unwrap_or(display))
+                } else {
+                    display.to_string()
+                };
+                push_link(&mut out, &display, resolve(tag_name, argument));
+            }
+            "annotation" | "constructor" | "operator" | "theme_item" => {
+                push_inline_code(&mut out, argument)
+            }
+
+            _ if is_class_reference(tag) => {
+                let path = resolve("class", tag);
+                let display = match &path {
+                    Some(path) => path.rsplit("::").next().unwrap_or(tag),
+                    None => tag,
+                };
+                push_link(&mut out, display, path.clone());
+            }
+            _ => {
+                // Unknown tag: keep it visible, without turning it into a Markdown link.
+                out.push_str("\\[");
+                out.push_str(tag);
+                out.push_str("\\]");
+            }
+        }
+    }
+
+    out.trim().to_string()
+}
+
+/// Whether the tag is a plain type name, e.g. `[Node]`, `[int]` or `[@GlobalScope]`.
+fn is_class_reference(tag: &str) -> bool {
+    let mut chars = tag.chars();
+    chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '@')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Returns the text before `end_tag` and the remainder after it. If the end tag is missing, everything is taken.
+fn take_until<'s>(text: &'s str, end_tag: &str) -> (&'s str, &'s str) {
+    match text.find(end_tag) {
+        Some(end) => (&text[..end], &text[end + end_tag.len()..]),
+        None => (text, ""),
+    }
+}
+
+fn unescape_brackets(text: &str) -> String {
+    text.replace("[lb]", "[").replace("[rb]", "]")
+}
+
+fn push_paragraph_break(out: &mut String) {
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push_str(if out.ends_with('\n') { "\n" } else { "\n\n" });
+    }
+}
+
+fn push_inline_code(out: &mut String, code: &str) {
+    if code.contains('`') {
+        out.push_str(&format!("`` {code} ``"));
+    } else {
+        out.push_str(&format!("`{code}`"));
+    }
+}
+
+fn push_link(out: &mut String, display: &str, path: Option<String>) {
+    match path {
+        Some(path) => out.push_str(&format!("[`{display}`][{path}]")),
+        None => push_inline_code(out, display),
+    }
+}
+
+/// Appends a fenced code block. Code from Godot's docs is not Rust, so it is marked as `text` (not compiled as doctest).
+fn push_code_block(out: &mut String, code: &str) {
+    let code = unescape_brackets(code);
+    let lines: Vec<&str> = code.lines().collect();
+
+    // Remove indentation stemming from the XML structure.
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let body: Vec<String> = lines
+        .iter()
+        .map(|line| {
+            line.get(indent..)
+                .unwrap_or("")
+                .trim_end()
+                .replace('\t', "    ")
+        })
+        .skip_while(|line| line.is_empty())
+        .collect();
+    let body = body.join("\n");
+
+    push_paragraph_break(out);
+    out.push_str("```text\n");
+    out.push_str(body.trim_end());
+    out.push_str("\n```\n\n");
+}
@@ -0,0 +1,464 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reads Godot's XML class reference (`doc/classes/*.xml`), used to document the generated APIs.
+
+#![allow(clippy::question_mark)] // in #[derive(DeJson)]
+
+use nanoserde::{DeJson, SerJson};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Key under which godot-ffi's build script passes the parsed class reference to godot-core's (`links` metadata).
+const SHARED_DOCS_KEY: &str = "class_docs";
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Doc models
+
+/// Class reference of all engine classes, keyed by Godot class name. Empty if no class reference is available.
+#[derive(Default, SerJson, DeJson)]
+pub struct ClassDocs {
+    classes: HashMap<String, ClassDoc>,
+}
+
+impl ClassDocs {
+    pub fn get(&self, godot_class_name: &str) -> Option<&ClassDoc> {
+        self.classes.get(godot_class_name)
+    }
+
+    /// Godot names of all classes that are marked experimental in the class reference.
+    pub fn experimental_classes(&self) -> impl Iterator<Item = &str> {
+        self.classes
+            .values()
+            .filter(|class| class.is_experimental)
+            .map(|class| class.name.as_str())
+    }
+
+    fn insert(&mut self, class: ClassDoc) {
+        self.classes.insert(class.name.clone(), class);
+    }
+}
+
+#[derive(Default, SerJson, DeJson)]
+pub struct ClassDoc {
+    pub name: String,
+    pub brief_description: String,
+    pub description: String,
+    pub is_experimental: bool,
+
+    /// Regular and virtual methods, keyed by Godot name (virtual ones start with `_`).
+    pub methods: HashMap<String, MemberDoc>,
+    /// Properties, keyed by Godot name.
+    pub members: HashMap<String, MemberDoc>,
+    pub signals: HashMap<String, MemberDoc>,
+    /// Constants and enumerators, keyed by Godot name.
+    pub constants: HashMap<String, MemberDoc>,
+}
+
+#[derive(Clone, Default, SerJson, DeJson)]
+pub struct MemberDoc {
+    /// Description in Godot's BBCode dialect.
+    pub description: String,
+    pub is_deprecated: bool,
+    pub is_experimental: bool,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Loading
+
+/// Loads the class reference from the directory returned by [`godot_bindings::locate_class_docs()`], if any.
+pub fn load_class_docs(watch: &mut godot_bindings::StopWatch) -> ClassDocs {
+    let mut docs = ClassDocs::default();
+
+    let Some(dir) = godot_bindings::locate_class_docs(watch) else {
+        return docs;
+    };
+
+    let mut paths = Vec::new();
+    collect_xml_files(&dir, &mut paths);
+    paths.sort();
+
+    for path in paths {
+        let xml = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read class reference {}: {e}", path.display()));
+
+        // Other XML files (e.g. editor settings) may be present next to class files; ignore them.
+        match parse_class_doc(&xml) {
+            Ok(Some(class)) => docs.insert(class),
+            Ok(None) => {}
+            Err(e) => panic!("failed to parse class reference {}: {e}", path.display()),
+        }
+    }
+
+    println!("Loaded class reference for {} classes", docs.classes.len());
+    watch.record("load_class_docs");
+    docs
+}
+
+/// Writes the class reference to `gen_path` and passes its location to dependent build scripts.
+///
+/// Called by godot-ffi's build script, so that godot-core's build script can use [`load_shared_class_docs()`] instead of
+/// dumping and parsing the class reference a second time.
+pub fn share_class_docs(docs: &ClassDocs, gen_path: &Path, watch: &mut godot_bindings::StopWatch) {
+    let path = gen_path.join("class_docs.json");
+    std::fs::write(&path, docs.serialize_json())
+        .unwrap_or_else(|e| panic!("failed to write class reference {}: {e}", path.display()));
+
+    println!("cargo:{SHARED_DOCS_KEY}={}", path.display());
+    watch.record("share_class_docs");
+}
+
+/// Loads the class reference shared by godot-ffi's build script through [`share_class_docs()`].
+///
+/// Falls back to [`load_class_docs()`] if there is none (e.g. when not invoked from godot-core's build script).
+pub fn load_shared_class_docs(watch: &mut godot_bindings::StopWatch) -> ClassDocs {
+    // Cargo exposes metadata of the `links = "godot"` dependency as DEP_GODOT_<KEY>.
+    let env_var = format!("DEP_GODOT_{}", SHARED_DOCS_KEY.to_uppercase());
+    let Ok(path) = std::env::var(env_var) else {
+        return load_class_docs(watch);
+    };
+
+    println!("cargo:rerun-if-changed={path}");
+    let json = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read class reference {path}: {e}"));
+    let docs = ClassDocs::deserialize_json(&json)
+        .unwrap_or_else(|e| panic!("failed to parse class reference {path}: {e:?}"));
+
+    watch.record("load_shared_class_docs");
+    docs
+}
+
+fn collect_xml_files(dir: &Path, out: &mut Vec<std::path::PathBuf>) {
+    let entries = std::fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("failed to read directory {}: {e}", dir.display()));
+
+    for entry in entries {
+        let path = entry
+            .unwrap_or_else(|e| panic!("failed to read entry in {}: {e}", dir.display()))
+            .path();
+
+        if path.is_dir() {
+            collect_xml_files(&path, out);
+        } else if path.extension().map_or(false, |ext| ext == "xml") {
+            out.push(path);
+        }
+    }
+}
+
+/// Parses a single class file. Returns `Ok(None)` if the XML document is not a class reference.
+pub(crate) fn parse_class_doc(xml: &str) -> Result<Option<ClassDoc>, String> {
+    let root = XmlParser::new(xml).parse_document()?;
+    if root.name != "class" {
+        return Ok(None);
+    }
+
+    let mut class = ClassDoc {
+        name: root.attr("name").unwrap_or_default().to_string(),
+        is_experimental: is_marked(&root, "experimental"),
+        ..Default::default()
+    };
+
+    for child in root.elements() {
+        match child.name.as_str() {
+            "brief_description" => class.brief_description = child.text(),
+            "description" => class.description = child.text(),
+            "methods" => collect_members(child, "method", &mut class.methods),
+            "members" => collect_members(child, "member", &mut class.members),
+            "signals" => collect_members(child, "signal", &mut class.signals),
+            "constants" => collect_members(child, "constant", &mut class.constants),
+            _ => {}
+        }
+    }
+
+    Ok(Some(class))
+}
+
+fn collect_members(list: &XmlElement, tag: &str, out: &mut HashMap<String, MemberDoc>) {
+    for element in list.elements().filter(|e| e.name == tag) {
+        let Some(name) = element.attr("name") else {
+            continue;
+        };
+
+        // Methods and signals nest their description; members and constants contain it directly.
+        let description = match element.child("description") {
+            Some(description) => description.text(),
+            None => element.text(),
+        };
+
+        let doc = MemberDoc {
+            description,
+            is_deprecated: is_marked(element, "deprecated"),
+            is_experimental: is_marked(element, "experimental"),
+        };
+
+        // Overloads are not possible in the engine API, but keep the first occurrence for determinism.
+        out.entry(name.to_string()).or_insert(doc);
+    }
+}
+
+/// Godot 4.2+ uses `experimental="message"`, earlier versions `is_experimental="true"` (same for `deprecated`).
+fn is_marked(element: &XmlElement, marker: &str) -> bool {
+    element.attr(marker).is_some() || element.attr(&format!("is_{marker}")) == Some("true")
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Minimal XML parser
+//
+// The class reference only uses a small subset of XML (elements, attributes, text, entities and comments), so a dedicated
+// dependency is not warranted.
+
+struct XmlElement {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<XmlNode>,
+}
+
+enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+impl XmlElement {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn elements(&self) -> impl Iterator<Item = &XmlElement> {
+        self.children.iter().filter_map(|node| match node {
+            XmlNode::Element(element) => Some(element),
+            XmlNode::Text(_) => None,
+        })
+    }
+
+    fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.elements().find(|e| e.name == name)
+    }
+
+    /// Concatenated text content of direct children, with surrounding whitespace removed.
+    fn text(&self) -> String {
+        let text: String = self
+            .children
+            .iter()
+            .filter_map(|node| match node {
+                XmlNode::Text(text) => Some(text.as_str()),
+                XmlNode::Element(_) => None,
+            })
+            .collect();
+
+        text.trim().to_string()
+    }
+}
+
+struct XmlParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> XmlParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn parse_document(&mut self) -> Result<XmlElement, String> {
+        loop {
+            self.skip_whitespace();
+            if self.eat("<?") {
+                self.skip_past("?>")?;
+            } else if self.eat("<!--") {
+                self.skip_past("-->")?;
+            } else if self.eat("<!") {
+                self.skip_past(">")?;
+            } else {
+                break;
+            }
+        }
+
+        self.expect("<")?;
+        self.parse_element()
+    }
+
+    /// Parses an element after its opening `<`.
+    fn parse_element(&mut self) -> Result<XmlElement, String> {
+        let name = self.parse_name()?;
+        let mut element = XmlElement {
+            name,
+            attributes: Vec::new(),
+            children: Vec::new(),
+        };
+
+        loop {
+            self.skip_whitespace();
+            if self.eat("/>") {
+                return Ok(element);
+            }
+            if self.eat(">") {
+                break;
+            }
+
+            let key = self.parse_name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let value = self.parse_quoted()?;
+            element.attributes.push((key, value));
+        }
+
+        loop {
+            if self.eat("</") {
+                let closing = self.parse_name()?;
+                if closing != element.name {
+                    return Err(
+                        self.error(&format!("expected </{}>, found </{closing}>", element.name))
+                    );
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(element);
+            } else if self.eat("<!--") {
+                self.skip_past("-->")?;
+            } else if self.eat("<![CDATA[") {
+                let end = self.find("]]>")?;
+                let text = self.input[self.pos..end].to_string();
+                self.pos = end + 3;
+                element.children.push(XmlNode::Text(text));
+            } else if self.eat("<") {
+                let child = self.parse_element()?;
+                element.children.push(XmlNode::Element(child));
+            } else if self.pos < self.input.len() {
+                let end = self.input[self.pos..]
+                    .find('<')
+                    .map_or(self.input.len(), |i| self.pos + i);
+                let text = decode_entities(&self.input[self.pos..end]);
+                self.pos = end;
+                element.children.push(XmlNode::Text(text));
+            } else {
+                return Err(self.error(&format!("unclosed element <{}>", element.name)));
+            }
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<String, String> {
+        let rest = &self.input[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | ':' | '.')))
+            .unwrap_or(rest.len());
+
+        if len == 0 {
+            return Err(self.error("expected name"));
+        }
+
+        self.pos += len;
+        Ok(rest[..len].to_string())
+    }
+
+    fn parse_quoted(&mut self) -> Result<String, String> {
+        let quote = if self.eat("\"") {
+            "\""
+        } else if self.eat("'") {
+            "'"
+        } else {
+            return Err(self.error("expected quoted attribute value"));
+        };
+
+        let end = self.find(quote)?;
+        let value = decode_entities(&self.input[self.pos..end]);
+        self.pos = end + 1;
+        Ok(value)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.input[self.pos..].starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{token}`")))
+        }
+    }
+
+    fn find(&self, token: &str) -> Result<usize, String> {
+        self.input[self.pos..]
+            .find(token)
+            .map(|i| self.pos + i)
+            .ok_or_else(|| self.error(&format!("missing `{token}`")))
+    }
+
+    fn skip_past(&mut self, token: &str) -> Result<(), String> {
+        self.pos = self.find(token)? + token.len();
+        Ok(())
+    }
+
+    fn error(&self, message: &str) -> String {
+        let line = self.input[..self.pos].matches('\n').count() + 1;
+        format!("line {line}: {message}")
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => {
+                    let code = if let Some(hex) = entity.strip_prefix("#x") {
+                        u32::from_str_radix(hex, 16).ok()
+                    } else if let Some(dec) = entity.strip_prefix('#') {
+                        dec.parse().ok()
+                    } else {
+                        None
+                    };
+                    code.and_then(char::from_u32)
+                }
+            };
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                // Not a valid entity, keep `&` literally.
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
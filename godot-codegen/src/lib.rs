@@ -9,6 +9,8 @@ mod central_generator;
 mod class_generator;
 mod codegen_special_cases;
 mod context;
+mod doc_converter;
+mod doc_parser;
 mod interface_generator;
 mod special_cases;
 mod util;
@@ -26,6 +28,7 @@ use class_generator::{
     generate_builtin_class_files, generate_class_files, generate_native_structures_files,
 };
 use context::Context;
+use doc_parser::{load_class_docs, load_shared_class_docs, share_class_docs};
 use interface_generator::generate_sys_interface_file;
 use util::{ident, to_pascal_case, to_snake_case};
 use utilities_generator::generate_utilities_file;
@@ -65,7 +68,9 @@ pub fn generate_sys_files(
     watch: &mut godot_bindings::StopWatch,
 ) {
     let (api, build_config) = load_extension_api(watch);
-    let mut ctx = Context::build_from_api(&api);
+    let class_docs = load_class_docs(watch);
+    share_class_docs(&class_docs, sys_gen_path, watch);
+    let mut ctx = Context::build_from_api(&api, class_docs);
     watch.record("build_context");
 
     generate_sys_central_file(&api, &mut ctx, build_config, sys_gen_path, &mut submit_fn);
@@ -95,7 +100,8 @@ pub fn generate_core_files(core_gen_path: &Path) {
     generate_core_mod_file(core_gen_path, &mut submit_fn);
 
     let (api, build_config) = load_extension_api(&mut watch);
-    let mut ctx = Context::build_from_api(&api);
+    let class_docs = load_shared_class_docs(&mut watch);
+    let mut ctx = Context::build_from_api(&api, class_docs);
     watch.record("build_context");
 
     generate_core_central_file(&api, &mut ctx, build_config, core_gen_path, &mut submit_fn);
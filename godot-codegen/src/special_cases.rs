@@ -20,6 +20,8 @@
 use crate::api_parser::{BuiltinClassMethod, ClassMethod};
 use crate::Context;
 use crate::{codegen_special_cases, TyName};
+use std::collections::HashSet;
+use std::sync::OnceLock;
 
 #[rustfmt::skip]
 pub(crate) fn is_deleted(class_name: &TyName, method: &ClassMethod, ctx: &mut Context) -> bool {
@@ -100,10 +102,21 @@ pub(crate) fn is_class_deleted(class_name: &TyName) -> bool {
     }
 }
 
+/// Classes marked as experimental in Godot's class reference, if it is available. Complements the hardcoded list below.
+static DOC_EXPERIMENTAL_CLASSES: OnceLock<HashSet<String>> = OnceLock::new();
+
+pub(crate) fn register_experimental_classes<'c>(classes: impl Iterator<Item = &'c str>) {
+    // Only the first registration counts; the class reference does not change during a build.
+    let _ = DOC_EXPERIMENTAL_CLASSES.set(classes.map(str::to_string).collect());
+}
+
 #[rustfmt::skip]
 fn is_class_experimental(class_name: &TyName) -> bool {
-    // These classes are currently hardcoded, but the information is available in Godot's doc/classes directory.
-    // The XML file contains a property <class name="NavigationMesh" ... is_experimental="true">.
+    // The XML class reference contains a property <class name="NavigationMesh" ... is_experimental="true">. It is only read if
+    // available (see GODOT4_DOCS_DIR), so the following classes remain hardcoded.
+    if DOC_EXPERIMENTAL_CLASSES.get().map_or(false, |classes| classes.contains(&class_name.godot_ty)) {
+        return true;
+    }
 
     match class_name.godot_ty.as_str() {
         | "GraphEdit"
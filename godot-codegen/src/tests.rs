@@ -4,6 +4,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::codegen_special_cases::referenced_class_name;
+use crate::doc_converter::bbcode_to_markdown;
+use crate::doc_parser::parse_class_doc;
 use crate::util::{
     parse_native_structures_format, to_pascal_case, to_snake_case, NativeStructuresField,
 };
@@ -208,3 +211,135 @@ fn test_parse_native_structures_format() {
     ];
     assert_eq!(actual.unwrap(), expected);
 }
+
+#[test]
+fn test_parse_class_doc() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<class name="Node2D" inherits="CanvasItem" experimental="" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+	<brief_description>
+		A 2D game object.
+	</brief_description>
+	<description>
+		Has a position &amp; rotation.
+	</description>
+	<methods>
+		<method name="apply_scale">
+			<return type="void" />
+			<param index="0" name="ratio" type="Vector2" />
+			<description>
+				Multiplies the current scale by the [param ratio] vector.
+			</description>
+		</method>
+	</methods>
+	<members>
+		<member name="position" type="Vector2" setter="set_position" getter="get_position" default="Vector2(0, 0)">
+			Position, relative to the node's parent.
+		</member>
+	</members>
+	<constants>
+		<constant name="MODE_A" value="0" enum="Mode" is_deprecated="true">
+		</constant>
+	</constants>
+</class>
+"#;
+
+    let class = parse_class_doc(xml).unwrap().expect("class element");
+    assert_eq!(class.name, "Node2D");
+    assert!(class.is_experimental);
+    assert_eq!(class.brief_description, "A 2D game object.");
+    assert_eq!(class.description, "Has a position & rotation.");
+    assert_eq!(
+        class.methods["apply_scale"].description,
+        "Multiplies the current scale by the [param ratio] vector."
+    );
+    assert_eq!(
+        class.members["position"].description,
+        "Position, relative to the node's parent."
+    );
+    assert!(class.constants["MODE_A"].is_deprecated);
+    assert!(class.constants["MODE_A"].description.is_empty());
+
+    assert!(parse_class_doc("<editor_settings></editor_settings>")
+        .unwrap()
+        .is_none());
+    assert!(parse_class_doc("<class><methods></class>").is_err());
+}
+
+#[test]
+fn test_bbcode_to_markdown() {
+    let mut resolve = |kind: &str, target: &str| match (kind, target) {
+        ("class", "Node") => Some("crate::engine::Node".to_string()),
+        ("class", "String") => Some("crate::builtin::GString".to_string()),
+        ("method", "Node.add_child") => Some("crate::engine::Node::add_child".to_string()),
+        ("member", "Node2D.position") => {
+            Some("crate::engine::node_2d::properties::POSITION".to_string())
+        }
+        ("signal", "ready") => Some("crate::engine::node::NodeSignals::ready".to_string()),
+        ("constant", "Node.PROCESS_MODE_INHERIT") => {
+            Some("crate::engine::node::ProcessMode::PROCESS_MODE_INHERIT".to_string())
+        }
+        _ => None,
+    };
+
+    assert_eq!(
+        bbcode_to_markdown(
+            "A [b]bold[/b] and [i]italic[/i] [code]x[lb]0[rb][/code].",
+            &mut resolve
+        ),
+        "A **bold** and *italic* `x[0]`."
+    );
+    assert_eq!(
+        bbcode_to_markdown("See [Node] and [String], not [int].", &mut resolve),
+        "See [`Node`][crate::engine::Node] and [`GString`][crate::builtin::GString], not `int`."
+    );
+    assert_eq!(
+        bbcode_to_markdown(
+            "Call [method Node.add_child] or [method remove_child] with [param node].",
+            &mut resolve
+        ),
+        "Call [`add_child()`][crate::engine::Node::add_child] or `remove_child()` with `node`."
+    );
+    assert_eq!(
+        bbcode_to_markdown(
+            "Set [member Node2D.position] after [signal ready], unless [constant Node.PROCESS_MODE_INHERIT] or [constant MAX].",
+            &mut resolve
+        ),
+        "Set [`position`][crate::engine::node_2d::properties::POSITION] after [`ready`][crate::engine::node::NodeSignals::ready], \
+        unless [`PROCESS_MODE_INHERIT`][crate::engine::node::ProcessMode::PROCESS_MODE_INHERIT] or `MAX`."
+    );
+    assert_eq!(
+        bbcode_to_markdown("First line.\n\t\tSecond line.", &mut resolve),
+        "First line.\n\nSecond line."
+    );
+    assert_eq!(
+        bbcode_to_markdown(
+            "[url=https://godotengine.org]Godot[/url] <3 *",
+            &mut resolve
+        ),
+        "[Godot](https://godotengine.org) \\<3 \\*"
+    );
+    assert_eq!(
+        bbcode_to_markdown(
+            "Example:\n\t\t[codeblocks]\n\t\t[gdscript]\n\t\tif x:\n\t\t\tprint(x)\n\t\t[/gdscript]\n\t\t[csharp]\n\t\tGD.Print(x);\n\t\t[/csharp]\n\t\t[/codeblocks]\n\t\tDone.",
+            &mut resolve
+        ),
+        "Example:\n\n```text\nif x:\n    print(x)\n```\n\nDone."
+    );
+}
+
+#[test]
+fn test_referenced_class_name() {
+    #[rustfmt::skip]
+    let mappings = [
+        ("Node",                          "Node"),
+        ("enum::Node.ProcessMode",        "Node"),
+        ("bitfield::Control.SizeFlags",   "Control"),
+        ("typedarray::Node2D",            "Node2D"),
+        ("enum::Error",                   ""),
+        ("int",                           "int"),
+    ];
+
+    for (ty, class) in mappings {
+        assert_eq!(referenced_class_name(ty), class, "type: {ty}");
+    }
+}
@@ -13,6 +13,7 @@ use crate::{Context, GodotTy, ModName, RustTy, TyName};
 use proc_macro2::{Ident, Literal, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 
+use std::collections::HashMap;
 use std::fmt;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -212,6 +213,14 @@ pub fn get_api_level(class: &Class) -> ClassCodegenLevel {
 }
 
 pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
+    make_documented_enum_definition(enum_, &HashMap::new())
+}
+
+/// Like [`make_enum_definition`], with rustdoc for enumerators, keyed by Godot enumerator name.
+pub fn make_documented_enum_definition(
+    enum_: &Enum,
+    enumerator_docs: &HashMap<String, String>,
+) -> TokenStream {
     // TODO enums which have unique ords could be represented as Rust enums
     // This would allow exhaustive matches (or at least auto-completed matches + #[non_exhaustive]). But even without #[non_exhaustive],
     // this might be a forward compatibility hazard, if Godot deprecates enumerators and adds new ones with existing ords.
@@ -226,8 +235,12 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
     for enumerator in values {
         let name = make_enumerator_name(&enumerator.name, &enum_.name);
         let ordinal = make_enumerator_ord(enumerator.value);
+        let doc = enumerator_docs
+            .get(&enumerator.name)
+            .map(|doc| quote! { #[doc = #doc] });
 
         enumerators.push(quote! {
+            #doc
             pub const #name: Self = Self { ord: #ordinal };
         });
         // matches.push(quote! {
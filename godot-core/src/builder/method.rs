@@ -4,136 +4,585 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-// use crate::GodotClass;
-// use godot_core::Variant;
-// use godot_ffi as sys;
-// use std::borrow::Cow;
-// use std::ffi::CStr;
-
-// pub trait Method<C> {
-//     type ReturnType;
-//     type ParamTypes;
-//
-//     fn method_name(&self) -> Cow<CStr>;
-//     fn ptrcall(&mut self, instance: &mut C, args: Self::ParamTypes) -> Self::ReturnType;
-// }
+use std::panic::AssertUnwindSafe;
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::meta::registration::method::{MethodInfo, MethodParamOrReturnInfo};
+use crate::builtin::meta::{ClassName, PropertyInfo};
+use crate::builtin::{GString, StringName, Variant, VariantType};
+use crate::engine::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
+use crate::obj::GodotClass;
+use crate::private::BorrowContext;
+
+use super::ClassBuilder;
+
+type ErasedMethodFn = dyn Fn(sys::GDExtensionClassInstancePtr, &[Variant]) -> Variant + Send + Sync;
+
+/// Declares a method of a [`ClassBuilder`]. Created by [`ClassBuilder::method()`] or [`ClassBuilder::method_mut()`].
+#[must_use]
+pub struct MethodBuilder<'cb, C> {
+    class_builder: &'cb mut ClassBuilder<C>,
+    method: DynamicMethod,
+}
+
+impl<'cb, C> MethodBuilder<'cb, C>
+where
+    C: GodotClass,
+{
+    pub(super) fn new(
+        class_builder: &'cb mut ClassBuilder<C>,
+        name: &str,
+        func: Box<ErasedMethodFn>,
+    ) -> Self {
+        Self {
+            class_builder,
+            method: DynamicMethod {
+                name: name.to_string(),
+                params: Vec::new(),
+                default_args: Vec::new(),
+                return_type: None,
+                is_vararg: false,
+                func,
+            },
+        }
+    }
+
+    /// Adds a parameter. If `variant_type` is [`VariantType::Nil`], arguments of any type are accepted.
+    ///
+    /// # Panics
+    /// If a parameter with default value has been declared before.
+    pub fn param(mut self, name: &str, variant_type: VariantType) -> Self {
+        assert!(
+            self.method.default_args.is_empty(),
+            "method `{}`: parameter `{name}` without default value cannot follow parameters with default value",
+            self.method.name
+        );
+
+        self.method
+            .params
+            .push(DynamicParam::new(name, variant_type));
+        self
+    }
+
+    /// Adds a parameter, which is set to `default` if the caller omits it.
+    ///
+    /// Parameters with default value must come last.
+    pub fn param_with_default(
+        mut self,
+        name: &str,
+        variant_type: VariantType,
+        default: Variant,
+    ) -> Self {
+        self.method
+            .params
+            .push(DynamicParam::new(name, variant_type));
+        self.method.default_args.push(default);
+        self
+    }
+
+    /// Declares the type of the returned value. By default, the method returns `Variant`.
+    pub fn returns(mut self, variant_type: VariantType) -> Self {
+        self.method.return_type = Some(variant_type);
+        self
+    }
+
+    /// Accepts arguments beyond the declared parameters, which are passed on to the method after the declared ones.
+    pub fn varargs(mut self) -> Self {
+        self.method.is_vararg = true;
+        self
+    }
+
+    /// Adds the method to the class.
+    pub fn done(self) {
+        self.class_builder.data.methods.push(self.method);
+    }
+}
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
-/*
+// Dynamic methods
 
-/// Method known at compile time (statically), either a Rust `fn` or closure.
-pub trait CodeMethod<C, R, Ps>
+/// Wraps a method taking `&C`, which only borrows the instance immutably during the call.
+pub(super) fn erase_method<C, F>(name: &str, method: F) -> Box<ErasedMethodFn>
 where
     C: GodotClass,
+    F: Fn(&C, &[Variant]) -> Variant + Send + Sync + 'static,
 {
-    const PARAM_COUNT: usize;
-    const NAME: &'static str;
+    let context = BorrowContext::Dynamic(name.into());
 
-    unsafe fn varcall(
-        &mut self,
-        instance: sys::GDExtensionClassInstancePtr,
-        args: *const sys::GDExtensionTypePtr,
-        ret: sys::GDExtensionTypePtr,
-        err: *mut sys::GDExtensionCallError,
-    );
+    Box::new(move |instance_ptr, args| {
+        let storage = unsafe { crate::private::as_storage::<C>(instance_ptr) };
+        let instance = storage.get_for(context.clone());
 
-    unsafe fn ptrcall(
-        &mut self,
-        instance: sys::GDExtensionClassInstancePtr,
-        args: *const sys::GDExtensionTypePtr,
-        ret: sys::GDExtensionTypePtr,
-    );
+        method(&*instance, args)
+    })
 }
 
+/// Wraps a method taking `&mut C`, which borrows the instance exclusively during the call.
+pub(super) fn erase_method_mut<C, F>(name: &str, method: F) -> Box<ErasedMethodFn>
+where
+    C: GodotClass,
+    F: Fn(&mut C, &[Variant]) -> Variant + Send + Sync + 'static,
+{
+    let context = BorrowContext::Dynamic(name.into());
 
-// TODO code duplication ((2))
-macro_rules! count_idents {
-    () => {
-        0
-    };
-    ($name:ident, $($other:ident,)*) => {
-        1 + $crate::gdext_count_idents!($($other,)*)
+    Box::new(move |instance_ptr, args| {
+        let storage = unsafe { crate::private::as_storage::<C>(instance_ptr) };
+        let mut instance = storage.get_mut_for(context.clone());
+
+        method(&mut *instance, args)
+    })
+}
+
+pub(super) struct DynamicParam {
+    name: String,
+    variant_type: VariantType,
+}
+
+impl DynamicParam {
+    pub(super) fn new(name: &str, variant_type: VariantType) -> Self {
+        Self {
+            name: name.to_string(),
+            variant_type,
+        }
+    }
+
+    /// Converts an argument to the parameter's type, following Godot's rules for typed parameters (e.g. `int` is accepted for `float`).
+    ///
+    /// Returns `None` if the argument cannot be converted.
+    fn convert_arg(&self, arg: &Variant) -> Option<Variant> {
+        let from_type = arg.get_type();
+        if self.variant_type == VariantType::Nil || from_type == self.variant_type {
+            return Some(arg.clone());
+        }
+
+        let can_convert = unsafe {
+            interface_fn!(variant_can_convert_strict)(from_type.sys(), self.variant_type.sys())
+        };
+        if can_convert == 0 {
+            return None;
+        }
+
+        // Constructing the target type from a single argument performs the conversion.
+        let mut err = sys::default_call_error();
+        let args = [arg.var_sys_const()];
+        let converted = unsafe {
+            Variant::from_var_sys_init(|variant_ptr| {
+                interface_fn!(variant_construct)(
+                    self.variant_type.sys(),
+                    variant_ptr,
+                    args.as_ptr(),
+                    1,
+                    &mut err,
+                )
+            })
+        };
+
+        (err.error == sys::GDEXTENSION_CALL_OK).then_some(converted)
+    }
+
+    pub(super) fn property_info(&self) -> PropertyInfo {
+        // Nil declares a parameter of type Variant.
+        let usage = if self.variant_type == VariantType::Nil {
+            PropertyUsageFlags::PROPERTY_USAGE_NIL_IS_VARIANT
+        } else {
+            PropertyUsageFlags::PROPERTY_USAGE_DEFAULT
+        };
+
+        PropertyInfo {
+            variant_type: self.variant_type,
+            class_name: ClassName::none(),
+            property_name: StringName::from(self.name.as_str()),
+            hint: PropertyHint::PROPERTY_HINT_NONE,
+            hint_string: GString::new(),
+            usage,
+        }
     }
 }
 
-macro_rules! impl_code_method {
-// 	( $( $Param:ident ),* ) => {
-    ( $( $Param:ident $arg:ident ),* ) => {
-        impl<C, F, R, $( $Param ),*> CodeMethod<C, R, ( $( $Param, )* )> for F
-        where
-            C: $crate::GodotClass + $crate::GodotDefault, // TODO only GodotClass
-            F: Fn(&C, $( $Param ),* ) -> R,
-            $(
-                $Param: sys::GodotFfi,
-            )*
-            R: sys::GodotFfi + 'static,
-        {
-            const PARAM_COUNT: usize = count_idents!($( $Param, )*);
-
-            // Varcall
-            #[inline]
-            #[allow(unused_variables, unused_assignments, unused_mut)]
-            unsafe fn varcall(
-                &mut self,
-                instance: sys::GDExtensionClassInstancePtr,
-                args: *const sys::GDExtensionTypePtr,
-                ret: sys::GDExtensionTypePtr,
-                err: *mut sys::GDExtensionCallError,
-            ) {
-                let storage = ::godot_core::private::as_storage::<C>(instance);
-                let instance = storage.get_mut_lateinit();
-
-                let mut idx = 0;
-
-                $(
-                    let $arg = <$Param as FromVariant::from_variant(&*(*args.offset(idx) as *mut Variant));
-                    idx += 1;
-                )*
-
-                let ret_val = self(&instance, $(
-                    $arg,
-                )*);
-
-                *(ret as *mut Variant) = Variant::from(ret_val);
-                (*err).error = sys::GDExtensionCallErrorType_GDEXTENSION_CALL_OK;
-            }
+/// Method declared through a builder, which is registered as vararg method and validates its arguments at runtime.
+pub(super) struct DynamicMethod {
+    name: String,
+    params: Vec<DynamicParam>,
+    default_args: Vec<Variant>,
+    return_type: Option<VariantType>,
+    is_vararg: bool,
+    func: Box<ErasedMethodFn>,
+}
 
+impl DynamicMethod {
+    /// Registers the method with Godot. `self` must stay at the same address for as long as the class is registered.
+    pub(super) fn register(&self, class_name: ClassName) {
+        let arguments = self
+            .params
+            .iter()
+            .map(|param| {
+                MethodParamOrReturnInfo::new(
+                    param.property_info(),
+                    sys::GDEXTENSION_METHOD_ARGUMENT_METADATA_NONE,
+                )
+            })
+            .collect();
+
+        let return_value = DynamicParam::new("", self.return_type.unwrap_or(VariantType::Nil));
+        let return_value = MethodParamOrReturnInfo::new(
+            return_value.property_info(),
+            sys::GDEXTENSION_METHOD_ARGUMENT_METADATA_NONE,
+        );
+
+        // SAFETY: call_dynamic_method() interprets the userdata as `DynamicMethod`, which the caller keeps alive. Varargs are
+        // interpreted according to `self.params`.
+        let method_info = unsafe {
+            MethodInfo::from_dynamic(
+                class_name,
+                StringName::from(self.name.as_str()),
+                self as *const Self as *mut std::ffi::c_void,
+                Some(call_dynamic_method),
+                MethodFlags::METHOD_FLAGS_DEFAULT | MethodFlags::METHOD_FLAG_VARARG,
+                Some(return_value),
+                arguments,
+                self.default_args.clone(),
+            )
+        };
+
+        method_info.register_extension_class_method();
+    }
+
+    /// Copies the arguments, filling in default values and checking them against the declared parameters.
+    ///
+    /// # Safety
+    /// `args_ptr` must point to `arg_count` valid variant pointers.
+    unsafe fn collect_args(
+        &self,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
+    ) -> Result<Vec<Variant>, sys::GDExtensionCallError> {
+        let provided = arg_count as usize;
+        let param_count = self.params.len();
+        let required = param_count - self.default_args.len();
+
+        if provided < required {
+            return Err(call_error(
+                sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS,
+                required,
+                required,
+            ));
+        }
+        if provided > param_count && !self.is_vararg {
+            return Err(call_error(
+                sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS,
+                param_count,
+                param_count,
+            ));
+        }
+
+        let mut args: Vec<Variant> = (0..provided)
+            .map(|i| (*Variant::ptr_from_sys(*args_ptr.add(i))).clone())
+            .collect();
+
+        if provided < param_count {
+            args.extend_from_slice(&self.default_args[provided - required..]);
+        }
 
-            // Ptrcall
-            #[inline]
-            #[allow(unused_variables, unused_assignments, unused_mut)]
-            unsafe fn ptrcall(
-                &mut self,
-                instance: sys::GDExtensionClassInstancePtr,
-                args: *const sys::GDExtensionTypePtr,
-                ret: sys::GDExtensionTypePtr,
-            ) {
-                let storage = $crate::private::as_storage::<C>(instance);
-                let instance = storage.get_mut_lateinit();
-
-                // TODO reuse code, see ((1))
-                let mut idx = 0;
-
-                $(
-                    let $arg = <$Param as sys::GodotFfi>::from_sys(*args.offset(idx));
-                    // FIXME update refcount, e.g. Gd::ready() or T::Mem::maybe_inc_ref(&result);
-                    // possibly in from_sys() directly; what about from_sys_init() and from_{obj|str}_sys()?
-                    idx += 1;
-                )*
-
-                let ret_val = self(&instance, $(
-                    $arg,
-                )*);
-
-                <R as sys::GodotFfi>::write_sys(&ret_val, ret);
+        for (index, (param, arg)) in self.params.iter().zip(args.iter_mut()).enumerate() {
+            match param.convert_arg(arg) {
+                Some(converted) => *arg = converted,
+                None => {
+                    return Err(call_error(
+                        sys::GDEXTENSION_CALL_ERROR_INVALID_ARGUMENT,
+                        index,
+                        param.variant_type.sys() as usize,
+                    ))
+                }
             }
         }
 
+        Ok(args)
+    }
+}
+
+fn call_error(
+    error: sys::GDExtensionCallErrorType,
+    argument: usize,
+    expected: usize,
+) -> sys::GDExtensionCallError {
+    let mut err = sys::default_call_error();
+    err.error = error;
+    err.argument = argument as i32;
+    err.expected = expected as i32;
+    err
+}
+
+unsafe extern "C" fn call_dynamic_method(
+    method_data: *mut std::ffi::c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+) {
+    let method = &*(method_data as *const DynamicMethod);
+
+    let args = match method.collect_args(args_ptr, arg_count) {
+        Ok(args) => args,
+        Err(call_error) => {
+            *err = call_error;
+            return;
+        }
     };
+
+    let result = crate::private::handle_panic(
+        || method.name.as_str(),
+        AssertUnwindSafe(|| (method.func)(instance_ptr, &args)),
+    );
+
+    match result {
+        Some(value) => {
+            *(ret as *mut Variant) = value;
+            (*err).error = sys::GDEXTENSION_CALL_OK;
+        }
+        None => {
+            // Signal error and set return type to Nil.
+            (*err).error = sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD; // no better fitting enum?
+
+            // TODO(uninit)
+            interface_fn!(variant_new_nil)(sys::AsUninit::as_uninit(ret));
+        }
+    }
 }
 
-impl_code_method!();
-impl_code_method!(P0 arg0);
-impl_code_method!(P0 arg0, P1 arg1);
-impl_code_method!(P0 arg0, P1 arg1, P2 arg2);
-*/
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Virtual methods
+
+#[cfg(since_api = "4.2")]
+pub use virtuals::VirtualMethod;
+#[cfg(since_api = "4.2")]
+pub(super) use virtuals::*;
+
+#[cfg(since_api = "4.2")]
+mod virtuals {
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+    use std::panic::AssertUnwindSafe;
+    use std::sync::Mutex;
+
+    use godot_ffi as sys;
+
+    use crate::builtin::meta::{ClassName, FromGodot, PtrcallSignatureTuple, ToGodot};
+    use crate::builtin::StringName;
+    use crate::obj::GodotClass;
+    use crate::private::BorrowContext;
+
+    type ErasedVirtualFn = dyn Fn(
+            sys::GDExtensionClassInstancePtr,
+            *const sys::GDExtensionConstTypePtr,
+            sys::GDExtensionTypePtr,
+        ) + Send
+        + Sync;
+
+    type StaticVirtualFn = unsafe extern "C" fn(
+        sys::GDExtensionClassInstancePtr,
+        *const sys::GDExtensionConstTypePtr,
+        sys::GDExtensionTypePtr,
+    );
+
+    type GetVirtualFn = unsafe extern "C" fn(
+        *mut std::ffi::c_void,
+        sys::GDExtensionConstStringNamePtr,
+    ) -> sys::GDExtensionClassCallVirtual;
+
+    /// Function or closure that can override an engine virtual method. See [`ClassBuilder::virtual_method()`][super::ClassBuilder::virtual_method].
+    ///
+    /// Implemented for `Fn(&mut C, P0, P1, ...) -> R` with up to 5 parameters, where the parameter and return types are convertible
+    /// to and from Godot. `Sig` is a marker type that disambiguates the implementations for different arities.
+    pub trait VirtualMethod<C, Sig>: Send + Sync + 'static {
+        /// Invokes the method with arguments passed as ptrcall.
+        ///
+        /// # Safety
+        /// `instance_ptr`, `args_ptr` and `ret` must be valid for a virtual call with the signature `Sig`.
+        #[doc(hidden)]
+        unsafe fn __virtual_ptrcall(
+            &self,
+            instance_ptr: sys::GDExtensionClassInstancePtr,
+            method_name: &BorrowContext,
+            args_ptr: *const sys::GDExtensionConstTypePtr,
+            ret: sys::GDExtensionTypePtr,
+        );
+    }
+
+    macro_rules! impl_virtual_method {
+        ($( $Pn:ident $pn:ident ),*) => {
+            impl<C, F, R, $( $Pn, )*> VirtualMethod<C, (R, $( $Pn, )*)> for F
+            where
+                C: GodotClass,
+                F: Fn(&mut C, $( $Pn, )*) -> R + Send + Sync + 'static,
+                R: ToGodot + FromGodot + Debug,
+                $( $Pn: ToGodot + FromGodot + Debug, )*
+            {
+                unsafe fn __virtual_ptrcall(
+                    &self,
+                    instance_ptr: sys::GDExtensionClassInstancePtr,
+                    method_name: &BorrowContext,
+                    args_ptr: *const sys::GDExtensionConstTypePtr,
+                    ret: sys::GDExtensionTypePtr,
+                ) {
+                    <(R, $( $Pn, )*) as PtrcallSignatureTuple>::in_ptrcall(
+                        instance_ptr,
+                        method_name.as_str(),
+                        args_ptr,
+                        ret,
+                        |instance_ptr, ( $( $pn, )* )| {
+                            let storage = unsafe { crate::private::as_storage::<C>(instance_ptr) };
+                            let mut instance = storage.get_mut_for(method_name.clone());
+
+                            self(&mut *instance, $( $pn, )*)
+                        },
+                        sys::PtrcallType::Virtual,
+                    )
+                }
+            }
+        };
+    }
+
+    impl_virtual_method!();
+    impl_virtual_method!(P0 p0);
+    impl_virtual_method!(P0 p0, P1 p1);
+    impl_virtual_method!(P0 p0, P1 p1, P2 p2);
+    impl_virtual_method!(P0 p0, P1 p1, P2 p2, P3 p3);
+    impl_virtual_method!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4);
+
+    /// Implementation of a virtual method, passed to Godot as userdata of the virtual call.
+    pub enum VirtualEntry {
+        /// Implemented in the class's virtual trait, and looked up through its `get_virtual_func`.
+        Static(StaticVirtualFn),
+
+        /// Declared through `ClassBuilder::virtual_method()`.
+        Dynamic(Box<ErasedVirtualFn>),
+    }
+
+    pub fn erase_virtual_method<C, Sig, F>(name: &str, method: F) -> VirtualEntry
+    where
+        C: GodotClass,
+        Sig: 'static,
+        F: VirtualMethod<C, Sig>,
+    {
+        let method_name = BorrowContext::Dynamic(name.into());
+
+        let virtual_fn = move |instance_ptr: sys::GDExtensionClassInstancePtr,
+                               args_ptr: *const sys::GDExtensionConstTypePtr,
+                               ret: sys::GDExtensionTypePtr| {
+            // SAFETY: only invoked by call_virtual_with_data(), with the arguments of the virtual call.
+            unsafe { method.__virtual_ptrcall(instance_ptr, &method_name, args_ptr, ret) }
+        };
+
+        VirtualEntry::Dynamic(Box::new(virtual_fn))
+    }
+
+    /// Virtual methods of one class, passed to Godot as class userdata.
+    ///
+    /// When Godot looks up a virtual method for the first time, an entry is created (if the class implements the method). The
+    /// entries are boxed, so their addresses stay stable while more entries are added.
+    pub struct VirtualTable {
+        static_get_virtual: Option<GetVirtualFn>,
+        entries: Mutex<HashMap<String, Box<VirtualEntry>>>,
+
+        /// Rust base class with its own table, whose builder-declared virtuals are inherited.
+        parent_class_name: Option<ClassName>,
+    }
+
+    impl VirtualTable {
+        pub fn new(
+            static_get_virtual: Option<GetVirtualFn>,
+            dynamic: HashMap<String, VirtualEntry>,
+            parent_class_name: Option<ClassName>,
+        ) -> Self {
+            let entries = dynamic
+                .into_iter()
+                .map(|(name, entry)| (name, Box::new(entry)))
+                .collect();
+
+            Self {
+                static_get_virtual,
+                entries: Mutex::new(entries),
+                parent_class_name,
+            }
+        }
+
+        /// Returns the entry for `method_name`, or null if the class does not implement it.
+        ///
+        /// Looks up, in this order: virtuals declared through this class's builder, those of its virtual trait (including the
+        /// trait impls of Rust base classes), and finally those declared through the builders of Rust base classes.
+        unsafe fn lookup(
+            &self,
+            name: sys::GDExtensionConstStringNamePtr,
+            method_name: &str,
+        ) -> *mut std::ffi::c_void {
+            let mut entries = self.entries.lock().unwrap();
+            if !entries.contains_key(method_name) {
+                let static_fn = match self.static_get_virtual {
+                    Some(get_virtual) => get_virtual(std::ptr::null_mut(), name),
+                    None => None,
+                };
+
+                let Some(static_fn) = static_fn else {
+                    drop(entries);
+
+                    // The base class is unregistered after this one, so its table and entries outlive this call.
+                    return match self
+                        .parent_class_name
+                        .and_then(|parent| super::super::virtual_table_ptr(&parent))
+                    {
+                        Some(parent_table) => (*parent_table).lookup(name, method_name),
+                        None => std::ptr::null_mut(),
+                    };
+                };
+
+                entries.insert(
+                    method_name.to_string(),
+                    Box::new(VirtualEntry::Static(static_fn)),
+                );
+            }
+
+            let entry: &VirtualEntry = &entries[method_name];
+            entry as *const VirtualEntry as *mut std::ffi::c_void
+        }
+    }
+
+    pub unsafe extern "C" fn get_virtual_call_data(
+        class_userdata: *mut std::ffi::c_void,
+        name: sys::GDExtensionConstStringNamePtr,
+    ) -> *mut std::ffi::c_void {
+        let table = &*(class_userdata as *const VirtualTable);
+
+        // This string is not ours, so we cannot call the destructor on it.
+        let borrowed_string = StringName::from_string_sys(sys::force_mut_ptr(name));
+        let method_name = borrowed_string.to_string();
+        std::mem::forget(borrowed_string);
+
+        table.lookup(name, &method_name)
+    }
+
+    pub unsafe extern "C" fn call_virtual_with_data(
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+        name: sys::GDExtensionConstStringNamePtr,
+        virtual_call_userdata: *mut std::ffi::c_void,
+        args_ptr: *const sys::GDExtensionConstTypePtr,
+        ret: sys::GDExtensionTypePtr,
+    ) {
+        match &*(virtual_call_userdata as *const VirtualEntry) {
+            VirtualEntry::Static(static_fn) => static_fn(instance_ptr, args_ptr, ret),
+            VirtualEntry::Dynamic(dynamic_fn) => {
+                let borrowed_string = StringName::from_string_sys(sys::force_mut_ptr(name));
+                let method_name = borrowed_string.to_string();
+                std::mem::forget(borrowed_string);
+
+                // Virtual calls have no means to report errors; a panic is printed and the return value left untouched.
+                let _ = crate::private::handle_panic(
+                    || method_name.as_str(),
+                    AssertUnwindSafe(|| dynamic_fn(instance_ptr, args_ptr, ret)),
+                );
+            }
+        }
+    }
+}
@@ -4,12 +4,80 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::obj::GodotClass;
+//! Programmatic registration of class members, without proc-macros.
+//!
+//! See [`ClassBuilder`] for details.
+
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::builtin::meta::registration::constant::{ConstantKind, ExportConstant, IntegerConstant};
+use crate::builtin::meta::ClassName;
+use crate::builtin::{StringName, Variant, VariantType};
+use crate::obj::GodotClass;
 
 mod method;
+mod property;
+mod signal;
 
+pub use method::*;
+pub use property::*;
+pub use signal::*;
+
+/// Registers methods, properties, signals, constants and virtual method overrides of a class at runtime.
+///
+/// A `ClassBuilder` is passed to the `register_class()` function of the class's virtual trait, e.g.
+/// [`IRefCounted::register_class()`][crate::engine::IRefCounted::register_class]. This function is invoked once, when the
+/// class is registered with Godot. Since all members are declared through regular function calls, their shape does not need to
+/// be known at compile time -- they can for example be generated from a data file.
+///
+/// The class itself is still declared in Rust with `#[derive(GodotClass)]`, which determines its name, its base class and the
+/// struct holding the instance data. Data of members that are only known at runtime is kept in a field of that struct, typically
+/// a [`Dictionary`][crate::builtin::Dictionary] keyed by member name. Classes without a Rust declaration cannot be registered.
+///
+/// Members registered through the builder can be freely combined with `#[func]`, `#[var]`, `#[signal]` etc. of the same class.
+/// They are added after the macro-generated ones, so a property declared here can use a `#[func]` as its getter.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::builder::ClassBuilder;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=RefCounted)]
+/// struct Inventory {
+///     items: Dictionary,
+///     #[base]
+///     base: Base<RefCounted>,
+/// }
+///
+/// #[godot_api]
+/// impl IRefCounted for Inventory {
+///     fn register_class(builder: &mut ClassBuilder<Self>) {
+///         builder
+///             .method("get_count", |this: &Self, args: &[Variant]| {
+///                 this.items.get(args[0].clone()).unwrap_or(0.to_variant())
+///             })
+///             .param("item", VariantType::String)
+///             .returns(VariantType::Int)
+///             .done();
+///
+///         builder
+///             .method_mut("add", |this: &mut Self, args: &[Variant]| {
+///                 let count = this.items.get(args[0].clone()).map_or(0, |count| count.to::<i64>());
+///                 this.items.set(args[0].clone(), count + 1);
+///                 Variant::nil()
+///             })
+///             .param("item", VariantType::String)
+///             .done();
+///
+///         builder.signal("item_added").param("item", VariantType::String).done();
+///         builder.constant("MAX_ITEMS", 64);
+///     }
+/// }
+/// ```
 pub struct ClassBuilder<C> {
+    data: ClassBuilderData,
     _c: PhantomData<C>,
 }
 
@@ -18,32 +86,242 @@ where
     C: GodotClass,
 {
     pub(crate) fn new() -> Self {
-        Self { _c: PhantomData }
+        Self {
+            data: ClassBuilderData::default(),
+            _c: PhantomData,
+        }
     }
 
-    pub fn virtual_method<'cb, F>(&'cb mut self, name: &'cb str, method: F) -> MethodBuilder<C, F> {
-        MethodBuilder::new(self, name, method)
+    /// The name of the class being registered.
+    pub fn class_name(&self) -> ClassName {
+        C::class_name()
     }
+
+    /// Declares a method named `name`, which can be called from GDScript and other languages.
+    ///
+    /// The method receives a shared reference to the instance and all arguments passed by the caller, and returns its result as
+    /// `Variant`. Parameters, default values and the return type are declared on the returned [`MethodBuilder`]; the arguments are
+    /// checked against them before `method` is invoked. The declaration only takes effect once [`MethodBuilder::done()`] is called.
+    ///
+    /// Like `#[func] fn f(&self)`, the instance is only borrowed immutably, so the method can be called while other shared borrows
+    /// are active, e.g. from a signal emitted by another `&self` method. Use [`method_mut()`][Self::method_mut] to modify the instance.
+    pub fn method<F>(&mut self, name: &str, method: F) -> MethodBuilder<'_, C>
+    where
+        F: Fn(&C, &[Variant]) -> Variant + Send + Sync + 'static,
+    {
+        MethodBuilder::new(self, name, method::erase_method(name, method))
+    }
+
+    /// Declares a method named `name`, which receives an exclusive reference to the instance.
+    ///
+    /// Like `#[func] fn f(&mut self)`, the call fails with a borrow error if the instance is already borrowed. Apart from that,
+    /// behaves like [`method()`][Self::method].
+    pub fn method_mut<F>(&mut self, name: &str, method: F) -> MethodBuilder<'_, C>
+    where
+        F: Fn(&mut C, &[Variant]) -> Variant + Send + Sync + 'static,
+    {
+        MethodBuilder::new(self, name, method::erase_method_mut(name, method))
+    }
+
+    /// Declares a property named `name` of the given type.
+    ///
+    /// Accessors, hints and usage flags are declared on the returned [`PropertyBuilder`]. The declaration only takes effect once
+    /// [`PropertyBuilder::done()`] is called.
+    pub fn property(&mut self, name: &str, variant_type: VariantType) -> PropertyBuilder<'_, C> {
+        PropertyBuilder::new(self, name, variant_type)
+    }
+
+    /// Declares a signal named `name`.
+    ///
+    /// Parameters are declared on the returned [`SignalBuilder`]. The declaration only takes effect once [`SignalBuilder::done()`]
+    /// is called.
+    pub fn signal(&mut self, name: &str) -> SignalBuilder<'_, C> {
+        SignalBuilder::new(self, name)
+    }
+
+    /// Declares an integer constant, accessible as `ClassName.NAME` from GDScript.
+    pub fn constant(&mut self, name: &str, value: i64) -> &mut Self {
+        let constant = IntegerConstant::new(StringName::from(name), value);
+        self.data.constants.push(ConstantKind::Integer(constant));
+        self
+    }
+
+    /// Declares an enum named `enum_name`, consisting of the given `(name, value)` enumerators.
+    pub fn enum_constants(&mut self, enum_name: &str, enumerators: &[(&str, i64)]) -> &mut Self {
+        self.data.constants.push(ConstantKind::Enum {
+            name: StringName::from(enum_name),
+            enumerators: make_integer_constants(enumerators),
+        });
+        self
+    }
+
+    /// Declares a bitfield named `bitfield_name`, consisting of the given `(name, value)` flags.
+    pub fn bitfield_constants(&mut self, bitfield_name: &str, flags: &[(&str, i64)]) -> &mut Self {
+        self.data.constants.push(ConstantKind::Bitfield {
+            name: StringName::from(bitfield_name),
+            flags: make_integer_constants(flags),
+        });
+        self
+    }
+
+    /// Overrides the engine virtual method `name`, e.g. `_process`.
+    ///
+    /// `method` is a function or closure taking `&mut C` followed by the parameters of the virtual method, whose types must match
+    /// the engine's declaration:
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::builder::ClassBuilder;
+    /// # #[derive(GodotClass)] #[class(init, base=Node)] struct Player { #[base] base: Base<Node> }
+    /// # fn register(builder: &mut ClassBuilder<Player>) {
+    /// builder.virtual_method("_process", |this: &mut Player, delta: f64| {
+    ///     godot_print!("processing {delta}");
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// Takes precedence over an implementation of the same method in the class's virtual trait (e.g. `INode::process()`). Rust classes
+    /// inheriting from `C` use the override too, unless they implement the method themselves.
+    #[cfg(since_api = "4.2")]
+    pub fn virtual_method<F, Sig>(&mut self, name: &str, method: F) -> &mut Self
+    where
+        F: VirtualMethod<C, Sig>,
+        Sig: 'static,
+    {
+        let virtual_fn = method::erase_virtual_method(name, method);
+        self.data.virtuals.insert(name.to_string(), virtual_fn);
+        self
+    }
+
+    pub(crate) fn into_data(self) -> ClassBuilderData {
+        self.data
+    }
+}
+
+fn make_integer_constants(constants: &[(&str, i64)]) -> Vec<IntegerConstant> {
+    constants
+        .iter()
+        .map(|&(name, value)| IntegerConstant::new(StringName::from(name), value))
+        .collect()
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
 
-#[allow(dead_code)] // TODO rm
-#[must_use]
-pub struct MethodBuilder<'cb, C, F> {
-    class_builder: &'cb mut ClassBuilder<C>,
-    name: &'cb str,
-    method: F,
+/// Type-erased declarations of a [`ClassBuilder`].
+///
+/// Collected before the class is registered with Godot, and applied afterwards.
+#[derive(Default)]
+pub(crate) struct ClassBuilderData {
+    methods: Vec<method::DynamicMethod>,
+    properties: Vec<property::PropertyDefinition>,
+    signals: Vec<signal::SignalDefinition>,
+    constants: Vec<ConstantKind>,
+    #[cfg(since_api = "4.2")]
+    virtuals: HashMap<String, method::VirtualEntry>,
 }
 
-impl<'cb, C, F> MethodBuilder<'cb, C, F> {
-    pub(super) fn new(class_builder: &'cb mut ClassBuilder<C>, name: &'cb str, method: F) -> Self {
-        Self {
-            class_builder,
-            name,
-            method,
+impl ClassBuilderData {
+    pub(crate) fn append(&mut self, other: ClassBuilderData) {
+        self.methods.extend(other.methods);
+        self.properties.extend(other.properties);
+        self.signals.extend(other.signals);
+        self.constants.extend(other.constants);
+
+        #[cfg(since_api = "4.2")]
+        self.virtuals.extend(other.virtuals);
+    }
+
+    /// Installs the virtual method overrides into the class creation info, before the class is registered.
+    ///
+    /// Overrides declared through the builder of a Rust base class `parent_class_name` are inherited. Has no effect if neither
+    /// class declares virtual methods, leaving the class's `get_virtual_func` in charge.
+    #[cfg(since_api = "4.2")]
+    pub(crate) fn prepare_virtuals(
+        &mut self,
+        class_name: ClassName,
+        parent_class_name: ClassName,
+        godot_params: &mut crate::sys::GDExtensionClassCreationInfo2,
+    ) {
+        let parent_has_table = virtual_table_ptr(&parent_class_name).is_some();
+        if self.virtuals.is_empty() && !parent_has_table {
+            return;
         }
+
+        let table = Box::new(method::VirtualTable::new(
+            godot_params.get_virtual_func,
+            std::mem::take(&mut self.virtuals),
+            parent_has_table.then_some(parent_class_name),
+        ));
+
+        // The table is owned by the registry below, and thus outlives the class registration.
+        godot_params.class_userdata =
+            &*table as *const method::VirtualTable as *mut std::ffi::c_void;
+        godot_params.get_virtual_call_data_func = Some(method::get_virtual_call_data);
+        godot_params.call_virtual_with_data_func = Some(method::call_virtual_with_data);
+
+        with_registry(|registry| {
+            registry.entry(class_name).or_default().virtual_table = Some(table)
+        });
     }
 
-    pub fn done(self) {}
+    /// Registers all declared members with Godot. The class must already be registered.
+    pub(crate) fn register(self, class_name: ClassName) {
+        let mut methods = Vec::with_capacity(self.methods.len());
+        for method in self.methods {
+            let method = Box::new(method);
+            method.register(class_name);
+            methods.push(method);
+        }
+
+        if !methods.is_empty() {
+            with_registry(|registry| registry.entry(class_name).or_default().methods = methods);
+        }
+
+        for signal in self.signals {
+            signal.register(class_name);
+        }
+
+        for property in self.properties {
+            property.register(class_name);
+        }
+
+        for kind in self.constants {
+            ExportConstant::new(class_name, kind).register();
+        }
+    }
+}
+
+/// Per-class data that Godot refers to through pointers, and which must thus be kept alive while the class is registered.
+#[derive(Default)]
+struct DynamicClassData {
+    methods: Vec<Box<method::DynamicMethod>>,
+    #[cfg(since_api = "4.2")]
+    virtual_table: Option<Box<method::VirtualTable>>,
+}
+
+static REGISTRY: Mutex<Option<HashMap<ClassName, DynamicClassData>>> = Mutex::new(None);
+
+fn with_registry<R>(f: impl FnOnce(&mut HashMap<ClassName, DynamicClassData>) -> R) -> R {
+    let mut registry = REGISTRY.lock().unwrap();
+    f(registry.get_or_insert_with(HashMap::new))
+}
+
+/// Returns the virtual table of `class_name`, if that class declared virtual methods through its builder (or inherited them).
+///
+/// The table is boxed and stays at the same address until the class is unregistered.
+#[cfg(since_api = "4.2")]
+fn virtual_table_ptr(class_name: &ClassName) -> Option<*const method::VirtualTable> {
+    with_registry(|registry| {
+        let table = registry.get(class_name)?.virtual_table.as_deref()?;
+        Some(table as *const method::VirtualTable)
+    })
+}
+
+/// Releases the data of a class registered through a builder. Must only be called after the class has been unregistered.
+pub(crate) fn unregister_class(class_name: &ClassName) {
+    let data = with_registry(|registry| registry.remove(class_name));
+
+    // Drop outside the lock, as destructors of user closures may run arbitrary code.
+    drop(data);
 }
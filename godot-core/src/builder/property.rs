@@ -0,0 +1,128 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::meta::{ClassName, PropertyInfo};
+use crate::builtin::{GString, StringName, VariantType};
+use crate::engine::global::{PropertyHint, PropertyUsageFlags};
+use crate::obj::GodotClass;
+
+use super::ClassBuilder;
+
+/// Declares a property of a [`ClassBuilder`]. Created by [`ClassBuilder::property()`].
+///
+/// Properties are backed by a getter and a setter method, which are referred to by name. These can be `#[func]` methods or
+/// methods declared through [`ClassBuilder::method()`] and [`ClassBuilder::method_mut()`]. A property without setter is
+/// read-only, one without getter write-only.
+#[must_use]
+pub struct PropertyBuilder<'cb, C> {
+    class_builder: &'cb mut ClassBuilder<C>,
+    property: PropertyDefinition,
+}
+
+impl<'cb, C> PropertyBuilder<'cb, C>
+where
+    C: GodotClass,
+{
+    pub(super) fn new(
+        class_builder: &'cb mut ClassBuilder<C>,
+        name: &str,
+        variant_type: VariantType,
+    ) -> Self {
+        Self {
+            class_builder,
+            property: PropertyDefinition {
+                name: name.to_string(),
+                variant_type,
+                class_name: ClassName::none(),
+                hint: PropertyHint::PROPERTY_HINT_NONE,
+                hint_string: String::new(),
+                usage: PropertyUsageFlags::PROPERTY_USAGE_DEFAULT,
+                getter: String::new(),
+                setter: String::new(),
+            },
+        }
+    }
+
+    /// Sets the name of the method returning the property's value.
+    pub fn getter(mut self, method_name: &str) -> Self {
+        self.property.getter = method_name.to_string();
+        self
+    }
+
+    /// Sets the name of the method accepting the property's new value.
+    pub fn setter(mut self, method_name: &str) -> Self {
+        self.property.setter = method_name.to_string();
+        self
+    }
+
+    /// Sets the editor hint, e.g. `PROPERTY_HINT_RANGE` with hint string `"0,100,1"`.
+    pub fn hint(mut self, hint: PropertyHint, hint_string: &str) -> Self {
+        self.property.hint = hint;
+        self.property.hint_string = hint_string.to_string();
+        self
+    }
+
+    /// Sets the usage flags. By default, `PROPERTY_USAGE_DEFAULT` is used, which stores the property and shows it in the editor.
+    pub fn usage(mut self, usage: PropertyUsageFlags) -> Self {
+        self.property.usage = usage;
+        self
+    }
+
+    /// For object properties, sets the class of the referenced objects.
+    pub fn class_name(mut self, class_name: ClassName) -> Self {
+        self.property.class_name = class_name;
+        self
+    }
+
+    /// Adds the property to the class.
+    pub fn done(self) {
+        self.class_builder.data.properties.push(self.property);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct PropertyDefinition {
+    name: String,
+    variant_type: VariantType,
+    class_name: ClassName,
+    hint: PropertyHint,
+    hint_string: String,
+    usage: PropertyUsageFlags,
+    getter: String,
+    setter: String,
+}
+
+impl PropertyDefinition {
+    pub(super) fn register(self, class_name: ClassName) {
+        let property_info = PropertyInfo {
+            variant_type: self.variant_type,
+            class_name: self.class_name,
+            property_name: StringName::from(self.name.as_str()),
+            hint: self.hint,
+            hint_string: GString::from(self.hint_string),
+            usage: self.usage,
+        };
+
+        let getter_name = StringName::from(self.getter.as_str());
+        let setter_name = StringName::from(self.setter.as_str());
+
+        let property_info_sys = property_info.property_sys();
+
+        unsafe {
+            interface_fn!(classdb_register_extension_class_property)(
+                sys::get_library(),
+                class_name.string_sys(),
+                std::ptr::addr_of!(property_info_sys),
+                setter_name.string_sys(),
+                getter_name.string_sys(),
+            );
+        }
+    }
+}
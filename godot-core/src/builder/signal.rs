@@ -0,0 +1,84 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::meta::{ClassName, PropertyInfo};
+use crate::builtin::{StringName, VariantType};
+use crate::obj::GodotClass;
+
+use super::method::DynamicParam;
+use super::ClassBuilder;
+
+/// Declares a signal of a [`ClassBuilder`]. Created by [`ClassBuilder::signal()`].
+#[must_use]
+pub struct SignalBuilder<'cb, C> {
+    class_builder: &'cb mut ClassBuilder<C>,
+    signal: SignalDefinition,
+}
+
+impl<'cb, C> SignalBuilder<'cb, C>
+where
+    C: GodotClass,
+{
+    pub(super) fn new(class_builder: &'cb mut ClassBuilder<C>, name: &str) -> Self {
+        Self {
+            class_builder,
+            signal: SignalDefinition {
+                name: name.to_string(),
+                params: Vec::new(),
+            },
+        }
+    }
+
+    /// Adds a parameter. If `variant_type` is [`VariantType::Nil`], the parameter is of type `Variant`.
+    pub fn param(mut self, name: &str, variant_type: VariantType) -> Self {
+        self.signal
+            .params
+            .push(DynamicParam::new(name, variant_type));
+        self
+    }
+
+    /// Adds the signal to the class.
+    pub fn done(self) {
+        self.class_builder.data.signals.push(self.signal);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct SignalDefinition {
+    name: String,
+    params: Vec<DynamicParam>,
+}
+
+impl SignalDefinition {
+    pub(super) fn register(self, class_name: ClassName) {
+        let parameters_info: Vec<PropertyInfo> = self
+            .params
+            .iter()
+            .map(DynamicParam::property_info)
+            .collect();
+
+        let parameters_info_sys: Vec<sys::GDExtensionPropertyInfo> = parameters_info
+            .iter()
+            .map(PropertyInfo::property_sys)
+            .collect();
+
+        let signal_name = StringName::from(self.name.as_str());
+
+        unsafe {
+            interface_fn!(classdb_register_extension_class_signal)(
+                sys::get_library(),
+                class_name.string_sys(),
+                signal_name.string_sys(),
+                parameters_info_sys.as_ptr(),
+                sys::GDExtensionInt::from(parameters_info_sys.len() as i64),
+            );
+        }
+    }
+}
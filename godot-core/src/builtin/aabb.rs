@@ -22,6 +22,7 @@ use super::meta::impl_godot_as_self;
 /// The 2D counterpart to `Aabb` is [`Rect2`](super::Rect2).
 #[derive(Default, Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Aabb"))]
 #[repr(C)]
 pub struct Aabb {
     pub position: Vector3,
@@ -90,16 +90,57 @@ impl Callable {
     where
         F: 'static + Send + Sync + FnMut(&[&Variant]) -> Result<Variant, ()>,
         S: Into<crate::builtin::GString>,
+    {
+        Self::from_fn_with_object_id(name.into(), rust_function, 0)
+    }
+
+    /// Create a callable from a Rust function or closure, whose lifetime is tied to an owning object.
+    ///
+    /// Works like [`from_fn()`][Self::from_fn], with two differences:
+    /// - The callable reports `owner` as its object. When connected to a signal, Godot thus removes the connection as soon as `owner`
+    ///   is freed, just like for method callables.
+    /// - Once `owner` has been freed, invoking the callable no longer calls `rust_function`, but fails with an invalid-call error.
+    ///   This prevents closures from accessing objects that are no longer alive.
+    ///
+    /// See also [`Signal::connect_owned()`][crate::builtin::Signal::connect_owned], which returns a handle for the connection.
+    #[cfg(since_api = "4.2")]
+    pub fn from_owned_fn<T, F, S>(owner: &Gd<T>, name: S, mut rust_function: F) -> Self
+    where
+        T: GodotClass,
+        F: 'static + Send + Sync + FnMut(&[&Variant]) -> Result<Variant, ()>,
+        S: Into<crate::builtin::GString>,
+    {
+        let owner_id = owner.instance_id();
+        let guarded_function = move |args: &[&Variant]| {
+            if crate::engine::object_ptr_from_id(owner_id).is_null() {
+                return Err(());
+            }
+
+            rust_function(args)
+        };
+
+        Self::from_fn_with_object_id(name.into(), guarded_function, owner_id.to_u64())
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn from_fn_with_object_id<F>(
+        name: crate::builtin::GString,
+        rust_function: F,
+        object_id: u64,
+    ) -> Self
+    where
+        F: 'static + Send + Sync + FnMut(&[&Variant]) -> Result<Variant, ()>,
     {
         let userdata = CallableUserdata {
             inner: FnWrapper {
                 rust_function,
-                name: name.into(),
+                name,
             },
         };
 
         let info = sys::GDExtensionCallableCustomInfo {
             callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
+            object_id,
             call_func: Some(rust_callable_call_fn::<F>),
             free_func: Some(rust_callable_destroy::<FnWrapper<F>>),
             to_string_func: Some(rust_callable_to_string_named::<F>),
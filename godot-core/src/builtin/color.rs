@@ -22,6 +22,7 @@ use super::meta::impl_godot_as_self;
 #[repr(C)]
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Color"))]
 pub struct Color {
     /// The color's red component.
     pub r: f32,
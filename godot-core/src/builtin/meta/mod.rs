@@ -8,12 +8,14 @@ pub mod registration;
 
 mod class_name;
 mod godot_convert;
+mod property_name;
 mod return_marshal;
 mod signature;
 
 pub use class_name::*;
 pub(crate) use godot_convert::convert_error::*;
 pub use godot_convert::*;
+pub use property_name::*;
 #[doc(hidden)]
 pub use return_marshal::*;
 #[doc(hidden)]
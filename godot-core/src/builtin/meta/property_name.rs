@@ -0,0 +1,88 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::{NodePath, StringName};
+
+/// Name of a property declared by class `C`, holding values of type `T`.
+///
+/// Engine classes come with a table of these constants in their sidecar module, e.g. `godot::engine::node_2d::properties::POSITION`.
+/// They can be passed to [`Gd::get_property()`][crate::obj::Gd::get_property] and [`Gd::set_property()`][crate::obj::Gd::set_property]
+/// for typed access, or converted to `StringName`/`NodePath` for APIs such as `Object::set()` or `Tween::tween_property()`.
+///
+/// This struct is very cheap to copy.
+pub struct PropertyName<C, T> {
+    name: &'static str,
+    _marker: PhantomData<fn() -> (C, T)>,
+}
+
+impl<C, T> PropertyName<C, T> {
+    #[doc(hidden)]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the Godot name of the property, e.g. `"position"` or `"theme_override_colors/font_color"`.
+    pub const fn as_str(&self) -> &'static str {
+        self.name
+    }
+
+    /// Converts the property name to a `StringName`, as used by `Object::get()` and `Object::set()`.
+    pub fn to_string_name(&self) -> StringName {
+        StringName::from(self.name)
+    }
+
+    /// Converts the property name to a `NodePath`, as used by `Tween::tween_property()` or `Object::get_indexed()`.
+    pub fn to_node_path(&self) -> NodePath {
+        NodePath::from(self.name)
+    }
+}
+
+// Manual impls, as derives would require `C` and `T` to implement the traits as well.
+impl<C, T> Copy for PropertyName<C, T> {}
+
+impl<C, T> Clone for PropertyName<C, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, T> PartialEq for PropertyName<C, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<C, T> Eq for PropertyName<C, T> {}
+
+impl<C, T> fmt::Debug for PropertyName<C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PropertyName").field(&self.name).finish()
+    }
+}
+
+impl<C, T> fmt::Display for PropertyName<C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl<C, T> From<PropertyName<C, T>> for StringName {
+    fn from(property: PropertyName<C, T>) -> Self {
+        property.to_string_name()
+    }
+}
+
+impl<C, T> From<PropertyName<C, T>> for NodePath {
+    fn from(property: PropertyName<C, T>) -> Self {
+        property.to_node_path()
+    }
+}
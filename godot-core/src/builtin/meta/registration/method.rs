@@ -27,6 +27,7 @@ impl MethodParamOrReturnInfo {
 pub struct MethodInfo {
     class_name: ClassName,
     method_name: StringName,
+    method_userdata: *mut std::ffi::c_void,
     call_func: sys::GDExtensionClassMethodCall,
     ptrcall_func: sys::GDExtensionClassMethodPtrCall,
     method_flags: MethodFlags,
@@ -45,7 +46,8 @@ impl MethodInfo {
     ///
     /// `call_func`, if provided, must:
     ///
-    /// - Interpret its parameters as a list of `S::PARAM_COUNT` `Variant`s.
+    /// - Interpret its parameters as a list of `S::PARAM_COUNT` `Variant`s, or at least as many if `method_flags`
+    ///   contains `METHOD_FLAG_VARARG`.
     /// - Return a `Variant`.
     ///
     /// `call_func` and `ptrcall_func`, if provided, must:
@@ -86,6 +88,7 @@ impl MethodInfo {
         Self {
             class_name,
             method_name,
+            method_userdata: std::ptr::null_mut(),
             call_func,
             ptrcall_func,
             method_flags,
@@ -95,6 +98,53 @@ impl MethodInfo {
         }
     }
 
+    /// Method whose parameters are only known at runtime, such as those registered through a
+    /// [`ClassBuilder`][crate::builder::ClassBuilder].
+    ///
+    /// # Safety
+    ///
+    /// `call_func` must:
+    ///
+    /// - Interpret `method_userdata` in the way it was passed here, for as long as the class is registered.
+    /// - Interpret its parameters as a list of `Variant`s, following `arguments`.
+    /// - Return a `Variant`.
+    ///
+    /// Since there is no `ptrcall_func`, `method_flags` must contain `METHOD_FLAG_VARARG`, which makes Godot always
+    /// use `call_func`.
+    pub unsafe fn from_dynamic(
+        class_name: ClassName,
+        method_name: StringName,
+        method_userdata: *mut std::ffi::c_void,
+        call_func: sys::GDExtensionClassMethodCall,
+        method_flags: MethodFlags,
+        return_value: Option<MethodParamOrReturnInfo>,
+        arguments: Vec<MethodParamOrReturnInfo>,
+        default_arguments: Vec<Variant>,
+    ) -> Self {
+        use crate::obj::EngineEnum as _;
+
+        assert!(
+            method_flags.ord() & MethodFlags::METHOD_FLAG_VARARG.ord() != 0,
+            "methods without `ptrcall_func` must be vararg"
+        );
+        assert!(
+            default_arguments.len() <= arguments.len(),
+            "cannot have more default arguments than arguments"
+        );
+
+        Self {
+            class_name,
+            method_name,
+            method_userdata,
+            call_func,
+            ptrcall_func: None,
+            method_flags,
+            return_value,
+            arguments,
+            default_arguments,
+        }
+    }
+
     pub fn register_extension_class_method(&self) {
         use crate::obj::EngineEnum as _;
 
@@ -122,7 +172,7 @@ impl MethodInfo {
 
         let method_info_sys = sys::GDExtensionClassMethodInfo {
             name: self.method_name.string_sys(),
-            method_userdata: std::ptr::null_mut(),
+            method_userdata: self.method_userdata,
             call_func: self.call_func,
             ptrcall_func: self.ptrcall_func,
             method_flags: self.method_flags.ord() as u32,
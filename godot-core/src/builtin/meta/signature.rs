@@ -32,11 +32,24 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
         instance_ptr: sys::GDExtensionClassInstancePtr,
         method_name: &str,
         args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
+        default_args: fn() -> Vec<Variant>,
         ret: sys::GDExtensionVariantPtr,
         err: *mut sys::GDExtensionCallError,
         func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
     );
 
+    /// Like [`in_varcall`](Self::in_varcall), but passes all arguments beyond `PARAM_COUNT` to `func` as a slice.
+    unsafe fn in_varcall_varargs(
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+        method_name: &str,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
+        ret: sys::GDExtensionVariantPtr,
+        err: *mut sys::GDExtensionCallError,
+        func: fn(sys::GDExtensionClassInstancePtr, Self::Params, &[Variant]) -> Self::Ret,
+    );
+
     unsafe fn out_class_varcall(
         method_bind: sys::GDExtensionMethodBindPtr,
         method_name: &'static str,
@@ -65,10 +78,10 @@ pub trait PtrcallSignatureTuple {
     // We could fall back to varcalls in such cases, and not require GodotFfi categorically.
     unsafe fn in_ptrcall(
         instance_ptr: sys::GDExtensionClassInstancePtr,
-        method_name: &'static str,
+        method_name: &str,
         args_ptr: *const sys::GDExtensionConstTypePtr,
         ret: sys::GDExtensionTypePtr,
-        func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
+        func: impl FnOnce(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
         call_type: sys::PtrcallType,
     );
 
@@ -157,11 +170,18 @@ macro_rules! impl_varcall_signature_for_tuple {
                 instance_ptr: sys::GDExtensionClassInstancePtr,
                 method_name: &str,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: sys::GDExtensionInt,
+                default_args: fn() -> Vec<Variant>,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
                 func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
             ) {
                 //$crate::out!("in_varcall: {method_name}");
+                let Some(varcall_args) = VarcallArgs::new(args_ptr, arg_count, $PARAM_COUNT, default_args, err) else {
+                    return;
+                };
+                let args_ptr = varcall_args.args_ptr;
+
                 let args = ($(
                     unsafe { varcall_arg::<$Pn, $n>(args_ptr, method_name) },
                 )*) ;
@@ -169,6 +189,34 @@ macro_rules! impl_varcall_signature_for_tuple {
                 varcall_return::<$R>(func(instance_ptr, args), ret, err)
             }
 
+            #[inline]
+            unsafe fn in_varcall_varargs(
+                instance_ptr: sys::GDExtensionClassInstancePtr,
+                method_name: &str,
+                args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: sys::GDExtensionInt,
+                ret: sys::GDExtensionVariantPtr,
+                err: *mut sys::GDExtensionCallError,
+                func: fn(sys::GDExtensionClassInstancePtr, Self::Params, &[Variant]) -> Self::Ret,
+            ) {
+                //$crate::out!("in_varcall_varargs: {method_name}");
+                let provided = arg_count as usize;
+                if provided < $PARAM_COUNT {
+                    varcall_too_few_args(err, $PARAM_COUNT);
+                    return;
+                }
+
+                let args = ($(
+                    unsafe { varcall_arg::<$Pn, $n>(args_ptr, method_name) },
+                )*) ;
+
+                let varargs: Vec<Variant> = ($PARAM_COUNT..provided)
+                    .map(|i| (*Variant::ptr_from_sys(*args_ptr.add(i))).clone())
+                    .collect();
+
+                varcall_return::<$R>(func(instance_ptr, args, &varargs), ret, err)
+            }
+
             #[inline]
             unsafe fn out_class_varcall(
                 method_bind: ClassMethodBind,
@@ -273,7 +321,7 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 method_name: &str,
                 args_ptr: *const sys::GDExtensionConstTypePtr,
                 ret: sys::GDExtensionTypePtr,
-                func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
+                func: impl FnOnce(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
                 call_type: sys::PtrcallType,
             ) {
                 // $crate::out!("in_ptrcall: {method_name}");
@@ -377,6 +425,74 @@ macro_rules! impl_ptrcall_signature_for_tuple {
     };
 }
 
+/// Arguments of an incoming varcall, in which trailing arguments omitted by the caller are replaced with their default values.
+struct VarcallArgs {
+    /// Points to `param_count` arguments; either the caller's or `completed_ptrs`.
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+
+    // Keep the default values and the completed pointer list alive, as `args_ptr` may point into them.
+    _defaults: Vec<Variant>,
+    _completed_ptrs: Vec<sys::GDExtensionConstVariantPtr>,
+}
+
+impl VarcallArgs {
+    /// Returns `None` and sets `err` if fewer arguments than required (i.e. without default value) have been passed.
+    ///
+    /// # Safety
+    /// - `args_ptr` must point to `arg_count` valid variant pointers.
+    /// - It must be safe to write a `sys::GDExtensionCallError` once to `err`.
+    unsafe fn new(
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
+        param_count: usize,
+        default_args: fn() -> Vec<Variant>,
+        err: *mut sys::GDExtensionCallError,
+    ) -> Option<Self> {
+        let provided = arg_count as usize;
+        if provided >= param_count {
+            return Some(Self {
+                args_ptr,
+                _defaults: Vec::new(),
+                _completed_ptrs: Vec::new(),
+            });
+        }
+
+        let defaults = default_args();
+        let required = param_count.saturating_sub(defaults.len());
+        if provided < required {
+            varcall_too_few_args(err, required);
+            return None;
+        }
+
+        let mut completed_ptrs = Vec::with_capacity(param_count);
+        if provided > 0 {
+            completed_ptrs.extend_from_slice(std::slice::from_raw_parts(args_ptr, provided));
+        }
+        completed_ptrs.extend(
+            defaults[provided - required..]
+                .iter()
+                .map(Variant::var_sys_const),
+        );
+
+        Some(Self {
+            args_ptr: completed_ptrs.as_ptr(),
+            _defaults: defaults,
+            _completed_ptrs: completed_ptrs,
+        })
+    }
+}
+
+/// Reports that a varcall received fewer than `required` arguments.
+///
+/// # Safety
+/// - It must be safe to write a `sys::GDExtensionCallError` once to `err`.
+unsafe fn varcall_too_few_args(err: *mut sys::GDExtensionCallError, required: usize) {
+    *err = sys::default_call_error();
+    (*err).error = sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS;
+    (*err).argument = required as i32;
+    (*err).expected = required as i32;
+}
+
 /// Convert the `N`th argument of `args_ptr` into a value of type `P`.
 ///
 /// # Safety
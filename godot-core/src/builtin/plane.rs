@@ -25,6 +25,7 @@ use super::meta::impl_godot_as_self;
 /// annotated for each method.
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Plane"))]
 #[repr(C)]
 pub struct Plane {
     /// Normal vector pointing away from the plane.
@@ -29,6 +29,7 @@ use super::meta::impl_godot_as_self;
 /// methods and as such are not as performant as other types.
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Projection"))]
 #[repr(C)]
 pub struct Projection {
     /// The columns of the projection matrix.
@@ -15,6 +15,7 @@ use super::meta::impl_godot_as_self;
 
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Quaternion"))]
 #[repr(C)]
 pub struct Quaternion {
     pub x: real,
@@ -22,6 +22,7 @@ use super::meta::impl_godot_as_self;
 /// The 3D counterpart to `Rect2` is [`Aabb`](super::Aabb).
 #[derive(Default, Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Rect2"))]
 #[repr(C)]
 pub struct Rect2 {
     pub position: Vector2,
@@ -16,6 +16,7 @@ use super::{meta::impl_godot_as_self, Rect2, RectSide, Vector2i};
 /// fast overlap tests.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Rect2i"))]
 #[repr(C)]
 pub struct Rect2i {
     /// The position of the rectangle.
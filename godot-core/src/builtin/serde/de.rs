@@ -0,0 +1,326 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use ::serde::de::{self, DeserializeSeed, IntoDeserializer, Visitor};
+use ::serde::forward_to_deserialize_any;
+use ::serde::{Deserializer as _, Serialize};
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::*;
+
+use super::{with_serde_builtins, Error, Serializer};
+
+/// Deserializer reading from a [`Variant`].
+///
+/// Usually, it is more convenient to use [`from_variant()`][super::from_variant].
+pub struct Deserializer {
+    variant: Variant,
+}
+
+impl Deserializer {
+    /// Creates a deserializer reading from `variant`.
+    pub fn new(variant: Variant) -> Self {
+        Self { variant }
+    }
+
+    fn unexpected(&self, expected: &str) -> Error {
+        Error::new(format!(
+            "expected {expected}, found {:?} with value {}",
+            self.variant.get_type(),
+            self.variant.stringify()
+        ))
+    }
+}
+
+/// Visits the elements of a packed array as a sequence of variants.
+fn visit_packed<'de, V, T>(elements: Vec<T>, visitor: V) -> Result<V::Value, Error>
+where
+    V: Visitor<'de>,
+    T: ToGodot,
+{
+    let elements = elements.into_iter().map(|element| element.to_variant());
+    visitor.visit_seq(SeqAccess::new(elements))
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        macro_rules! visit_builtins {
+            ( $variant:expr, $visitor:expr ; $($Builtin:ident),* ) => {
+                match $variant.get_type() {
+                    $(
+                        // Goes through the dictionary representation, so derived `Deserialize` impls can read the fields.
+                        VariantType::$Builtin => {
+                            let value: $Builtin = $variant.to();
+                            let dict = value.serialize(Serializer::without_native_builtins())?;
+                            return Deserializer::new(dict).deserialize_any($visitor);
+                        }
+                    )*
+                    _ => {}
+                }
+            };
+        }
+
+        with_serde_builtins!(visit_builtins!(self.variant, visitor));
+
+        match self.variant.get_type() {
+            VariantType::Nil => visitor.visit_unit(),
+            VariantType::Bool => visitor.visit_bool(self.variant.to()),
+            VariantType::Int => visitor.visit_i64(self.variant.to()),
+            VariantType::Float => visitor.visit_f64(self.variant.to()),
+            VariantType::String | VariantType::StringName | VariantType::NodePath => {
+                visitor.visit_string(self.variant.stringify().to_string())
+            }
+            VariantType::Array => {
+                let array: VariantArray = self.variant.to();
+                visitor.visit_seq(SeqAccess::new(array.iter_shared()))
+            }
+            VariantType::Dictionary => {
+                let dict: Dictionary = self.variant.to();
+                visitor.visit_map(MapAccess::new(dict.iter_shared()))
+            }
+            VariantType::PackedByteArray => {
+                visit_packed(self.variant.to::<PackedByteArray>().to_vec(), visitor)
+            }
+            VariantType::PackedInt32Array => {
+                visit_packed(self.variant.to::<PackedInt32Array>().to_vec(), visitor)
+            }
+            VariantType::PackedInt64Array => {
+                visit_packed(self.variant.to::<PackedInt64Array>().to_vec(), visitor)
+            }
+            VariantType::PackedFloat32Array => {
+                visit_packed(self.variant.to::<PackedFloat32Array>().to_vec(), visitor)
+            }
+            VariantType::PackedFloat64Array => {
+                visit_packed(self.variant.to::<PackedFloat64Array>().to_vec(), visitor)
+            }
+            VariantType::PackedStringArray => {
+                visit_packed(self.variant.to::<PackedStringArray>().to_vec(), visitor)
+            }
+            VariantType::PackedVector2Array => {
+                visit_packed(self.variant.to::<PackedVector2Array>().to_vec(), visitor)
+            }
+            VariantType::PackedVector3Array => {
+                visit_packed(self.variant.to::<PackedVector3Array>().to_vec(), visitor)
+            }
+            VariantType::PackedColorArray => {
+                visit_packed(self.variant.to::<PackedColorArray>().to_vec(), visitor)
+            }
+            _ => Err(self.unexpected("a type supported by serde")),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.variant.is_nil() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.variant.try_to::<PackedByteArray>() {
+            Ok(bytes) => visitor.visit_byte_buf(bytes.to_vec()),
+            Err(_) => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.variant.get_type() {
+            VariantType::String | VariantType::StringName => {
+                let variant = self.variant.stringify().to_string();
+                visitor.visit_enum(variant.into_deserializer())
+            }
+            VariantType::Dictionary => {
+                let dict: Dictionary = self.variant.to();
+                let mut entries = dict.iter_shared();
+
+                match (entries.next(), entries.next()) {
+                    (Some((key, value)), None) => visitor.visit_enum(EnumAccess {
+                        variant: key,
+                        content: value,
+                    }),
+                    _ => Err(self.unexpected("a dictionary with exactly one entry")),
+                }
+            }
+            _ => Err(self.unexpected("a string or dictionary representing an enum")),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Deserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+struct SeqAccess<I> {
+    elements: I,
+}
+
+impl<I> SeqAccess<I> {
+    fn new(elements: I) -> Self {
+        Self { elements }
+    }
+}
+
+impl<'de, I> de::SeqAccess<'de> for SeqAccess<I>
+where
+    I: Iterator<Item = Variant>,
+{
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.elements.next() {
+            Some(element) => seed.deserialize(Deserializer::new(element)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        match self.elements.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(upper),
+            _ => None,
+        }
+    }
+}
+
+struct MapAccess<I> {
+    entries: I,
+    pending_value: Option<Variant>,
+}
+
+impl<I> MapAccess<I> {
+    fn new(entries: I) -> Self {
+        Self {
+            entries,
+            pending_value: None,
+        }
+    }
+}
+
+impl<'de, I> de::MapAccess<'de> for MapAccess<I>
+where
+    I: Iterator<Item = (Variant, Variant)>,
+{
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.pending_value = Some(value);
+                seed.deserialize(Deserializer::new(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let value = self
+            .pending_value
+            .take()
+            .ok_or_else(|| Error::new("next_value_seed() called before next_key_seed()"))?;
+
+        seed.deserialize(Deserializer::new(value))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Enum represented as single-entry dictionary `{ variant: content }`.
+struct EnumAccess {
+    variant: Variant,
+    content: Variant,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess {
+    type Error = Error;
+    type Variant = VariantAccess;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, VariantAccess), Error> {
+        let variant = seed.deserialize(Deserializer::new(self.variant))?;
+        let access = VariantAccess {
+            content: self.content,
+        };
+
+        Ok((variant, access))
+    }
+}
+
+struct VariantAccess {
+    content: Variant,
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        if self.content.is_nil() {
+            Ok(())
+        } else {
+            Err(Error::new(format!(
+                "expected no content for unit variant, found {}",
+                self.content.stringify()
+            )))
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(Deserializer::new(self.content))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_seq(Deserializer::new(self.content), visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_map(Deserializer::new(self.content), visitor)
+    }
+}
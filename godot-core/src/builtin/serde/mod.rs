@@ -0,0 +1,145 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversion between [`Variant`] and any Rust type implementing [`serde::Serialize`] or [`serde::Deserialize`].
+//!
+//! This allows reusing existing serde types directly in Godot, without a `ToGodot`/`FromGodot` implementation. The resulting
+//! values can for example be passed to GDScript, or stored in resources and save files.
+//!
+//! ```no_run
+//! use godot::builtin::serde::{from_variant, to_variant};
+//! use godot::builtin::{Variant, Vector3};
+//! # use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct SaveGame {
+//!     level: u32,
+//!     player_name: String,
+//!     position: Vector3,
+//!     inventory: Vec<String>,
+//! }
+//!
+//! let save = SaveGame {
+//!     level: 3,
+//!     player_name: "Rusty".to_string(),
+//!     position: Vector3::new(1.0, 2.0, 3.0),
+//!     inventory: vec!["sword".to_string(), "shield".to_string()],
+//! };
+//!
+//! // Dictionary { "level": 3, "player_name": "Rusty", "position": Vector3(1, 2, 3), "inventory": ["sword", "shield"] }
+//! let variant: Variant = to_variant(&save).unwrap();
+//!
+//! let back: SaveGame = from_variant(&variant).unwrap();
+//! assert_eq!(back, save);
+//! ```
+//!
+//! # Mapping
+//!
+//! The [serde data model](https://serde.rs/data-model.html) is mapped to Godot types as follows:
+//!
+//! | serde                                  | Godot                                               |
+//! |----------------------------------------|-----------------------------------------------------|
+//! | `bool`                                 | `bool`                                              |
+//! | integers                               | `int` (values that don't fit into `i64` are errors) |
+//! | `f32`, `f64`                           | `float`                                             |
+//! | `char`, string                         | `String`                                            |
+//! | byte array                             | `PackedByteArray`                                   |
+//! | `None`, unit, unit struct              | `null`                                              |
+//! | `Some(value)`, newtype struct          | `value`                                             |
+//! | sequence, tuple, tuple struct          | `Array`                                             |
+//! | map                                    | `Dictionary`                                        |
+//! | struct                                 | `Dictionary` with field names as `String` keys      |
+//! | unit variant                           | variant name as `String`                            |
+//! | newtype, tuple and struct variants     | `Dictionary` with the variant name as only key      |
+//!
+//! Godot's builtin types with serde support (e.g. [`Vector3`][crate::builtin::Vector3], [`Color`][crate::builtin::Color]) are
+//! stored as their native Godot counterparts, rather than dictionaries. This also applies when they are accessed through references
+//! or smart pointers such as `&Vector3` or `Box<Color>`. They are recognized by a reserved serde name (e.g. `__godot_Vector3`), so
+//! your own structs are stored as dictionaries even if they share a name with a builtin. Other serde formats see the builtins as
+//! regular structs; the reserved name only shows up in formats that write out struct names.
+//!
+//! Deserialization accepts the same representations. In addition, `StringName` and `NodePath` are accepted as strings, and packed
+//! arrays as sequences.
+
+mod de;
+mod ser;
+
+pub use de::Deserializer;
+pub use ser::Serializer;
+
+use std::fmt;
+
+use crate::builtin::Variant;
+
+/// Converts a value into a [`Variant`], using its [`serde::Serialize`] implementation.
+///
+/// See the [module documentation](self) for how values are represented.
+pub fn to_variant<T>(value: &T) -> Result<Variant, Error>
+where
+    T: ::serde::Serialize + ?Sized,
+{
+    Serializer::new().serialize_nested(value)
+}
+
+/// Converts a [`Variant`] into a value, using its [`serde::Deserialize`] implementation.
+///
+/// See the [module documentation](self) for which representations are accepted.
+pub fn from_variant<T>(variant: &Variant) -> Result<T, Error>
+where
+    T: ::serde::de::DeserializeOwned,
+{
+    T::deserialize(Deserializer::new(variant.clone()))
+}
+
+/// Error that occurred during serialization to or deserialization from a [`Variant`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    fn new(message: impl fmt::Display) -> Self {
+        Self {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ::serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg)
+    }
+}
+
+impl ::serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg)
+    }
+}
+
+/// Prefix of the serde names of Godot builtins, which are renamed with `#[serde(rename = "__godot_Vector3")]` etc.
+const BUILTIN_NAME_PREFIX: &str = "__godot_";
+
+/// Invokes `$callback!` with the builtin types that implement serde traits, and their `VariantType` enumerators.
+macro_rules! with_serde_builtins {
+    ($callback:ident ! ( $($args:tt)* )) => {
+        $callback!(
+            $($args)*;
+            Aabb, Basis, Color, Plane, Projection, Quaternion, Rect2, Rect2i, Transform2D, Transform3D,
+            Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i
+        )
+    };
+}
+
+use with_serde_builtins;
@@ -0,0 +1,450 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use ::serde::ser::{self, Serialize, Serializer as _};
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::*;
+
+use super::{from_variant, with_serde_builtins, Error, BUILTIN_NAME_PREFIX};
+
+/// Serializer producing a [`Variant`].
+///
+/// Usually, it is more convenient to use [`to_variant()`][super::to_variant].
+pub struct Serializer {
+    /// Whether Godot builtins such as `Vector3` are stored natively, instead of as `Dictionary`.
+    native_builtins: bool,
+}
+
+impl Serializer {
+    /// Creates a serializer which stores Godot builtins natively.
+    pub fn new() -> Self {
+        Self {
+            native_builtins: true,
+        }
+    }
+
+    /// Creates a serializer which stores every struct as `Dictionary`, including Godot builtins.
+    pub(super) fn without_native_builtins() -> Self {
+        Self {
+            native_builtins: false,
+        }
+    }
+
+    fn nested(&self) -> Self {
+        Self {
+            native_builtins: self.native_builtins,
+        }
+    }
+
+    /// Serializes a value that is part of the current one, with the same settings.
+    pub(super) fn serialize_nested<T: Serialize + ?Sized>(
+        &self,
+        value: &T,
+    ) -> Result<Variant, Error> {
+        value.serialize(self.nested())
+    }
+}
+
+impl Default for Serializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Converts the dictionary representation of a Godot builtin into its native variant, if `struct_name` is the serde name of such a
+/// builtin.
+fn native_builtin(struct_name: &str, variant: &Variant) -> Option<Variant> {
+    let type_name = struct_name.strip_prefix(BUILTIN_NAME_PREFIX)?;
+
+    macro_rules! try_builtins {
+        ( ; $($Builtin:ident),* ) => {{
+            $(
+                if type_name == stringify!($Builtin) {
+                    return from_variant::<$Builtin>(variant).ok().map(|value| value.to_variant());
+                }
+            )*
+
+            None
+        }};
+    }
+
+    with_serde_builtins!(try_builtins!())
+}
+
+fn single_entry(key: &str, value: Variant) -> Variant {
+    let mut dict = Dictionary::new();
+    dict.set(key, value);
+    dict.to_variant()
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Variant;
+    type Error = Error;
+
+    type SerializeSeq = SerializeArray;
+    type SerializeTuple = SerializeArray;
+    type SerializeTupleStruct = SerializeArray;
+    type SerializeTupleVariant = SerializeArray;
+    type SerializeMap = SerializeDictionary;
+    type SerializeStruct = SerializeDictionary;
+    type SerializeStructVariant = SerializeDictionary;
+
+    fn serialize_bool(self, v: bool) -> Result<Variant, Error> {
+        Ok(v.to_variant())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Variant, Error> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Variant, Error> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Variant, Error> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Variant, Error> {
+        Ok(v.to_variant())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Variant, Error> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Variant, Error> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Variant, Error> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Variant, Error> {
+        match i64::try_from(v) {
+            Ok(v) => self.serialize_i64(v),
+            Err(_) => Err(Error::new(format!(
+                "integer {v} does not fit into Godot's 64-bit signed int"
+            ))),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Variant, Error> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Variant, Error> {
+        Ok(v.to_variant())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Variant, Error> {
+        Ok(GString::from(v.to_string()).to_variant())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Variant, Error> {
+        Ok(GString::from(v).to_variant())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Variant, Error> {
+        Ok(PackedByteArray::from(v).to_variant())
+    }
+
+    fn serialize_none(self) -> Result<Variant, Error> {
+        Ok(Variant::nil())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Variant, Error> {
+        self.serialize_nested(value)
+    }
+
+    fn serialize_unit(self) -> Result<Variant, Error> {
+        Ok(Variant::nil())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Variant, Error> {
+        Ok(Variant::nil())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Variant, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Variant, Error> {
+        self.serialize_nested(value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Variant, Error> {
+        let value = self.serialize_nested(value)?;
+        Ok(single_entry(variant, value))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SerializeArray, Error> {
+        Ok(SerializeArray::new(self, None))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeArray, Error> {
+        Ok(SerializeArray::new(self, Some(variant)))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeDictionary, Error> {
+        Ok(SerializeDictionary::new(self, None))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<SerializeDictionary, Error> {
+        let mut serializer = SerializeDictionary::new(self, None);
+        if serializer.serializer.native_builtins {
+            serializer.struct_name = Some(name);
+        }
+
+        Ok(serializer)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeDictionary, Error> {
+        Ok(SerializeDictionary::new(self, Some(variant)))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Serializes sequences, tuples and tuple variants into an `Array`.
+#[doc(hidden)]
+pub struct SerializeArray {
+    serializer: Serializer,
+    array: VariantArray,
+    enum_variant: Option<&'static str>,
+}
+
+impl SerializeArray {
+    fn new(serializer: Serializer, enum_variant: Option<&'static str>) -> Self {
+        Self {
+            serializer,
+            array: VariantArray::new(),
+            enum_variant,
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let value = self.serializer.serialize_nested(value)?;
+        self.array.push(value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Variant, Error> {
+        let array = self.array.to_variant();
+
+        match self.enum_variant {
+            Some(variant) => Ok(single_entry(variant, array)),
+            None => Ok(array),
+        }
+    }
+}
+
+impl ser::SerializeSeq for SerializeArray {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeArray {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Serializes maps, structs and struct variants into a `Dictionary`.
+#[doc(hidden)]
+pub struct SerializeDictionary {
+    serializer: Serializer,
+    dict: Dictionary,
+    enum_variant: Option<&'static str>,
+    pending_key: Option<Variant>,
+    /// Name of the serialized struct, if it may be a Godot builtin to be stored natively.
+    struct_name: Option<&'static str>,
+}
+
+impl SerializeDictionary {
+    fn new(serializer: Serializer, enum_variant: Option<&'static str>) -> Self {
+        Self {
+            serializer,
+            dict: Dictionary::new(),
+            enum_variant,
+            pending_key: None,
+            struct_name: None,
+        }
+    }
+
+    fn insert_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        let value = self.serializer.serialize_nested(value)?;
+        self.dict.set(key, value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Variant, Error> {
+        let dict = self.dict.to_variant();
+
+        if let Some(native) = self
+            .struct_name
+            .and_then(|name| native_builtin(name, &dict))
+        {
+            return Ok(native);
+        }
+
+        match self.enum_variant {
+            Some(variant) => Ok(single_entry(variant, dict)),
+            None => Ok(dict),
+        }
+    }
+}
+
+impl ser::SerializeMap for SerializeDictionary {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.pending_key = Some(self.serializer.serialize_nested(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .pending_key
+            .take()
+            .ok_or_else(|| Error::new("serialize_value() called before serialize_key()"))?;
+
+        let value = self.serializer.serialize_nested(value)?;
+        self.dict.set(key, value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeDictionary {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert_field(key, value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeDictionary {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert_field(key, value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        self.finish()
+    }
+}
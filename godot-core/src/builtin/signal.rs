@@ -0,0 +1,548 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{impl_godot_as_self, FromGodot, GodotType, ToGodot};
+use crate::builtin::{inner, Callable, Dictionary, StringName, Variant};
+use crate::engine::global::Error;
+use crate::engine::Object;
+use crate::obj::mem::Memory;
+use crate::obj::{EngineEnum, Gd, GodotClass, InstanceId};
+use std::marker::PhantomData;
+use std::{fmt, ptr};
+use sys::{ffi_methods, GodotFfi};
+
+/// A `Signal` represents a signal of an `Object` instance in Godot.
+///
+/// Signals are composed of a reference to an `Object` and the name of the signal on this object.
+///
+/// For a statically typed API on top of this, see [`TypedSignal`]. Signals declared with `#[signal]` inside a `#[godot_api]` block
+/// are accessible through the generated `signals()` method.
+///
+/// _Godot equivalent: `Signal`_
+#[repr(C, align(8))]
+pub struct Signal {
+    opaque: sys::types::OpaqueSignal,
+}
+
+impl Signal {
+    fn from_opaque(opaque: sys::types::OpaqueSignal) -> Self {
+        Self { opaque }
+    }
+
+    /// Create a signal for the signal `object::signal_name`.
+    ///
+    /// _Godot equivalent: `Signal(Object object, StringName signal)`_
+    pub fn from_object_signal<T, S>(object: &Gd<T>, signal_name: S) -> Self
+    where
+        T: GodotClass,
+        S: Into<StringName>,
+    {
+        let signal_name = signal_name.into();
+        unsafe {
+            sys::from_sys_init_or_init_default::<Self>(|self_ptr| {
+                let ctor = sys::builtin_fn!(signal_from_object_signal);
+                let raw = object.to_ffi();
+                let args = [raw.as_arg_ptr(), signal_name.sys_const()];
+                ctor(self_ptr, args.as_ptr());
+            })
+        }
+    }
+
+    /// Creates an invalid/empty signal that is not able to be called.
+    ///
+    /// _Godot equivalent: `Signal()`_
+    pub fn invalid() -> Self {
+        unsafe {
+            Self::from_sys_init(|self_ptr| {
+                let ctor = sys::builtin_fn!(signal_construct_default);
+                ctor(self_ptr, ptr::null_mut())
+            })
+        }
+    }
+
+    /// Connects this signal to the specified callable.
+    ///
+    /// Optional flags can be also added to configure the connection's behavior (see `Object::ConnectFlags` constants).
+    /// You can provide additional arguments to the connected callable by using `Callable::bind`.
+    ///
+    /// A signal can only be connected once to the same callable. If the signal is already connected,
+    /// returns [`Error::ERR_INVALID_PARAMETER`] and pushes an error message.
+    ///
+    /// _Godot equivalent: `connect`_
+    pub fn connect(&self, callable: Callable, flags: i64) -> Error {
+        let error = self.as_inner().connect(callable, flags);
+
+        Error::from_ord(error as i32)
+    }
+
+    /// Disconnects this signal from the specified [`Callable`].
+    ///
+    /// If the connection does not exist, generates an error. Use [`Signal::is_connected`] to make sure that the connection exists.
+    ///
+    /// _Godot equivalent: `disconnect`_
+    pub fn disconnect(&self, callable: Callable) {
+        self.as_inner().disconnect(callable);
+    }
+
+    /// Returns `true` if the specified [`Callable`] is connected to this signal.
+    ///
+    /// _Godot equivalent: `is_connected`_
+    pub fn is_connected(&self, callable: Callable) -> bool {
+        self.as_inner().is_connected(callable)
+    }
+
+    /// Connects this signal to a Rust closure, whose lifetime is tied to the object `owner`.
+    ///
+    /// Returns a [`ConnectionHandle`], which disconnects the closure when dropped. Regardless of the handle, the connection is removed
+    /// as soon as either this signal's object or `owner` is freed; the closure is never invoked after that.
+    ///
+    /// See also [`Callable::from_owned_fn()`].
+    #[cfg(since_api = "4.2")]
+    pub fn connect_owned<T, F>(&self, owner: &Gd<T>, function: F) -> ConnectionHandle
+    where
+        T: GodotClass,
+        F: 'static + Send + Sync + FnMut(&[&Variant]) -> Result<Variant, ()>,
+    {
+        let callable = Callable::from_owned_fn(owner, self.name(), function);
+        let error = self.connect(callable.clone(), 0);
+
+        assert_eq!(
+            error,
+            Error::OK,
+            "failed to connect signal `{}`",
+            self.name()
+        );
+        ConnectionHandle::new(self.clone(), callable, owner.instance_id())
+    }
+
+    /// Returns a future that resolves with the arguments of the next emission of this signal.
+    ///
+    /// The future must be run by the main-loop executor, see [`task::spawn()`][crate::task::spawn].
+    ///
+    /// _Godot equivalent: `await signal`_
+    #[cfg(since_api = "4.2")]
+    pub fn to_future(&self) -> crate::task::SignalFuture {
+        crate::task::SignalFuture::new(self.clone())
+    }
+
+    /// Emits this signal.
+    ///
+    /// All Callables connected to this signal will be triggered.
+    ///
+    /// # Panics
+    /// If the object of this signal is no longer alive, or if the signal is invalid.
+    ///
+    /// _Godot equivalent: `emit`_
+    pub fn emit(&self, varargs: &[Variant]) {
+        let Some(mut object) = self.object() else {
+            panic!("Signal::emit(): signal {self} has no valid object");
+        };
+
+        object.emit_signal(self.name(), varargs);
+    }
+
+    /// Returns a list of all connections of this signal.
+    ///
+    /// Each connection is represented as a `Dictionary` with the keys `signal`, `callable` and `flags`.
+    ///
+    /// _Godot equivalent: `get_connections`_
+    pub fn connections(&self) -> Vec<Dictionary> {
+        self.as_inner()
+            .get_connections()
+            .iter_shared()
+            .map(|variant| variant.to())
+            .collect()
+    }
+
+    /// Returns the name of this signal.
+    ///
+    /// _Godot equivalent: `get_name`_
+    pub fn name(&self) -> StringName {
+        self.as_inner().get_name()
+    }
+
+    /// Returns the object to which this signal belongs.
+    ///
+    /// Returns `None` when this signal doesn't have any object.
+    ///
+    /// _Godot equivalent: `get_object`_
+    pub fn object(&self) -> Option<Gd<Object>> {
+        // Increment refcount because we're getting a reference, and `InnerSignal::get_object` doesn't
+        // increment the refcount.
+        self.as_inner().get_object().map(|object| {
+            <Object as GodotClass>::Mem::maybe_inc_ref(&object.raw);
+            object
+        })
+    }
+
+    /// Returns the ID of this signal's object, see also [`Gd::instance_id`].
+    ///
+    /// Returns `None` when this signal doesn't have any object.
+    ///
+    /// _Godot equivalent: `get_object_id`_
+    pub fn object_id(&self) -> Option<InstanceId> {
+        let id = self.as_inner().get_object_id();
+        InstanceId::try_from_i64(id)
+    }
+
+    /// Returns `true` if this signal has no object.
+    ///
+    /// _Godot equivalent: `is_null`_
+    pub fn is_null(&self) -> bool {
+        self.as_inner().is_null()
+    }
+
+    #[doc(hidden)]
+    pub fn as_inner(&self) -> inner::InnerSignal {
+        inner::InnerSignal::from_outer(self)
+    }
+
+    fn inc_ref(&self) {
+        std::mem::forget(self.clone())
+    }
+}
+
+impl_builtin_traits! {
+    for Signal {
+        PartialEq => signal_operator_equal;
+        Clone => signal_construct_copy;
+        Drop => signal_destroy;
+    }
+}
+
+// SAFETY:
+// The `opaque` in `Signal` is an object ID + a `StringName`, and requires no special initialization or cleanup
+// beyond what is done in `from_opaque` and `drop`. So using `*mut Opaque` is safe.
+unsafe impl GodotFfi for Signal {
+    fn variant_type() -> sys::VariantType {
+        sys::VariantType::Signal
+    }
+
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Opaque;
+        fn from_sys;
+        fn sys;
+        fn from_sys_init;
+        fn move_return_ptr;
+    }
+
+    unsafe fn from_arg_ptr(ptr: sys::GDExtensionTypePtr, _call_type: sys::PtrcallType) -> Self {
+        let signal = Self::from_sys(ptr);
+        signal.inc_ref();
+        signal
+    }
+
+    unsafe fn from_sys_init_default(init_fn: impl FnOnce(sys::GDExtensionTypePtr)) -> Self {
+        let mut result = Self::invalid();
+        init_fn(result.sys_mut());
+        result
+    }
+}
+
+impl_godot_as_self!(Signal);
+
+impl fmt::Debug for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name();
+        let object = self.object();
+
+        f.debug_struct("Signal")
+            .field("name", &name)
+            .field("object", &object)
+            .finish()
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_variant())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Connection handles
+
+/// Handle to a connection between a signal and a Rust closure.
+///
+/// Created by [`Signal::connect_owned()`] or `TypedSignal::connect_owned()`. The closure is tied to two objects: the one emitting the
+/// signal, and the owner passed on connection. Once either of them is freed, Godot removes the connection and the handle becomes inert.
+///
+/// Dropping the handle disconnects the closure, unless [`detach()`][Self::detach] has been called before.
+#[cfg(since_api = "4.2")]
+#[must_use = "dropping a ConnectionHandle disconnects the signal immediately; use detach() to keep the connection"]
+pub struct ConnectionHandle {
+    signal: Signal,
+    callable: Callable,
+    owner_id: InstanceId,
+    disconnect_on_drop: bool,
+}
+
+#[cfg(since_api = "4.2")]
+impl ConnectionHandle {
+    fn new(signal: Signal, callable: Callable, owner_id: InstanceId) -> Self {
+        Self {
+            signal,
+            callable,
+            owner_id,
+            disconnect_on_drop: true,
+        }
+    }
+
+    /// Returns `true` if both objects are still alive and the closure is still connected to the signal.
+    pub fn is_connected(&self) -> bool {
+        self.are_objects_alive() && self.signal.is_connected(self.callable.clone())
+    }
+
+    /// Disconnects the closure from the signal, if still connected.
+    pub fn disconnect(mut self) {
+        self.disconnect_if_connected();
+    }
+
+    /// Keeps the connection alive after this handle is dropped.
+    ///
+    /// The connection is still removed once either object is freed.
+    pub fn detach(mut self) {
+        self.disconnect_on_drop = false;
+    }
+
+    /// Returns the signal that the closure is connected to.
+    pub fn signal(&self) -> &Signal {
+        &self.signal
+    }
+
+    /// Returns the instance ID of the owner object, which the closure's lifetime is tied to.
+    pub fn owner_id(&self) -> InstanceId {
+        self.owner_id
+    }
+
+    fn are_objects_alive(&self) -> bool {
+        let is_alive = |id: InstanceId| !crate::engine::object_ptr_from_id(id).is_null();
+
+        self.signal.object_id().map_or(false, is_alive) && is_alive(self.owner_id)
+    }
+
+    fn disconnect_if_connected(&mut self) {
+        if self.is_connected() {
+            self.signal.disconnect(self.callable.clone());
+        }
+
+        self.disconnect_on_drop = false;
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl Drop for ConnectionHandle {
+    fn drop(&mut self) {
+        if self.disconnect_on_drop {
+            self.disconnect_if_connected();
+        }
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl fmt::Debug for ConnectionHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionHandle")
+            .field("signal", &self.signal.name())
+            .field("owner_id", &self.owner_id)
+            .field("is_connected", &self.is_connected())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed signals
+
+/// Statically typed version of a [`Signal`].
+///
+/// `Ps` is a tuple of the signal's parameter types, e.g. `(i64, Gd<Node>)`. Emitting and connecting are checked at compile time
+/// against these types, so mismatches no longer show up as runtime errors.
+///
+/// You typically don't construct this type yourself. For every `#[signal]` inside a `#[godot_api]` block, a method with the same
+/// name is generated on the class' signal collection, which is returned by `signals()`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn hit(damage: i64, source: Gd<Node>);
+///
+///     #[func]
+///     fn take_damage(&mut self, damage: i64, source: Gd<Node>) {
+///         self.signals().hit().emit(damage, source);
+///     }
+/// }
+/// ```
+pub struct TypedSignal<Ps> {
+    signal: Signal,
+    _params: PhantomData<fn(Ps)>,
+}
+
+impl<Ps> TypedSignal<Ps> {
+    /// Create a typed signal for the signal `object::signal_name`.
+    ///
+    /// The signal must have been registered with parameters matching `Ps`; this is not checked.
+    pub fn new<T, S>(object: &Gd<T>, signal_name: S) -> Self
+    where
+        T: GodotClass,
+        S: Into<StringName>,
+    {
+        Self {
+            signal: Signal::from_object_signal(object, signal_name),
+            _params: PhantomData,
+        }
+    }
+
+    /// Connects this signal to an arbitrary callable, for example a `#[func]` method obtained via [`Gd::callable()`].
+    ///
+    /// The callable's parameters are not checked against `Ps`.
+    pub fn connect_callable(&self, callable: Callable) {
+        let error = self.signal.connect(callable, 0);
+
+        assert_eq!(
+            error,
+            Error::OK,
+            "failed to connect signal `{}`",
+            self.signal.name()
+        );
+    }
+
+    /// Returns the untyped signal.
+    pub fn to_untyped(&self) -> Signal {
+        self.signal.clone()
+    }
+}
+
+/// Converts the `index`th variant argument of a signal connection to `P`.
+#[cfg(since_api = "4.2")]
+fn signal_arg<P: FromGodot>(args: &[&Variant], index: usize) -> Result<P, ()> {
+    let variant = args.get(index).ok_or(())?;
+
+    P::try_from_variant(variant).map_err(|_| ())
+}
+
+macro_rules! impl_typed_signal_for_tuple {
+    (
+        $( ($pn:ident, $n:tt): $Pn:ident ),*
+    ) => {
+        #[allow(unused_variables)]
+        impl<$($Pn,)*> TypedSignal<($($Pn,)*)>
+        where
+            $( $Pn: ToGodot + FromGodot + 'static, )*
+        {
+            /// Emits this signal with the given arguments.
+            ///
+            /// # Panics
+            /// If the object of this signal is no longer alive.
+            pub fn emit(&self, $($pn: $Pn),*) {
+                self.signal.emit(&[$( $pn.to_variant(), )*]);
+            }
+
+            /// Returns a future that resolves with the typed arguments of the next emission of this signal.
+            ///
+            /// See [`Signal::to_future()`]. The future panics if the emitted arguments cannot be converted to the signal's parameter types.
+            #[cfg(since_api = "4.2")]
+            pub fn to_future(&self) -> impl std::future::Future<Output = ($($Pn,)*)> {
+                let future = self.signal.to_future();
+                let name = self.signal.name();
+
+                async move {
+                    let args = future.await;
+                    let args: Vec<&Variant> = args.iter().collect();
+
+                    ($(
+                        signal_arg::<$Pn>(&args, $n).unwrap_or_else(|()| {
+                            panic!("signal `{name}`: cannot convert argument {} to {}", $n, std::any::type_name::<$Pn>())
+                        }),
+                    )*)
+                }
+            }
+
+            /// Connects this signal to a Rust closure, which is invoked with typed arguments.
+            #[cfg(since_api = "4.2")]
+            pub fn connect<F>(&self, mut function: F)
+            where
+                F: FnMut($($Pn),*) + 'static + Send + Sync,
+            {
+                let callable = Callable::from_fn(self.signal.name(), move |args| {
+                    $(
+                        let $pn = signal_arg::<$Pn>(args, $n)?;
+                    )*
+
+                    function($($pn),*);
+                    Ok(Variant::nil())
+                });
+
+                self.connect_callable(callable);
+            }
+
+            /// Connects this signal to a Rust closure, whose lifetime is tied to the object `owner`.
+            ///
+            /// Returns a [`ConnectionHandle`], which disconnects the closure when dropped. See [`Signal::connect_owned()`].
+            #[cfg(since_api = "4.2")]
+            pub fn connect_owned<T, F>(&self, owner: &Gd<T>, mut function: F) -> ConnectionHandle
+            where
+                T: GodotClass,
+                F: FnMut($($Pn),*) + 'static + Send + Sync,
+            {
+                self.signal.connect_owned(owner, move |args| {
+                    $(
+                        let $pn = signal_arg::<$Pn>(args, $n)?;
+                    )*
+
+                    function($($pn),*);
+                    Ok(Variant::nil())
+                })
+            }
+
+            /// Connects this signal to a method of a user-defined object, typically a `#[func]`.
+            ///
+            /// `method` receives `&mut R` together with the signal arguments, for example `|this, damage| this.on_hit(damage)`.
+            /// The connection is removed automatically when the receiver is freed, like for method callables.
+            #[cfg(since_api = "4.2")]
+            pub fn connect_obj<R, F>(&self, receiver: &Gd<R>, mut method: F)
+            where
+                R: GodotClass<Declarer = crate::obj::dom::UserDomain>,
+                F: FnMut(&mut R, $($Pn),*) + 'static + Send + Sync,
+            {
+                let receiver_id = receiver.instance_id();
+                let name = format!("{}::{}", R::class_name(), self.signal.name());
+
+                let callable = Callable::from_owned_fn(receiver, name, move |args| {
+                    $(
+                        let $pn = signal_arg::<$Pn>(args, $n)?;
+                    )*
+
+                    let mut receiver = Gd::<R>::try_from_instance_id(receiver_id).map_err(|_| ())?;
+                    let mut guard = receiver.bind_mut();
+                    method(&mut *guard, $($pn),*);
+                    Ok(Variant::nil())
+                });
+
+                self.connect_callable(callable);
+            }
+        }
+    };
+}
+
+impl_typed_signal_for_tuple!();
+impl_typed_signal_for_tuple!((p0, 0): P0);
+impl_typed_signal_for_tuple!((p0, 0): P0, (p1, 1): P1);
+impl_typed_signal_for_tuple!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2);
+impl_typed_signal_for_tuple!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3);
+impl_typed_signal_for_tuple!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4);
+impl_typed_signal_for_tuple!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5);
+impl_typed_signal_for_tuple!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6);
+impl_typed_signal_for_tuple!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7);
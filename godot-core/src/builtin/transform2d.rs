@@ -28,6 +28,7 @@ use super::meta::impl_godot_as_self;
 /// ```
 #[derive(Default, Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Transform2D"))]
 #[repr(C)]
 pub struct Transform2D {
     /// The first basis vector.
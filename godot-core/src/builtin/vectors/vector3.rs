@@ -26,6 +26,7 @@ use std::fmt;
 /// See [`Vector3i`] for its integer counterpart.
 #[derive(Default, Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Vector3"))]
 #[repr(C)]
 pub struct Vector3 {
     /// The vector's X component.
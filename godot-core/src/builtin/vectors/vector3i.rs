@@ -25,6 +25,7 @@ use crate::builtin::{real, RVec3, Vector3, Vector3Axis};
 /// if 64-bit values are needed.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Vector3i"))]
 #[repr(C)]
 pub struct Vector3i {
     /// The vector's X component.
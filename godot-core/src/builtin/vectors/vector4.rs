@@ -24,6 +24,7 @@ use std::fmt;
 /// See [`Vector4i`] for its integer counterpart.
 #[derive(Default, Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Vector4"))]
 #[repr(C)]
 pub struct Vector4 {
     /// The vector's X component.
@@ -23,6 +23,7 @@ use std::fmt;
 /// if 64-bit values are needed.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "__godot_Vector4i"))]
 #[repr(C)]
 pub struct Vector4i {
     /// The vector's X component.
@@ -0,0 +1,243 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Editor documentation for user classes, collected from Rust doc comments.
+
+use std::any::TypeId;
+use std::collections::BTreeMap;
+
+use crate::builtin::meta::{ClassName, GodotConvert, GodotType};
+use crate::property::Property;
+use crate::registry::{ClassPlugin, PluginComponent};
+
+/// Documentation of a `#[derive(GodotClass)]` struct and its `#[var]`/`#[export]` fields.
+///
+/// All strings are already in the format of Godot's XML class reference (escaped BBCode).
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StructDocs {
+    /// First paragraph of the struct's doc comment.
+    pub brief: &'static str,
+    /// Remaining paragraphs of the struct's doc comment.
+    pub description: &'static str,
+    /// One entry per property.
+    pub members: &'static [MemberDocs],
+}
+
+/// Documentation of a single `#[var]` or `#[export]` property.
+#[derive(Copy, Clone, Debug)]
+pub struct MemberDocs {
+    pub name: &'static str,
+    /// Returns the Godot name of the property's type, e.g. `int` or `Node`.
+    pub type_name: fn() -> String,
+    pub description: &'static str,
+}
+
+/// Documentation of the items inside a `#[godot_api] impl MyClass` block.
+///
+/// All strings are already in the format of Godot's XML class reference (escaped BBCode, `<constant>` elements).
+#[derive(Copy, Clone, Debug, Default)]
+pub struct InherentImplDocs {
+    /// One entry per `#[func]`.
+    pub methods: &'static [MethodDocs],
+    /// One entry per `#[signal]`.
+    pub signals: &'static [SignalDocs],
+    /// One `<constant>` element per `#[constant]`.
+    pub constants: &'static [&'static str],
+}
+
+/// Documentation of a `#[func]`.
+#[derive(Copy, Clone, Debug)]
+pub struct MethodDocs {
+    pub name: &'static str,
+    /// Space-separated method qualifiers such as `static` or `vararg`, or empty.
+    pub qualifiers: &'static str,
+    pub return_type_name: fn() -> String,
+    pub params: &'static [ParamDocs],
+    pub description: &'static str,
+}
+
+/// Documentation of a `#[signal]`.
+#[derive(Copy, Clone, Debug)]
+pub struct SignalDocs {
+    pub name: &'static str,
+    pub params: &'static [ParamDocs],
+    pub description: &'static str,
+}
+
+/// Name and type of a method or signal parameter.
+#[derive(Copy, Clone, Debug)]
+pub struct ParamDocs {
+    pub name: &'static str,
+    pub type_name: fn() -> String,
+}
+
+/// Godot type name of a parameter or return type `T`, as shown in the editor help (e.g. `float` for `f32`).
+///
+/// The unit type `()` is documented as `void`.
+pub fn docs_type_name<T: GodotConvert>() -> String {
+    if TypeId::of::<T::Via>() == TypeId::of::<()>() {
+        return "void".to_string();
+    }
+
+    T::Via::godot_type_name()
+}
+
+/// Godot type name of a `#[var]` or `#[export]` field of type `T`.
+pub fn docs_property_type_name<T>() -> String
+where
+    T: Property,
+    T::Intermediate: GodotConvert,
+{
+    docs_type_name::<T::Intermediate>()
+}
+
+#[derive(Default)]
+struct ClassDocs {
+    base_class_name: Option<ClassName>,
+    struct_docs: Option<StructDocs>,
+    impl_docs: Vec<InherentImplDocs>,
+}
+
+/// Returns one XML class reference document per user class that has documentation.
+///
+/// Classes without any doc comments (on the struct or the registered members) are skipped.
+pub fn gather_xml_docs() -> Vec<String> {
+    // BTreeMap, for deterministic order.
+    let mut classes = BTreeMap::<String, ClassDocs>::new();
+
+    crate::private::iterate_plugins(|plugin: &ClassPlugin| {
+        let class_name = plugin.class_name.to_string();
+
+        match &plugin.component {
+            PluginComponent::ClassDef {
+                base_class_name,
+                docs,
+                ..
+            } => {
+                let class = classes.entry(class_name).or_default();
+                class.base_class_name = Some(*base_class_name);
+                class.struct_docs = *docs;
+            }
+            PluginComponent::UserMethodBinds {
+                docs: Some(docs), ..
+            } => {
+                classes.entry(class_name).or_default().impl_docs.push(*docs);
+            }
+            _ => {}
+        }
+    });
+
+    classes
+        .into_iter()
+        .filter(|(_, class)| class.struct_docs.is_some() || !class.impl_docs.is_empty())
+        .map(|(class_name, class)| make_class_xml(&class_name, &class))
+        .collect()
+}
+
+/// Loads the documentation of all user classes into the editor help.
+///
+/// Must be called after the classes have been registered; only has an effect in the editor.
+#[cfg(since_api = "4.3")]
+pub(crate) fn register_editor_docs() {
+    use crate::sys;
+
+    for xml in gather_xml_docs() {
+        let xml = std::ffi::CString::new(xml).expect("class documentation must not contain NUL");
+
+        // SAFETY: the pointer refers to a valid, NUL-terminated UTF-8 string, which is copied by Godot.
+        unsafe {
+            sys::interface_fn!(editor_help_load_xml_from_utf8_chars)(xml.as_ptr());
+        }
+    }
+}
+
+fn make_class_xml(class_name: &str, class: &ClassDocs) -> String {
+    let StructDocs {
+        brief,
+        description,
+        members,
+    } = class.struct_docs.unwrap_or_default();
+
+    let base = class
+        .base_class_name
+        .map(|name| name.to_string())
+        .unwrap_or_default();
+
+    let impl_docs = || class.impl_docs.iter();
+    let methods: String = impl_docs()
+        .flat_map(|docs| docs.methods)
+        .map(make_method_xml)
+        .collect();
+    let signals: String = impl_docs()
+        .flat_map(|docs| docs.signals)
+        .map(make_signal_xml)
+        .collect();
+    let constants: String = impl_docs()
+        .flat_map(|docs| docs.constants)
+        .copied()
+        .collect();
+    let members: String = members.iter().map(make_member_xml).collect();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" ?>
+<class name="{class_name}" inherits="{base}">
+<brief_description>{brief}</brief_description>
+<description>{description}</description>
+<methods>{methods}</methods>
+<members>{members}</members>
+<signals>{signals}</signals>
+<constants>{constants}</constants>
+</class>"#
+    )
+}
+
+fn make_member_xml(member: &MemberDocs) -> String {
+    format!(
+        r#"<member name="{name}" type="{ty}">{description}</member>"#,
+        name = member.name,
+        ty = (member.type_name)(),
+        description = member.description,
+    )
+}
+
+fn make_method_xml(method: &MethodDocs) -> String {
+    let qualifiers = if method.qualifiers.is_empty() {
+        String::new()
+    } else {
+        format!(r#" qualifiers="{}""#, method.qualifiers)
+    };
+
+    format!(
+        r#"<method name="{name}"{qualifiers}><return type="{return_ty}" />{params}<description>{description}</description></method>"#,
+        name = method.name,
+        return_ty = (method.return_type_name)(),
+        params = make_params_xml(method.params),
+        description = method.description,
+    )
+}
+
+fn make_signal_xml(signal: &SignalDocs) -> String {
+    format!(
+        r#"<signal name="{name}">{params}<description>{description}</description></signal>"#,
+        name = signal.name,
+        params = make_params_xml(signal.params),
+        description = signal.description,
+    )
+}
+
+fn make_params_xml(params: &[ParamDocs]) -> String {
+    params
+        .iter()
+        .enumerate()
+        .map(|(index, param)| {
+            format!(
+                r#"<param index="{index}" name="{name}" type="{ty}" />"#,
+                name = param.name,
+                ty = (param.type_name)(),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,336 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Helpers for editor plugins written in Rust.
+//!
+//! A class declared with `#[class(tool, editor_plugin, base=EditorPlugin)]` is registered at the `Editor` init level and enabled
+//! automatically, without a `plugin.cfg` entry. Everything such a plugin adds to the editor in `enter_tree()` has to be removed again
+//! in `exit_tree()`; [`EditorAdditions`] keeps track of this.
+//!
+//! Icons of classes declared with `#[class(icon = "res://...")]` are added to the editor automatically. Since Godot 4.2, class icons
+//! are re-applied whenever the editor rebuilds its theme.
+
+#[cfg(since_api = "4.2")]
+use crate::builtin::meta::ClassName;
+#[cfg(since_api = "4.2")]
+use crate::builtin::{Callable, Signal, Variant};
+use crate::builtin::{GString, StringName};
+use crate::engine::editor_plugin::DockSlot;
+#[cfg(since_api = "4.2")]
+use crate::engine::global::Error;
+#[cfg(since_api = "4.2")]
+use crate::engine::object::ConnectFlags;
+use crate::engine::{Control, EditorInspectorPlugin, EditorPlugin, Texture2D};
+#[cfg(since_api = "4.2")]
+use crate::obj::EngineEnum;
+use crate::obj::{Gd, GodotClass, Inherits};
+use std::cell::RefCell;
+
+/// Theme type under which the editor looks up class icons.
+const EDITOR_ICONS: &str = "EditorIcons";
+
+/// Records what an editor plugin adds to the editor, so it can be removed in one go.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::editor::EditorAdditions;
+/// use godot::engine::editor_plugin::DockSlot;
+/// use godot::engine::{EditorInspectorPlugin, EditorPlugin, IEditorPlugin, Label};
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, editor_plugin, base=EditorPlugin)]
+/// struct MyPlugin {
+///     additions: EditorAdditions,
+///     #[base]
+///     base: Base<EditorPlugin>,
+/// }
+///
+/// #[godot_api]
+/// impl IEditorPlugin for MyPlugin {
+///     fn enter_tree(&mut self) {
+///         self.additions.add_inspector_plugin(&mut self.base, EditorInspectorPlugin::new_gd());
+///         self.additions.add_dock(&mut self.base, DockSlot::DOCK_SLOT_RIGHT_UL, Label::new_alloc());
+///     }
+///
+///     fn exit_tree(&mut self) {
+///         self.additions.remove_all(&mut self.base);
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct EditorAdditions {
+    inspector_plugins: Vec<Gd<EditorInspectorPlugin>>,
+    docks: Vec<Gd<Control>>,
+    bottom_panels: Vec<Gd<Control>>,
+    class_icons: Vec<StringName>,
+}
+
+impl EditorAdditions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an inspector plugin, which can provide custom `EditorProperty` widgets.
+    pub fn add_inspector_plugin<T>(
+        &mut self,
+        plugin: &mut Gd<EditorPlugin>,
+        inspector_plugin: Gd<T>,
+    ) where
+        T: Inherits<EditorInspectorPlugin>,
+    {
+        let inspector_plugin = inspector_plugin.upcast();
+        plugin.add_inspector_plugin(inspector_plugin.clone());
+        self.inspector_plugins.push(inspector_plugin);
+    }
+
+    /// Adds `control` as a dock panel in the given slot.
+    ///
+    /// The control is owned by the editor afterwards; it is freed by [`remove_all()`][Self::remove_all].
+    pub fn add_dock<T>(&mut self, plugin: &mut Gd<EditorPlugin>, slot: DockSlot, control: Gd<T>)
+    where
+        T: Inherits<Control>,
+    {
+        let control = control.upcast();
+        plugin.add_control_to_dock(slot, control.clone());
+        self.docks.push(control);
+    }
+
+    /// Adds `control` as a tab of the bottom panel, with the given title.
+    ///
+    /// The control is owned by the editor afterwards; it is freed by [`remove_all()`][Self::remove_all].
+    pub fn add_bottom_panel<T>(
+        &mut self,
+        plugin: &mut Gd<EditorPlugin>,
+        title: impl Into<GString>,
+        control: Gd<T>,
+    ) where
+        T: Inherits<Control>,
+    {
+        let control = control.upcast();
+        plugin.add_control_to_bottom_panel(control.clone(), title.into());
+        self.bottom_panels.push(control);
+    }
+
+    /// Shows `icon` for class `T` in the scene tree, the "Create New Node" dialog and the inspector.
+    ///
+    /// Since Godot 4.2, the icon is re-applied whenever the editor rebuilds its theme, e.g. after editor settings have changed.
+    pub fn set_class_icon<T>(&mut self, plugin: &mut Gd<EditorPlugin>, icon: Gd<Texture2D>)
+    where
+        T: GodotClass,
+    {
+        let class_name = T::class_name().to_string_name();
+
+        let Some(base_control) = editor_base_control(plugin) else {
+            crate::log::godot_warn!(
+                "cannot set icon for class `{class_name}`: editor theme not available"
+            );
+            return;
+        };
+
+        add_class_icon(&base_control, class_name.clone(), icon);
+        self.class_icons.push(class_name);
+    }
+
+    /// Removes everything that was added through this object.
+    pub fn remove_all(&mut self, plugin: &mut Gd<EditorPlugin>) {
+        for inspector_plugin in self.inspector_plugins.drain(..) {
+            plugin.remove_inspector_plugin(inspector_plugin);
+        }
+
+        for control in self.docks.drain(..) {
+            plugin.remove_control_from_docks(control.clone());
+            control.free();
+        }
+
+        for control in self.bottom_panels.drain(..) {
+            plugin.remove_control_from_bottom_panel(control.clone());
+            control.free();
+        }
+
+        if self.class_icons.is_empty() {
+            return;
+        }
+
+        let base_control = editor_base_control(plugin);
+        for class_name in self.class_icons.drain(..) {
+            remove_class_icon(base_control.as_ref(), class_name);
+        }
+    }
+}
+
+fn editor_base_control(plugin: &mut Gd<EditorPlugin>) -> Option<Gd<Control>> {
+    plugin.get_editor_interface()?.get_base_control()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Class icons in the editor theme
+
+thread_local! {
+    /// Class icons added to the editor theme, through `#[class(icon)]` or [`EditorAdditions::set_class_icon()`].
+    static CLASS_ICONS: RefCell<ClassIcons> = RefCell::new(ClassIcons::default());
+}
+
+/// The editor rebuilds its theme from scratch, e.g. when editor settings change, which drops icons added to the previous theme.
+/// Class icons are therefore re-applied whenever the editor's base control reports a theme change.
+#[derive(Default)]
+struct ClassIcons {
+    entries: Vec<(StringName, Gd<Texture2D>)>,
+    base_control: Option<Gd<Control>>,
+
+    /// Connection to the `theme_changed` signal of the base control, while there are icons.
+    #[cfg(since_api = "4.2")]
+    theme_watch: Option<(Signal, Callable)>,
+}
+
+impl ClassIcons {
+    #[cfg(since_api = "4.2")]
+    fn watch_theme(&mut self, base_control: &Gd<Control>) {
+        if self.theme_watch.is_some() {
+            return;
+        }
+
+        let callable = Callable::from_fn("godot-rust class icons", |_args| {
+            reapply_class_icons();
+            Ok(Variant::nil())
+        });
+
+        // Deferred, so that icons are applied once the editor has finished rebuilding the theme.
+        let signal = Signal::from_object_signal(base_control, "theme_changed");
+        let error = signal.connect(
+            callable.clone(),
+            ConnectFlags::CONNECT_DEFERRED.ord() as i64,
+        );
+        assert_eq!(error, Error::OK, "failed to connect to `theme_changed`");
+
+        self.theme_watch = Some((signal, callable));
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn stop_watching_theme(&mut self) {
+        let Some((signal, callable)) = self.theme_watch.take() else {
+            return;
+        };
+
+        if signal.object().is_some() && signal.is_connected(callable.clone()) {
+            signal.disconnect(callable);
+        }
+    }
+}
+
+/// Stops re-applying class icons and releases them. Called when the `Editor` init level is unloaded.
+pub(crate) fn cleanup() {
+    let icons = CLASS_ICONS.with(|icons| {
+        let mut icons = icons.borrow_mut();
+
+        #[cfg(since_api = "4.2")]
+        icons.stop_watching_theme();
+
+        std::mem::take(&mut *icons)
+    });
+
+    // Release objects outside of the borrow.
+    drop(icons);
+}
+
+fn add_class_icon(base_control: &Gd<Control>, class_name: StringName, icon: Gd<Texture2D>) {
+    CLASS_ICONS.with(|icons| {
+        let mut icons = icons.borrow_mut();
+        icons.entries.retain(|(name, _)| *name != class_name);
+        icons.entries.push((class_name, icon));
+        icons.base_control = Some(base_control.clone());
+
+        #[cfg(since_api = "4.2")]
+        icons.watch_theme(base_control);
+    });
+
+    reapply_class_icons();
+}
+
+fn remove_class_icon(base_control: Option<&Gd<Control>>, class_name: StringName) {
+    CLASS_ICONS.with(|icons| {
+        let mut icons = icons.borrow_mut();
+        icons.entries.retain(|(name, _)| *name != class_name);
+
+        if icons.entries.is_empty() {
+            #[cfg(since_api = "4.2")]
+            icons.stop_watching_theme();
+
+            icons.base_control = None;
+        }
+    });
+
+    if let Some(mut theme) = base_control.and_then(|control| control.get_theme()) {
+        theme.clear_icon(class_name, EDITOR_ICONS.into());
+    }
+}
+
+/// Sets all class icons that are missing from the current editor theme.
+fn reapply_class_icons() {
+    let (theme, entries) = CLASS_ICONS.with(|icons| {
+        let icons = icons.borrow();
+        let theme = icons
+            .base_control
+            .as_ref()
+            .and_then(|control| control.get_theme());
+
+        (theme, icons.entries.clone())
+    });
+
+    let Some(mut theme) = theme else {
+        if !entries.is_empty() {
+            crate::log::godot_warn!("cannot set class icons: editor theme not available");
+        }
+        return;
+    };
+
+    // Only set missing icons: setting an icon changes the theme, which triggers another theme change notification.
+    for (class_name, icon) in entries {
+        let is_set = theme.has_icon(class_name.clone(), EDITOR_ICONS.into())
+            && theme.get_icon(class_name.clone(), EDITOR_ICONS.into()) == Some(icon.clone());
+
+        if !is_set {
+            theme.set_icon(class_name, EDITOR_ICONS.into(), icon);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Icons from #[class(icon)]
+
+/// Adds the icons to the editor theme, once the editor UI has been created.
+///
+/// Extensions are initialized before the editor UI exists, so this is deferred to the first frame.
+#[cfg(since_api = "4.2")]
+pub(crate) fn set_class_icons_deferred(icons: Vec<(ClassName, &'static str)>) {
+    if icons.is_empty() {
+        return;
+    }
+
+    let mut icons = Some(icons);
+    let callable = Callable::from_fn("set_class_icons", move |_args| {
+        if let Some(icons) = icons.take() {
+            set_class_icons(&icons);
+        }
+        Ok(Variant::nil())
+    });
+
+    callable.to_variant().call("call_deferred", &[]);
+}
+
+#[cfg(since_api = "4.2")]
+fn set_class_icons(icons: &[(ClassName, &'static str)]) {
+    let Some(base_control) = crate::engine::EditorInterface::singleton().get_base_control() else {
+        crate::log::godot_warn!("cannot set class icons: editor theme not available");
+        return;
+    };
+
+    for (class_name, path) in icons {
+        match crate::engine::try_load::<Texture2D>(*path) {
+            Some(texture) => add_class_icon(&base_control, class_name.to_string_name(), texture),
+            None => crate::log::godot_warn!("cannot load icon `{path}` for class `{class_name}`"),
+        }
+    }
+}
@@ -94,17 +94,38 @@ fn gdext_on_level_init(level: InitLevel) {
             }
             InitLevel::Scene => {
                 sys::load_class_method_table(sys::ClassApiLevel::Scene);
+
+                #[cfg(any(feature = "log", feature = "tracing"))]
+                crate::log::on_scene_init();
             }
             InitLevel::Editor => {
                 sys::load_class_method_table(sys::ClassApiLevel::Editor);
             }
         }
         crate::auto_register_classes(level);
+
+        // Editor help is only available once the editor level is loaded.
+        #[cfg(since_api = "4.3")]
+        if level == InitLevel::Editor {
+            crate::docs::register_editor_docs();
+        }
     }
 }
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    if level == InitLevel::Scene {
+        // Pending tasks may hold objects, which must be released while the engine is still alive.
+        crate::task::cleanup();
+
+        #[cfg(any(feature = "log", feature = "tracing"))]
+        crate::log::on_scene_deinit();
+    }
+
+    if level == InitLevel::Editor {
+        crate::editor::cleanup();
+    }
+
     crate::unregister_classes(level);
 }
 
@@ -4,15 +4,21 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod docs;
 mod registry;
 mod storage;
 
 pub mod builder;
 pub mod builtin;
+pub mod editor;
 pub mod init;
 pub mod log;
 pub mod obj;
+pub mod panic_report;
 pub mod property;
+pub mod settings;
+pub mod task;
+pub mod text_resource;
 
 pub use godot_ffi as sys;
 #[doc(hidden)]
@@ -50,11 +56,13 @@ pub mod private {
     pub trait You_forgot_the_attribute__godot_api {}
     pub use crate::property::Cannot_export_without_godot_api_impl;
 
-    use std::sync::{Arc, Mutex};
-
+    pub use crate::docs::{
+        docs_property_type_name, docs_type_name, gather_xml_docs, InherentImplDocs, MemberDocs,
+        MethodDocs, ParamDocs, SignalDocs, StructDocs,
+    };
     pub use crate::gen::classes::class_macros;
     pub use crate::registry::{callbacks, ClassPlugin, ErasedRegisterFn, PluginComponent};
-    pub use crate::storage::as_storage;
+    pub use crate::storage::{as_storage, BorrowContext};
     pub use godot_ffi::out;
 
     use crate::{log, sys};
@@ -104,13 +112,7 @@ pub mod private {
         }
     }
 
-    struct GodotPanicInfo {
-        line: u32,
-        file: String,
-        //backtrace: Backtrace, // for future use
-    }
-
-    /// Executes `code`. If a panic is thrown, it is caught and an error message is printed to Godot.
+    /// Executes `code`. If a panic is thrown, it is caught and reported to Godot, see [`crate::panic_report`].
     ///
     /// Returns `None` if a panic occurred, and `Some(result)` with the result of `code` otherwise.
     #[must_use]
@@ -120,51 +122,86 @@ pub mod private {
         F: FnOnce() -> R + std::panic::UnwindSafe,
         S: std::fmt::Display,
     {
-        let info: Arc<Mutex<Option<GodotPanicInfo>>> = Arc::new(Mutex::new(None));
-
-        // Back up previous hook, set new one
-        let prev_hook = std::panic::take_hook();
-        {
-            let info = info.clone();
-            std::panic::set_hook(Box::new(move |panic_info| {
-                if let Some(location) = panic_info.location() {
-                    *info.lock().unwrap() = Some(GodotPanicInfo {
-                        file: location.file().to_string(),
-                        line: location.line(),
-                        //backtrace: Backtrace::capture(),
-                    });
-                } else {
-                    println!("panic occurred but can't get location information...");
-                }
-            }));
-        }
+        crate::panic_report::catch_panic(
+            || crate::panic_report::PanicContext::Other(error_context().to_string()),
+            code,
+        )
+    }
 
-        // Run code that should panic, restore hook
-        let panic = std::panic::catch_unwind(code);
-        std::panic::set_hook(prev_hook);
+    /// Like [`handle_panic()`], for calls from Godot into methods of user classes. The report includes class, method and instance.
+    #[must_use]
+    pub fn handle_call_panic<E, F, R>(call_info: E, code: F) -> Option<R>
+    where
+        E: FnOnce() -> crate::panic_report::CallInfo,
+        F: FnOnce() -> R + std::panic::UnwindSafe,
+    {
+        crate::panic_report::catch_panic(
+            || crate::panic_report::PanicContext::Call(call_info()),
+            code,
+        )
+    }
 
-        match panic {
-            Ok(result) => Some(result),
+    /// Converts the result of a fallible `#[func]` into the `Variant` returned to Godot.
+    ///
+    /// On `Err`, pushes a script error with the message produced by `describe` (see [`FuncError`]) and returns the value of `on_error`.
+    pub fn func_result_to_variant<T, E>(
+        result: Result<T, E>,
+        method_name: &str,
+        describe: fn(&E) -> String,
+        on_error: fn(&E) -> crate::builtin::Variant,
+    ) -> crate::builtin::Variant
+    where
+        T: crate::builtin::meta::ToGodot,
+        E: std::fmt::Display,
+    {
+        match result {
+            Ok(value) => value.to_variant(),
             Err(err) => {
-                // Flush, to make sure previous Rust output (e.g. test announcement, or debug prints during app) have been printed
-                // TODO write custom panic handler and move this there, before panic backtrace printing
-                flush_stdout();
-
-                let guard = info.lock().unwrap();
-                let info = guard.as_ref().expect("no panic info available");
-                log::godot_error!(
-                    "Rust function panicked in file {} at line {}. Context: {}",
-                    info.file,
-                    info.line,
-                    error_context()
-                );
-                //eprintln!("Backtrace:\n{}", info.backtrace);
-                print_panic(err);
-                None
+                log::godot_script_error!("{method_name}: {}", describe(&err));
+                on_error(&err)
             }
         }
     }
 
+    /// Error returned by a `#[func]`, formatted through autoref specialization.
+    ///
+    /// `(&&FuncError(err)).describe()` includes the [`source()`][std::error::Error::source] chain if `err` implements
+    /// `std::error::Error`, and falls back to its alternate `Display` output otherwise (e.g. for `String` or `anyhow::Error`).
+    pub struct FuncError<'a, E>(pub &'a E);
+
+    pub trait FuncErrorChain {
+        fn describe(&self) -> String;
+    }
+
+    impl<E: std::error::Error> FuncErrorChain for &FuncError<'_, E> {
+        fn describe(&self) -> String {
+            error_chain_to_string(self.0)
+        }
+    }
+
+    pub trait FuncErrorDisplay {
+        fn describe(&self) -> String;
+    }
+
+    impl<E: std::fmt::Display> FuncErrorDisplay for FuncError<'_, E> {
+        fn describe(&self) -> String {
+            format!("{:#}", self.0)
+        }
+    }
+
+    /// Formats an error followed by each of its causes, one per line.
+    pub fn error_chain_to_string(err: &dyn std::error::Error) -> String {
+        use std::fmt::Write;
+
+        let mut message = err.to_string();
+        let mut source = err.source();
+        while let Some(cause) = source {
+            let _ = write!(message, "\n  caused by: {cause}");
+            source = cause.source();
+        }
+        message
+    }
+
     pub fn flush_stdout() {
         use std::io::Write;
         std::io::stdout().flush().expect("flush stdout");
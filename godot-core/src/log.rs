@@ -14,11 +14,14 @@ macro_rules! inner_godot_msg {
             let msg = format!("{}\0", format_args!($fmt $(, $args)*));
             // assert!(msg.is_ascii(), "godot_error: message must be ASCII");
 
+            fn __godot_msg_fn() {}
+            let function = format!("{}\0", $crate::log::function_name_of(__godot_msg_fn));
+
             // Check whether engine is loaded, otherwise fall back to stderr.
             if $crate::sys::is_initialized() {
                 $crate::sys::interface_fn!($godot_fn)(
                     $crate::sys::c_str_from_str(&msg),
-                    $crate::sys::c_str(b"<function unset>\0"),
+                    $crate::sys::c_str_from_str(&function),
                     $crate::sys::c_str_from_str(concat!(file!(), "\0")),
                     line!() as i32,
                     false as $crate::sys::GDExtensionBool, // whether to create a toast notification in editor
@@ -75,6 +78,12 @@ macro_rules! godot_print {
 
 pub use crate::{godot_error, godot_print, godot_script_error, godot_warn};
 
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod bridge;
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub use bridge::*;
+
 use crate::builtin::{StringName, Variant};
 use crate::sys::{self, GodotFfi};
 
@@ -100,3 +109,51 @@ pub fn print(varargs: &[Variant]) {
     // TODO use generated method, but figure out how print() with zero args can be called
     // crate::engine::utilities::print(head, rest);
 }
+
+/// Returns the path of the function enclosing `marker`, a local `fn` item declared for this purpose.
+///
+/// Used by the `godot_*!` macros to report the calling function, e.g. `my_crate::player::Player::ready`.
+#[doc(hidden)]
+pub fn function_name_of<F>(_marker: F) -> &'static str {
+    let full_name = std::any::type_name::<F>();
+    let mut name = full_name
+        .rsplit_once("::")
+        .map_or(full_name, |(enclosing, _marker)| enclosing);
+
+    // Closures add a `{{closure}}` segment per nesting level, which is not helpful in Godot's output.
+    while let Some(stripped) = name.strip_suffix("::{{closure}}") {
+        name = stripped;
+    }
+    name
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::function_name_of;
+
+    struct Player;
+
+    impl Player {
+        fn ready(&self) -> &'static str {
+            fn marker() {}
+            function_name_of(marker)
+        }
+    }
+
+    #[test]
+    fn function_name_of_method() {
+        assert_eq!(Player.ready(), "godot_core::log::tests::Player::ready");
+    }
+
+    #[test]
+    fn function_name_of_closure() {
+        let name = (|| {
+            fn marker() {}
+            function_name_of(marker)
+        })();
+
+        assert_eq!(name, "godot_core::log::tests::function_name_of_closure");
+    }
+}
@@ -0,0 +1,406 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Forwarding of `log` and `tracing` records to Godot's output.
+
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::GString;
+use crate::engine::global::PropertyHint;
+use crate::property::PropertyHintInfo;
+use crate::settings::{self, SettingInfo};
+use crate::sys;
+
+/// Project setting that holds the most verbose level forwarded to Godot.
+///
+/// Stored as integer: 0 = off, 1 = error, 2 = warn, 3 = info, 4 = debug, 5 = trace. Defaults to info.
+pub const LOG_LEVEL_SETTING: &str = "debug/rust/log_level";
+
+const LEVEL_OFF: u8 = 0;
+const LEVEL_ERROR: u8 = 1;
+const LEVEL_WARN: u8 = 2;
+const LEVEL_INFO: u8 = 3;
+const LEVEL_DEBUG: u8 = 4;
+const LEVEL_TRACE: u8 = 5;
+
+/// Most verbose level that is forwarded, mirroring [`LOG_LEVEL_SETTING`].
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(LEVEL_INFO);
+
+/// Whether a logger or layer has been installed, which needs the level setting.
+static IS_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Whether `ProjectSettings` can be used, i.e. the `Scene` init level is loaded.
+static IS_SETTINGS_AVAILABLE: AtomicBool = AtomicBool::new(false);
+
+/// Whether [`LOG_LEVEL_SETTING`] has been registered during the current load of the extension.
+static IS_REGISTERED: AtomicBool = AtomicBool::new(false);
+
+fn is_level_enabled(level: u8) -> bool {
+    level != LEVEL_OFF && level <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Requests that the level follows [`LOG_LEVEL_SETTING`].
+///
+/// If the settings are not yet available (logger installed in an early init level), registration is deferred to [`on_scene_init()`].
+fn request_level_from_settings() {
+    IS_REQUESTED.store(true, Ordering::Relaxed);
+
+    if IS_SETTINGS_AVAILABLE.load(Ordering::Relaxed) {
+        init_level_from_settings();
+    }
+}
+
+/// Called by gdext once the `Scene` init level is loaded, before user code runs.
+pub(crate) fn on_scene_init() {
+    IS_SETTINGS_AVAILABLE.store(true, Ordering::Relaxed);
+
+    if IS_REQUESTED.load(Ordering::Relaxed) {
+        init_level_from_settings();
+    }
+}
+
+/// Called by gdext before the `Scene` init level is unloaded. A subsequent load (hot reload) registers the setting again.
+pub(crate) fn on_scene_deinit() {
+    IS_SETTINGS_AVAILABLE.store(false, Ordering::Relaxed);
+    IS_REGISTERED.store(false, Ordering::Relaxed);
+}
+
+/// Registers [`LOG_LEVEL_SETTING`] and follows its value. Only the first call per load of the extension has an effect.
+fn init_level_from_settings() {
+    if IS_REGISTERED.swap(true, Ordering::Relaxed) {
+        return;
+    }
+
+    settings::register_setting(&SettingInfo {
+        path: LOG_LEVEL_SETTING,
+        default_value: (LEVEL_INFO as i64).to_variant(),
+        hint_info: PropertyHintInfo {
+            hint: PropertyHint::PROPERTY_HINT_ENUM,
+            hint_string: GString::from("Off,Error,Warn,Info,Debug,Trace"),
+        },
+        restart_if_changed: false,
+        basic: false,
+    });
+    update_level_from_settings();
+
+    #[cfg(since_api = "4.2")]
+    {
+        let project_settings = crate::engine::ProjectSettings::singleton();
+        crate::builtin::Signal::from_object_signal(&project_settings, "settings_changed")
+            .connect_owned(&project_settings, |_args| {
+                update_level_from_settings();
+                Ok(crate::builtin::Variant::nil())
+            })
+            .detach();
+    }
+}
+
+fn update_level_from_settings() {
+    let level = settings::get_setting_or(LOG_LEVEL_SETTING, || LEVEL_INFO as i64);
+    MAX_LEVEL.store(level_from_setting(level), Ordering::Relaxed);
+}
+
+/// Maps the setting's value to a level; out-of-range values are clamped.
+fn level_from_setting(value: i64) -> u8 {
+    value.clamp(LEVEL_OFF as i64, LEVEL_TRACE as i64) as u8
+}
+
+/// Location of a record in the Rust sources.
+struct Location<'a> {
+    module: Option<&'a str>,
+    file: Option<&'a str>,
+    line: Option<u32>,
+}
+
+/// Sends a record to Godot: errors and warnings to the debugger (with source location), everything else to the console.
+fn emit(level: u8, target: &str, message: &str, location: Location) {
+    if !unsafe { sys::is_initialized() } {
+        eprintln!("[{}] {target}: {message}", level_name(level));
+        return;
+    }
+
+    let print_fn = match level {
+        LEVEL_ERROR => sys::interface_fn!(print_error),
+        LEVEL_WARN => sys::interface_fn!(print_warning),
+        _ => {
+            let line = format!("[{}] {target}: {message}", level_name(level));
+            super::print(&[GString::from(line).to_variant()]);
+            return;
+        }
+    };
+
+    let message = format!("{message}\0");
+    let function = format!("{}\0", location.module.unwrap_or(target));
+    let file = format!("{}\0", location.file.unwrap_or("<unknown>"));
+    let line = location.line.unwrap_or(0) as i32;
+
+    // SAFETY: all strings are NUL-terminated and outlive the call; Godot copies them.
+    unsafe {
+        print_fn(
+            sys::c_str_from_str(&message),
+            sys::c_str_from_str(&function),
+            sys::c_str_from_str(&file),
+            line,
+            false as sys::GDExtensionBool, // whether to create a toast notification in editor
+        );
+    }
+}
+
+fn level_name(level: u8) -> &'static str {
+    match level {
+        LEVEL_ERROR => "ERROR",
+        LEVEL_WARN => "WARN",
+        LEVEL_INFO => "INFO",
+        LEVEL_DEBUG => "DEBUG",
+        _ => "TRACE",
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// log
+
+/// Installs a [`log`] logger that forwards records to Godot.
+///
+/// Errors and warnings are pushed to the debugger with the record's file, line and module; other levels are printed to the console.
+/// The most verbose forwarded level is read from the project setting [`LOG_LEVEL_SETTING`], which is registered by this function.
+///
+/// Call this once, typically in [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init]. When called before
+/// the `Scene` level is loaded, the default level (info) is used until the setting is registered at that level.
+/// Fails if another logger has already been installed.
+#[cfg(feature = "log")]
+pub fn init_log() -> Result<(), log::SetLoggerError> {
+    static LOGGER: GodotLogger = GodotLogger;
+
+    log::set_logger(&LOGGER)?;
+    log::set_max_level(log::LevelFilter::Trace);
+    request_level_from_settings();
+    Ok(())
+}
+
+#[cfg(feature = "log")]
+struct GodotLogger;
+
+#[cfg(feature = "log")]
+impl GodotLogger {
+    fn level(level: log::Level) -> u8 {
+        match level {
+            log::Level::Error => LEVEL_ERROR,
+            log::Level::Warn => LEVEL_WARN,
+            log::Level::Info => LEVEL_INFO,
+            log::Level::Debug => LEVEL_DEBUG,
+            log::Level::Trace => LEVEL_TRACE,
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl log::Log for GodotLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        is_level_enabled(Self::level(metadata.level()))
+    }
+
+    fn log(&self, record: &log::Record) {
+        let level = Self::level(record.level());
+        if !is_level_enabled(level) {
+            return;
+        }
+
+        let location = Location {
+            module: record.module_path(),
+            file: record.file(),
+            line: record.line(),
+        };
+        emit(level, record.target(), &record.args().to_string(), location);
+    }
+
+    fn flush(&self) {}
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// tracing
+
+/// A [`tracing_subscriber::Layer`] that forwards events to Godot.
+///
+/// Events are routed like `log` records; fields other than `message` are appended as `key=value`. Spans are not reported.
+/// The most verbose forwarded level is read from the project setting [`LOG_LEVEL_SETTING`], which is registered on construction.
+///
+/// ```ignore
+/// use tracing_subscriber::prelude::*;
+///
+/// tracing_subscriber::registry()
+///     .with(godot::log::GodotTracingLayer::new())
+///     .init();
+/// ```
+#[cfg(feature = "tracing")]
+#[derive(Debug)]
+pub struct GodotTracingLayer {
+    _private: (),
+}
+
+#[cfg(feature = "tracing")]
+impl GodotTracingLayer {
+    /// Creates the layer. The level setting is registered once the `Scene` init level is loaded.
+    pub fn new() -> Self {
+        request_level_from_settings();
+        Self { _private: () }
+    }
+
+    fn level(level: &tracing_core::Level) -> u8 {
+        match *level {
+            tracing_core::Level::ERROR => LEVEL_ERROR,
+            tracing_core::Level::WARN => LEVEL_WARN,
+            tracing_core::Level::INFO => LEVEL_INFO,
+            tracing_core::Level::DEBUG => LEVEL_DEBUG,
+            _ => LEVEL_TRACE,
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Default for GodotTracingLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<S: tracing_core::Subscriber> tracing_subscriber::Layer<S> for GodotTracingLayer {
+    fn register_callsite(
+        &self,
+        _metadata: &'static tracing_core::Metadata<'static>,
+    ) -> tracing_core::subscriber::Interest {
+        // The level can change at runtime, so interest must not be cached.
+        tracing_core::subscriber::Interest::sometimes()
+    }
+
+    fn enabled(
+        &self,
+        metadata: &tracing_core::Metadata<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) -> bool {
+        is_level_enabled(Self::level(metadata.level()))
+    }
+
+    fn on_event(
+        &self,
+        event: &tracing_core::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let metadata = event.metadata();
+        let level = Self::level(metadata.level());
+        if !is_level_enabled(level) {
+            return;
+        }
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+
+        let location = Location {
+            module: metadata.module_path(),
+            file: metadata.file(),
+            line: metadata.line(),
+        };
+        emit(level, metadata.target(), &visitor.finish(), location);
+    }
+}
+
+/// Collects the `message` field and formats other fields as `key=value`.
+#[cfg(feature = "tracing")]
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: String,
+}
+
+#[cfg(feature = "tracing")]
+impl FieldVisitor {
+    fn record(&mut self, name: &str, value: &dyn std::fmt::Debug) {
+        use std::fmt::Write as _;
+
+        if name == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            if !self.fields.is_empty() {
+                self.fields.push(' ');
+            }
+            let _ = write!(self.fields, "{name}={value:?}");
+        }
+    }
+
+    fn finish(self) -> String {
+        match (self.message.is_empty(), self.fields.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.fields,
+            (false, false) => format!("{} {}", self.message, self.fields),
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl tracing_core::field::Visit for FieldVisitor {
+    fn record_str(&mut self, field: &tracing_core::Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &tracing_core::Field, value: &dyn std::fmt::Debug) {
+        self.record(field.name(), value);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_filtering() {
+        let previous = MAX_LEVEL.swap(LEVEL_WARN, Ordering::Relaxed);
+
+        assert!(is_level_enabled(LEVEL_ERROR));
+        assert!(is_level_enabled(LEVEL_WARN));
+        assert!(!is_level_enabled(LEVEL_INFO));
+        assert!(!is_level_enabled(LEVEL_TRACE));
+        assert!(!is_level_enabled(LEVEL_OFF));
+
+        MAX_LEVEL.store(LEVEL_OFF, Ordering::Relaxed);
+        assert!(!is_level_enabled(LEVEL_ERROR));
+
+        MAX_LEVEL.store(previous, Ordering::Relaxed);
+    }
+
+    #[test]
+    fn level_setting_values() {
+        assert_eq!(level_from_setting(0), LEVEL_OFF);
+        assert_eq!(level_from_setting(3), LEVEL_INFO);
+        assert_eq!(level_from_setting(5), LEVEL_TRACE);
+        assert_eq!(level_from_setting(-1), LEVEL_OFF);
+        assert_eq!(level_from_setting(42), LEVEL_TRACE);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn field_visitor_formatting() {
+        let mut visitor = FieldVisitor::default();
+        visitor.record("message", &format_args!("player spawned"));
+        visitor.record("id", &7);
+        visitor.record("name", &"Rusty");
+        assert_eq!(visitor.finish(), r#"player spawned id=7 name="Rusty""#);
+
+        let mut visitor = FieldVisitor::default();
+        visitor.record("id", &7);
+        assert_eq!(visitor.finish(), "id=7");
+
+        let mut visitor = FieldVisitor::default();
+        visitor.record("message", &format_args!("only message"));
+        assert_eq!(visitor.finish(), "only message");
+    }
+}
@@ -0,0 +1,293 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::{Any, TypeId};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::ops::{Deref, DerefMut};
+
+use crate::engine::Object;
+use crate::obj::{dom, Gd, GdMut, GdRef, GodotClass, Inherits};
+
+/// Smart pointer to a Godot object, which can additionally be accessed through the Rust trait object `D`.
+///
+/// Obtained from [`Gd::try_dyn()`], which looks up the implementation of `D` for the dynamic class of the object at runtime, or from
+/// [`Gd::into_dyn()`] if the class is statically known. Implementations must be annotated with `#[godot_dyn]`:
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// trait Damageable {
+///     fn take_damage(&mut self, amount: i32);
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct Enemy {
+///     health: i32,
+///     #[base]
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_dyn]
+/// impl Damageable for Enemy {
+///     fn take_damage(&mut self, amount: i32) {
+///         self.health -= amount;
+///     }
+/// }
+///
+/// fn on_collision(body: Gd<Node2D>) {
+///     if let Ok(mut damageable) = body.try_dyn::<dyn Damageable>() {
+///         damageable.dyn_bind_mut().take_damage(10);
+///     }
+/// }
+/// ```
+///
+/// `DynGd<T, D>` dereferences to `Gd<T>`, so methods of `T` can be called directly. The trait object is borrowed through
+/// [`dyn_bind()`][Self::dyn_bind] and [`dyn_bind_mut()`][Self::dyn_bind_mut], with the same rules as [`Gd::bind()`] and
+/// [`Gd::bind_mut()`].
+pub struct DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    obj: Gd<T>,
+
+    // Points to the same object, typed as the class implementing D.
+    erased_obj: Box<dyn ErasedDynGd<D>>,
+}
+
+impl<T, D> DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    pub(super) fn from_user_gd<U>(obj: Gd<T>, user_obj: Gd<U>) -> Self
+    where
+        U: AsDyn<D> + GodotClass<Declarer = dom::UserDomain>,
+    {
+        Self {
+            obj,
+            erased_obj: Box::new(user_obj),
+        }
+    }
+
+    pub(super) fn try_from_gd(obj: Gd<T>) -> Result<Self, Gd<T>>
+    where
+        T: Inherits<Object>,
+    {
+        let type_ids = obj.raw.rust_type_ids();
+        let Some(erased_dyn_fn) =
+            crate::registry::find_dyn_trait_impl(&type_ids, TypeId::of::<D>())
+        else {
+            return Err(obj);
+        };
+
+        let erased_obj = erased_dyn_fn(obj.clone().upcast())
+            .downcast::<Box<dyn ErasedDynGd<D>>>()
+            .expect("DynGd: bad type erasure");
+
+        Ok(Self {
+            obj,
+            erased_obj: *erased_obj,
+        })
+    }
+
+    /// Hands out a guard for a shared borrow, through which the object can be accessed as `&D`.
+    ///
+    /// # Panics
+    /// Like [`Gd::bind()`], if the Rust instance implementing `D` is exclusively bound.
+    pub fn dyn_bind(&self) -> DynGdRef<D> {
+        self.erased_obj.dyn_bind()
+    }
+
+    /// Hands out a guard for an exclusive borrow, through which the object can be accessed as `&mut D`.
+    ///
+    /// # Panics
+    /// Like [`Gd::bind_mut()`], if the Rust instance implementing `D` is bound.
+    pub fn dyn_bind_mut(&mut self) -> DynGdMut<D> {
+        self.erased_obj.dyn_bind_mut()
+    }
+
+    /// **Upcast:** convert into a smart pointer to a base class, keeping access to `D`.
+    pub fn upcast<Base>(self) -> DynGd<Base, D>
+    where
+        Base: GodotClass,
+        T: Inherits<Base>,
+    {
+        DynGd {
+            obj: self.obj.upcast(),
+            erased_obj: self.erased_obj,
+        }
+    }
+
+    /// Returns the underlying `Gd<T>`, giving up access to `D`.
+    pub fn into_gd(self) -> Gd<T> {
+        self.obj
+    }
+}
+
+impl<T, D> Deref for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    type Target = Gd<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.obj
+    }
+}
+
+// No DerefMut: assigning a different object to the Gd<T> would make it disagree with the trait object.
+
+impl<T, D> Clone for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            obj: self.obj.clone(),
+            erased_obj: self.erased_obj.clone_box(),
+        }
+    }
+}
+
+impl<T, D> Debug for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        crate::engine::debug_string(&self.obj, f, "DynGd")
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Guards
+
+/// Shared borrow of the trait object `D` behind a [`DynGd`].
+///
+/// See [`DynGd::dyn_bind()`] for usage.
+pub struct DynGdRef<'a, D: ?Sized> {
+    // Keeps the instance borrowed while `cached_ptr` is used.
+    _guard: Box<dyn ErasedGuard<'a> + 'a>,
+    cached_ptr: *const D,
+}
+
+impl<'a, D: ?Sized + 'static> DynGdRef<'a, D> {
+    fn from_guard<T: AsDyn<D>>(guard: GdRef<'a, T>) -> Self {
+        // The instance lives inside its storage, so the pointer stays valid when the guard is moved into the box.
+        let cached_ptr = guard.dyn_upcast() as *const D;
+
+        Self {
+            _guard: Box::new(guard),
+            cached_ptr,
+        }
+    }
+}
+
+impl<D: ?Sized> Deref for DynGdRef<'_, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        // SAFETY: points into the instance, which is borrowed through `_guard` for the lifetime of self.
+        unsafe { &*self.cached_ptr }
+    }
+}
+
+/// Exclusive borrow of the trait object `D` behind a [`DynGd`].
+///
+/// See [`DynGd::dyn_bind_mut()`] for usage.
+pub struct DynGdMut<'a, D: ?Sized> {
+    // Keeps the instance borrowed while `cached_ptr` is used.
+    _guard: Box<dyn ErasedGuard<'a> + 'a>,
+    cached_ptr: *mut D,
+}
+
+impl<'a, D: ?Sized + 'static> DynGdMut<'a, D> {
+    fn from_guard<T: AsDyn<D>>(mut guard: GdMut<'a, T>) -> Self {
+        // The instance lives inside its storage, so the pointer stays valid when the guard is moved into the box.
+        let cached_ptr = guard.dyn_upcast_mut() as *mut D;
+
+        Self {
+            _guard: Box::new(guard),
+            cached_ptr,
+        }
+    }
+}
+
+impl<D: ?Sized> Deref for DynGdMut<'_, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        // SAFETY: points into the instance, which is exclusively borrowed through `_guard` for the lifetime of self.
+        unsafe { &*self.cached_ptr }
+    }
+}
+
+impl<D: ?Sized> DerefMut for DynGdMut<'_, D> {
+    fn deref_mut(&mut self) -> &mut D {
+        // SAFETY: see deref().
+        unsafe { &mut *self.cached_ptr }
+    }
+}
+
+/// Any guard, only kept alive for its destructor.
+trait ErasedGuard<'a>: 'a {}
+
+impl<'a, G: 'a> ErasedGuard<'a> for G {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type erasure
+
+/// Implemented for user classes that implement the trait behind `D`, in an impl annotated with `#[godot_dyn]`.
+///
+/// This trait is not manually implemented; `#[godot_dyn]` generates the implementation.
+pub trait AsDyn<D: ?Sized>: GodotClass {
+    #[doc(hidden)]
+    fn dyn_upcast(&self) -> &D;
+
+    #[doc(hidden)]
+    fn dyn_upcast_mut(&mut self) -> &mut D;
+}
+
+/// `Gd<U>` for the user class `U` implementing `D`, whose type is only known to the registry.
+trait ErasedDynGd<D: ?Sized> {
+    fn dyn_bind(&self) -> DynGdRef<D>;
+    fn dyn_bind_mut(&mut self) -> DynGdMut<D>;
+    fn clone_box(&self) -> Box<dyn ErasedDynGd<D>>;
+}
+
+impl<U, D> ErasedDynGd<D> for Gd<U>
+where
+    U: AsDyn<D> + GodotClass<Declarer = dom::UserDomain>,
+    D: ?Sized + 'static,
+{
+    fn dyn_bind(&self) -> DynGdRef<D> {
+        DynGdRef::from_guard(self.bind())
+    }
+
+    fn dyn_bind_mut(&mut self) -> DynGdMut<D> {
+        DynGdMut::from_guard(self.bind_mut())
+    }
+
+    fn clone_box(&self) -> Box<dyn ErasedDynGd<D>> {
+        Box::new(self.clone())
+    }
+}
+
+/// Converts an object of class `U` into a `Box<dyn ErasedDynGd<D>>`, type-erased as `Box<dyn Any>`.
+///
+/// Registered for each `#[godot_dyn]` impl, see `DynGd::try_from_gd()`.
+pub(crate) fn erase_dyn_gd<U, D>(object: Gd<Object>) -> Box<dyn Any>
+where
+    U: AsDyn<D> + Inherits<Object> + GodotClass<Declarer = dom::UserDomain>,
+    D: ?Sized + 'static,
+{
+    let erased_obj: Box<dyn ErasedDynGd<D>> = Box::new(object.cast::<U>());
+    Box::new(erased_obj)
+}
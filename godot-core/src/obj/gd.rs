@@ -12,11 +12,11 @@ use godot_ffi::VariantType;
 use sys::static_assert_eq_size;
 
 use crate::builtin::meta::{
-    ConvertError, FromFfiError, FromGodot, GodotConvert, GodotType, ToGodot,
+    ConvertError, FromFfiError, FromGodot, GodotConvert, GodotType, PropertyName, ToGodot,
 };
 use crate::builtin::{Callable, StringName};
 use crate::obj::{cap, dom, mem, EngineEnum, GdDerefTarget, GodotClass, Inherits, Share};
-use crate::obj::{GdMut, GdRef, InstanceId};
+use crate::obj::{AsDyn, DynGd, GdMut, GdRef, InstanceId, WeakGd};
 use crate::property::{Export, Property, PropertyHintInfo, TypeStringHint};
 use crate::{callbacks, engine, out};
 
@@ -176,7 +176,11 @@ where
     /// * If another `Gd` smart pointer pointing to the same Rust instance has a live `GdMut` guard bound.
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
+    ///
+    /// In debug builds, the panic message lists each guard that is still bound, with its source location and thread, and the
+    /// `#[func]` or callback it was taken for.
     // Note: possible names: write/read, hold/hold_mut, r/w, r/rw, ...
+    #[track_caller]
     pub fn bind(&self) -> GdRef<T> {
         self.raw.bind()
     }
@@ -192,9 +196,25 @@ where
     /// * If another `Gd` smart pointer pointing to the same Rust instance has a live `GdRef` or `GdMut` guard bound.
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&T` or `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
+    ///
+    /// In debug builds, the panic message lists each guard that is still bound, with its source location and thread, and the
+    /// `#[func]` or callback it was taken for.
+    #[track_caller]
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Gives access to the object through the Rust trait object `D`, which `T` implements in an impl annotated with `#[godot_dyn]`.
+    ///
+    /// Unlike [`try_dyn()`][Self::try_dyn], this needs no runtime lookup.
+    pub fn into_dyn<D>(self) -> DynGd<T, D>
+    where
+        D: ?Sized + 'static,
+        T: AsDyn<D>,
+    {
+        let user_obj = self.clone();
+        DynGd::from_user_gd(self, user_obj)
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -281,6 +301,16 @@ impl<T: GodotClass> Gd<T> {
         self.instance_id_or_none().is_some()
     }
 
+    /// Creates a weak pointer to this object, which does not keep it alive.
+    ///
+    /// For ref-counted objects, the reference count is not incremented. See [`WeakGd`] for details.
+    ///
+    /// # Panics
+    /// If this object is no longer alive.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        WeakGd::from_instance_id(self.instance_id())
+    }
+
     /// **Upcast:** convert into a smart pointer to a base class. Always succeeds.
     ///
     /// Moves out of this value. If you want to create _another_ smart pointer instance,
@@ -333,8 +363,20 @@ impl<T: GodotClass> Gd<T> {
         })
     }
 
+    /// **Trait downcast:** try to access the object through the Rust trait object `D`.
+    ///
+    /// Succeeds if the dynamic class of the object, or one of its Rust base classes, implements the trait in an impl annotated
+    /// with `#[godot_dyn]`. Otherwise, `self` is returned unchanged. See [`DynGd`] for an example.
+    pub fn try_dyn<D>(self) -> Result<DynGd<T, D>, Self>
+    where
+        D: ?Sized + 'static,
+        T: Inherits<engine::Object>,
+    {
+        DynGd::try_from_gd(self)
+    }
+
     /// Returns `Ok(cast_obj)` on success, `Err(self)` on error
-    fn owned_cast<U>(self) -> Result<Gd<U>, Self>
+    pub(super) fn owned_cast<U>(self) -> Result<Gd<U>, Self>
     where
         U: GodotClass,
     {
@@ -389,6 +431,42 @@ impl<T: GodotClass> Gd<T> {
     pub fn callable<S: Into<StringName>>(&self, method_name: S) -> Callable {
         Callable::from_object_method(self.clone(), method_name)
     }
+
+    /// Returns the value of a property declared by the engine class `C`, or one of its bases.
+    ///
+    /// Property names are available as constants in each class' `properties` module, e.g.
+    /// `godot::engine::node_2d::properties::POSITION`. Indexed properties such as `theme_override_colors/font_color` are supported.
+    ///
+    /// # Panics
+    /// If the stored value cannot be converted to `V`.
+    pub fn get_property<C, V>(&self, property: PropertyName<C, V>) -> V
+    where
+        C: GodotClass,
+        T: Inherits<C>,
+        V: FromGodot,
+    {
+        let name = property.to_string_name();
+        let variant = self.raw.as_object(|obj| obj.get(name.clone()));
+
+        variant.try_to::<V>().unwrap_or_else(|err| {
+            panic!("property `{property}` holds a value of unexpected type: {err}")
+        })
+    }
+
+    /// Sets the value of a property declared by the engine class `C`, or one of its bases.
+    ///
+    /// See [`get_property()`][Self::get_property] for details.
+    pub fn set_property<C, V>(&mut self, property: PropertyName<C, V>, value: V)
+    where
+        C: GodotClass,
+        T: Inherits<C>,
+        V: ToGodot,
+    {
+        let name = property.to_string_name();
+        let variant = value.to_variant();
+        self.raw
+            .as_object(|obj| obj.set(name.clone(), variant.clone()));
+    }
 }
 
 impl<T: GodotClass> Deref for Gd<T> {
@@ -6,33 +6,21 @@
 
 use godot_ffi::out;
 
-#[cfg(not(feature = "experimental-threads"))]
-use std::cell;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
-#[cfg(feature = "experimental-threads")]
-use std::sync;
+
+use crate::storage::{MutGuard, RefGuard};
 
 /// Immutably/shared bound reference guard for a [`Gd`][crate::obj::Gd] smart pointer.
 ///
 /// See [`Gd::bind`][crate::obj::Gd::bind] for usage.
 #[derive(Debug)]
 pub struct GdRef<'a, T> {
-    #[cfg(not(feature = "experimental-threads"))]
-    cell_ref: cell::Ref<'a, T>,
-
-    #[cfg(feature = "experimental-threads")]
-    cell_ref: sync::RwLockReadGuard<'a, T>,
+    cell_ref: RefGuard<'a, T>,
 }
 
 impl<'a, T> GdRef<'a, T> {
-    #[cfg(not(feature = "experimental-threads"))]
-    pub(crate) fn from_cell(cell_ref: cell::Ref<'a, T>) -> Self {
-        Self { cell_ref }
-    }
-
-    #[cfg(feature = "experimental-threads")]
-    pub(crate) fn from_cell(cell_ref: sync::RwLockReadGuard<'a, T>) -> Self {
+    pub(crate) fn from_cell(cell_ref: RefGuard<'a, T>) -> Self {
         out!("GdRef init: {:?}", std::any::type_name::<T>());
         Self { cell_ref }
     }
@@ -61,21 +49,11 @@ impl<T> Drop for GdRef<'_, T> {
 /// See [`Gd::bind_mut`][crate::obj::Gd::bind_mut] for usage.
 #[derive(Debug)]
 pub struct GdMut<'a, T> {
-    #[cfg(not(feature = "experimental-threads"))]
-    cell_ref: cell::RefMut<'a, T>,
-
-    #[cfg(feature = "experimental-threads")]
-    cell_ref: sync::RwLockWriteGuard<'a, T>,
+    cell_ref: MutGuard<'a, T>,
 }
 
 impl<'a, T> GdMut<'a, T> {
-    #[cfg(not(feature = "experimental-threads"))]
-    pub(crate) fn from_cell(cell_ref: cell::RefMut<'a, T>) -> Self {
-        Self { cell_ref }
-    }
-
-    #[cfg(feature = "experimental-threads")]
-    pub(crate) fn from_cell(cell_ref: sync::RwLockWriteGuard<'a, T>) -> Self {
+    pub(crate) fn from_cell(cell_ref: MutGuard<'a, T>) -> Self {
         out!("GdMut init: {:?}", std::any::type_name::<T>());
         Self { cell_ref }
     }
@@ -11,17 +11,23 @@
 //! * [`Gd`], a smart pointer that manages instances of Godot classes.
 
 mod base;
+mod dyn_gd;
 mod gd;
 mod guards;
 mod instance_id;
+mod onready;
 mod raw;
 mod traits;
+mod weak_gd;
 
 pub use base::*;
+pub use dyn_gd::*;
 pub use gd::*;
 pub use guards::*;
 pub use instance_id::*;
+pub use onready::*;
 pub use raw::*;
 pub use traits::*;
+pub use weak_gd::*;
 
-type GdDerefTarget<T> = <<T as GodotClass>::Declarer as dom::Domain>::DerefTarget<T>;
+pub(crate) type GdDerefTarget<T> = <<T as GodotClass>::Declarer as dom::Domain>::DerefTarget<T>;
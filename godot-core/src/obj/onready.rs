@@ -0,0 +1,279 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+use crate::builtin::NodePath;
+use crate::engine::{Node, NodeExt};
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Field of a node class that is initialized in `ready()`, instead of in the constructor.
+///
+/// Nodes often need references to their children, which only exist once the node has entered the scene tree. Instead of storing
+/// `Option<Gd<T>>` and unwrapping it on every access, such fields can be declared as `OnReady<Gd<T>>`. Their value is computed right
+/// before the class' `ready()` method runs, after which they dereference to `T`.
+///
+/// `OnReady<T>` can be initialized in three ways:
+/// - From a node path, with [`OnReady::node()`] or `#[init(node = "Path/To/Child")]` on the field.
+/// - From a closure, with [`OnReady::new()`] or [`OnReady::from_base_fn()`].
+/// - Manually, with [`OnReady::manual()`] and a later call to [`init()`][Self::init], e.g. in `ready()`. This is also the default.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[init(node = "Sprite")]
+///     sprite: OnReady<Gd<Node2D>>,
+///
+///     #[init(default = OnReady::new(|| 100))]
+///     health: OnReady<i32>,
+///
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Player {
+///     fn ready(&mut self) {
+///         // Already initialized here.
+///         self.sprite.set_visible(*self.health > 0);
+///     }
+/// }
+/// ```
+///
+/// Automatic initialization also works if the class does not override `ready()`, or does not implement its interface trait (here `INode`)
+/// at all. Fields of Rust base classes are initialized before those of the derived class.
+///
+/// # Panics
+/// Dereferencing panics if the value is not yet initialized. Initialization from a node path panics if no node of the given type exists,
+/// naming the class and the path. If an initializer panics, its field stays uninitialized, and the fields that were not initialized yet
+/// remain scheduled for the next `ready()`.
+pub struct OnReady<T> {
+    state: InitState<T>,
+}
+
+impl<T> OnReady<T> {
+    /// Schedules the value to be computed by `init_fn` before `ready()`.
+    pub fn new<F>(init_fn: F) -> Self
+    where
+        F: FnOnce() -> T + 'static,
+    {
+        Self::from_base_fn(|_base| init_fn())
+    }
+
+    /// Schedules the value to be computed by `init_fn` before `ready()`, with access to the node itself.
+    pub fn from_base_fn<F>(init_fn: F) -> Self
+    where
+        F: FnOnce(&Gd<Node>) -> T + 'static,
+    {
+        Self {
+            state: InitState::AutoPrepared {
+                initializer: Box::new(init_fn),
+            },
+        }
+    }
+
+    /// Leaves the value uninitialized, until it is set with [`init()`][Self::init].
+    pub fn manual() -> Self {
+        Self {
+            state: InitState::ManualUninitialized,
+        }
+    }
+
+    /// Initializes the value, if it was created with [`manual()`][Self::manual].
+    ///
+    /// # Panics
+    /// If the value is already initialized, or scheduled for automatic initialization.
+    pub fn init(&mut self, value: T) {
+        match &self.state {
+            InitState::ManualUninitialized => {
+                self.state = InitState::Initialized { value };
+            }
+            InitState::AutoPrepared { .. } | InitState::AutoInitializing | InitState::Failed => {
+                panic!("OnReady::init() called on a value that is initialized automatically before ready()");
+            }
+            InitState::Initialized { .. } => {
+                panic!("OnReady::init() called on an already initialized value");
+            }
+        }
+    }
+
+    /// Whether the value is available, i.e. can be dereferenced.
+    pub fn is_initialized(&self) -> bool {
+        matches!(self.state, InitState::Initialized { .. })
+    }
+
+    /// Runs the scheduled initializer, if any. Called before `ready()`; does nothing on subsequent calls.
+    #[doc(hidden)]
+    pub fn init_auto(&mut self, base: &Gd<Node>) {
+        self.state = match mem::replace(&mut self.state, InitState::ManualUninitialized) {
+            InitState::AutoPrepared { initializer } => InitState::Initialized {
+                value: initializer(base),
+            },
+            state => state,
+        };
+    }
+}
+
+/// Scheduled initializer of an `OnReady` field in class `C`, taken out of the instance before `ready()`.
+///
+/// Computing the value does not borrow the instance, so the initializer may access the object. Afterwards, the value is stored with
+/// the returned closure. An initializer that has not completed, e.g. because it or a preceding one panicked, is put back with
+/// [`restore()`][Self::restore].
+#[doc(hidden)]
+pub struct OnReadyInitializer<C> {
+    compute: Box<dyn FnMut(&Gd<Node>) -> Box<dyn FnOnce(&mut C)>>,
+    restore: Box<dyn FnOnce(&mut C)>,
+}
+
+impl<C> OnReadyInitializer<C> {
+    /// Computes the value and returns a closure storing it in the instance. Must be called at most once.
+    pub fn compute(&mut self, base: &Gd<Node>) -> Box<dyn FnOnce(&mut C)> {
+        (self.compute)(base)
+    }
+
+    /// Puts the initializer back into its field, if it has not run yet. Otherwise it panicked, and the field is marked as failed.
+    pub fn restore(self, instance: &mut C) {
+        (self.restore)(instance)
+    }
+}
+
+impl<T: 'static> OnReady<T> {
+    /// Takes the scheduled initializer, if any. `field` locates this value inside the instance, for storing the result.
+    ///
+    /// Until the value is stored, it counts as not initialized.
+    #[doc(hidden)]
+    pub fn take_initializer<C: 'static>(
+        &mut self,
+        field: fn(&mut C) -> &mut Self,
+    ) -> Option<OnReadyInitializer<C>> {
+        let initializer = match mem::replace(&mut self.state, InitState::AutoInitializing) {
+            InitState::AutoPrepared { initializer } => initializer,
+            state => {
+                self.state = state;
+                return None;
+            }
+        };
+
+        // Shared between computing and restoring; taken out once the initializer runs.
+        let slot = Rc::new(Cell::new(Some(initializer)));
+        let restore_slot = Rc::clone(&slot);
+
+        Some(OnReadyInitializer {
+            compute: Box::new(move |base| {
+                let initializer = slot.take().expect("OnReady initializer already computed");
+                let value = initializer(base);
+
+                Box::new(move |instance: &mut C| {
+                    field(instance).state = InitState::Initialized { value };
+                })
+            }),
+            restore: Box::new(move |instance: &mut C| {
+                field(instance).state = match restore_slot.take() {
+                    Some(initializer) => InitState::AutoPrepared { initializer },
+                    None => InitState::Failed,
+                };
+            }),
+        })
+    }
+}
+
+impl<T> OnReady<Gd<T>>
+where
+    T: GodotClass + Inherits<Node>,
+{
+    /// Schedules the node at `path`, relative to this node, to be looked up before `ready()`.
+    ///
+    /// This is what `#[init(node = "Path/To/Child")]` expands to.
+    ///
+    /// # Panics
+    /// During initialization, if there is no node at `path`, or if it does not have type `T` or inherited.
+    pub fn node(path: impl Into<NodePath>) -> Self {
+        let path = path.into();
+
+        Self::from_base_fn(move |base| {
+            base.try_get_node_as::<T>(path.clone()).unwrap_or_else(|| {
+                panic!(
+                    "OnReady: class `{class}` has no child node of type `{ty}` at path `{path}`",
+                    class = base.get_class(),
+                    ty = T::class_name(),
+                )
+            })
+        })
+    }
+}
+
+impl<T> Default for OnReady<T> {
+    /// Same as [`OnReady::manual()`].
+    fn default() -> Self {
+        Self::manual()
+    }
+}
+
+impl<T> Deref for OnReady<T> {
+    type Target = T;
+
+    /// Returns the initialized value.
+    ///
+    /// # Panics
+    /// If the value is not yet initialized.
+    fn deref(&self) -> &T {
+        match &self.state {
+            InitState::Initialized { value } => value,
+            InitState::Failed => panic!("OnReady value not available, because its initializer panicked"),
+            _ => panic!("OnReady value accessed before initialization; it is only available from ready() on"),
+        }
+    }
+}
+
+impl<T> DerefMut for OnReady<T> {
+    /// Returns the initialized value.
+    ///
+    /// # Panics
+    /// If the value is not yet initialized.
+    fn deref_mut(&mut self) -> &mut T {
+        match &mut self.state {
+            InitState::Initialized { value } => value,
+            InitState::Failed => panic!("OnReady value not available, because its initializer panicked"),
+            _ => panic!("OnReady value accessed before initialization; it is only available from ready() on"),
+        }
+    }
+}
+
+impl<T: Debug> Debug for OnReady<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.state {
+            InitState::ManualUninitialized => write!(f, "OnReady(uninitialized)"),
+            InitState::AutoPrepared { .. } | InitState::AutoInitializing => {
+                write!(f, "OnReady(pending)")
+            }
+            InitState::Failed => write!(f, "OnReady(failed)"),
+            InitState::Initialized { value } => write!(f, "OnReady({value:?})"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+enum InitState<T> {
+    ManualUninitialized,
+    AutoPrepared {
+        initializer: Box<dyn FnOnce(&Gd<Node>) -> T>,
+    },
+    /// Initializer has been taken out and is running.
+    AutoInitializing,
+    /// Initializer panicked.
+    Failed,
+    Initialized {
+        value: T,
+    },
+}
@@ -4,6 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::any::TypeId;
 use std::ptr;
 
 use godot_ffi as sys;
@@ -199,7 +200,7 @@ impl<T: GodotClass> RawGd<T> {
 
     // Target is always an engine class:
     // * if T is an engine class => T
-    // * if T is a user class => T::Base
+    // * if T is a user class => the deref target of T::Base, i.e. the first engine class in the inheritance chain
     pub(super) fn as_target(&self) -> Option<&GdDerefTarget<T>> {
         if self.is_null() {
             return None;
@@ -236,7 +237,7 @@ impl<T: GodotClass> RawGd<T> {
 
     // Target is always an engine class:
     // * if T is an engine class => T
-    // * if T is a user class => T::Base
+    // * if T is a user class => the deref target of T::Base, i.e. the first engine class in the inheritance chain
     pub(super) fn as_target_mut(&mut self) -> Option<&mut GdDerefTarget<T>> {
         if self.is_null() {
             return None;
@@ -269,6 +270,7 @@ where
     ///
     /// See [`crate::obj::Gd::bind()`] for a more in depth explanation.
     // Note: possible names: write/read, hold/hold_mut, r/w, r/rw, ...
+    #[track_caller]
     pub(crate) fn bind(&self) -> GdRef<T> {
         engine::ensure_object_alive(self.cached_instance_id, self.obj_sys(), "bind");
         GdRef::from_cell(self.storage().unwrap().get())
@@ -277,6 +279,7 @@ where
     /// Hands out a guard for an exclusive borrow, through which the user instance can be read and written.
     ///
     /// See [`crate::obj::Gd::bind_mut()`] for a more in depth explanation.
+    #[track_caller]
     pub(crate) fn bind_mut(&mut self) -> GdMut<T> {
         engine::ensure_object_alive(self.cached_instance_id, self.obj_sys(), "bind_mut");
         GdMut::from_cell(self.storage().unwrap().get_mut())
@@ -295,6 +298,23 @@ where
         }
     }
 
+    /// `TypeId`s of the Rust classes whose state the object holds, from the most derived class to its Rust base classes.
+    ///
+    /// Empty if the object is not an instance of a Rust class.
+    pub(crate) fn rust_type_ids(&self) -> Vec<TypeId> {
+        engine::ensure_object_alive(self.cached_instance_id, self.obj_sys(), "rust_type_ids");
+
+        // SAFETY: the object is alive, so a non-null binding points to a valid instance.
+        unsafe {
+            let binding = self.find_instance_ptr();
+            if binding.is_null() {
+                Vec::new()
+            } else {
+                crate::storage::stored_type_ids(binding)
+            }
+        }
+    }
+
     unsafe fn resolve_instance_ptr(&self) -> sys::GDExtensionClassInstancePtr {
         if self.is_null() {
             return ptr::null_mut();
@@ -311,6 +331,20 @@ where
         );
         binding as sys::GDExtensionClassInstancePtr
     }
+
+    /// Returns the instance pointer, or null if the object is not an instance of a Rust class.
+    ///
+    /// Unlike [`resolve_instance_ptr()`][Self::resolve_instance_ptr], this never creates an (empty) binding on engine objects.
+    /// The object must not be null.
+    unsafe fn find_instance_ptr(&self) -> sys::GDExtensionClassInstancePtr {
+        let token = sys::get_library() as *mut std::ffi::c_void;
+
+        // Without callbacks, Godot only looks up an existing binding.
+        let binding =
+            interface_fn!(object_get_instance_binding)(self.obj_sys(), token, ptr::null());
+
+        binding as sys::GDExtensionClassInstancePtr
+    }
 }
 
 // SAFETY:
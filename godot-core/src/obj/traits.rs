@@ -6,7 +6,7 @@
 
 use crate::builder::ClassBuilder;
 use crate::builtin::meta::ClassName;
-use crate::builtin::GString;
+use crate::builtin::{Dictionary, GString};
 use crate::init::InitLevel;
 use crate::obj::Gd;
 
@@ -25,7 +25,9 @@ pub unsafe trait GodotClass: 'static
 where
     Self: Sized,
 {
-    /// The immediate superclass of `T`. This is always a Godot engine class.
+    /// The immediate superclass of `T`.
+    ///
+    /// This is a Godot engine class, or another Rust class declared with `#[class(base = ...)]`.
     type Base: GodotClass; // not EngineClass because it can be ()
 
     /// Whether this class is a core Godot class provided by the engine, or declared by the user as a Rust struct.
@@ -41,6 +43,10 @@ where
     /// from.
     const INIT_LEVEL: Option<InitLevel>;
 
+    /// Whether instances carry their state across a hot-reload, see [`HotReload`]. Set by `#[class(hot_reload)]`.
+    #[doc(hidden)]
+    const __HOT_RELOAD: bool = false;
+
     /// The name of the class, under which it is registered in Godot.
     ///
     /// This may deviate from the Rust struct name: `HttpRequest::class_name().as_str() == "HTTPRequest"`.
@@ -159,6 +165,35 @@ pub trait UserClass: GodotClass<Declarer = dom::UserDomain> {
     {
         Gd::default_instance()
     }
+
+    /// Whether the class declares [`OnReady`][crate::obj::OnReady] fields, which are initialized right before `ready()`.
+    #[doc(hidden)]
+    const __HAS_ONREADY: bool = false;
+
+    /// Takes the scheduled initializers of the `OnReady` fields of this class, to run them before `ready()`.
+    #[doc(hidden)]
+    fn __onready_initializers(&mut self) -> Vec<crate::obj::OnReadyInitializer<Self>> {
+        Vec::new()
+    }
+}
+
+/// Carries Rust-side state of user objects across a hot-reload of the extension library.
+///
+/// When Godot reloads the library (requires `reloadable = true` in the `.gdextension` file), the Rust instances of all objects are
+/// destroyed and recreated with the new code, while the Godot objects stay alive. Godot itself only restores properties with storage
+/// usage; any other state is lost unless the class implements this trait and opts in with `#[class(hot_reload)]`.
+///
+/// `#[class(hot_reload)]` implements this trait automatically, saving all fields annotated with `#[var]`, `#[export]` or
+/// `#[hot_reload]`. To implement it manually instead, use `#[class(hot_reload = manual)]`.
+///
+/// Hot-reloading is available since Godot 4.2.
+pub trait HotReload: GodotClass {
+    /// Called when the library is unloaded in the editor, which may be followed by a reload. Returns the state to carry over.
+    fn save_state(&self) -> Dictionary;
+
+    /// Called on the newly created instance after the library has been reloaded, with the state returned by
+    /// [`save_state()`][Self::save_state]. Entries may be missing or have different types if the class changed in the meantime.
+    fn restore_state(&mut self, state: Dictionary);
 }
 
 /// Auto-implemented for all engine-provided classes.
@@ -322,7 +357,8 @@ pub mod dom {
 
     /// Trait that specifies who declares a given `GodotClass`.
     pub trait Domain: Sealed {
-        type DerefTarget<T: GodotClass>;
+        /// The engine class that `Gd<T>` dereferences to.
+        type DerefTarget<T: GodotClass>: GodotClass;
 
         #[doc(hidden)]
         fn scoped_mut<T, F, R>(obj: &mut RawGd<T>, closure: F) -> R
@@ -369,7 +405,9 @@ pub mod dom {
     pub enum UserDomain {}
     impl Sealed for UserDomain {}
     impl Domain for UserDomain {
-        type DerefTarget<T: GodotClass> = T::Base;
+        // Rust base classes are skipped, until the first engine class.
+        type DerefTarget<T: GodotClass> =
+            <<T::Base as GodotClass>::Declarer as Domain>::DerefTarget<T::Base>;
 
         fn scoped_mut<T, F, R>(obj: &mut RawGd<T>, closure: F) -> R
         where
@@ -0,0 +1,125 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+
+use crate::engine::{Object, RefCounted};
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Weak pointer to a Godot object, which does not keep the object alive.
+///
+/// Obtained from [`Gd::downgrade()`] or [`WeakGd::from_instance_id()`]. To access the object, it must be upgraded to a `Gd<T>`
+/// with [`upgrade()`][Self::upgrade], which fails once the object is destroyed.
+///
+/// This works the same way for ref-counted and manually managed classes:
+/// - A `WeakGd<T>` of a `RefCounted` object does not contribute to its reference count. It can thus be used to break reference cycles,
+///   e.g. between resources that refer to each other.
+/// - A `WeakGd<T>` of a manually managed object does not prevent it from being freed. This makes it suitable for back-pointers, e.g.
+///   from a child node to its parent.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// let node = Node::new_alloc();
+/// let weak = node.downgrade();
+/// assert!(weak.upgrade().is_some());
+///
+/// node.free();
+/// assert!(weak.upgrade().is_none());
+/// ```
+pub struct WeakGd<T: GodotClass> {
+    instance_id: InstanceId,
+
+    // Like Gd<T>, neither Send nor Sync.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    /// Creates a weak pointer from an instance ID, without checking if it refers to a live object of class `T`.
+    ///
+    /// The check is deferred to [`upgrade()`][Self::upgrade].
+    pub fn from_instance_id(instance_id: InstanceId) -> Self {
+        Self {
+            instance_id,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a strong pointer to the object, or `None` if it is no longer alive.
+    ///
+    /// Also returns `None` if the object's dynamic class is not compatible with `T`, which can only happen for weak pointers
+    /// created through [`from_instance_id()`][Self::from_instance_id].
+    ///
+    /// For ref-counted objects, the returned `Gd<T>` increments the reference count, keeping the object alive as long as it exists.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        let ptr = crate::engine::object_ptr_from_id(self.instance_id);
+        if ptr.is_null() {
+            return None;
+        }
+
+        // A RefCounted object whose count dropped to zero is still registered during its destruction, but can no longer be referenced.
+        if self.instance_id.is_ref_counted() {
+            // SAFETY: the object is alive (found in the object DB); the weak Gd is not dropped, so the count is not touched.
+            let refc = ManuallyDrop::new(unsafe { Gd::<RefCounted>::from_obj_sys_weak(ptr) });
+            if refc.get_reference_count() <= 0 {
+                return None;
+            }
+        }
+
+        // SAFETY: assumes that the returned GDExtensionObjectPtr is convertible to Object* (i.e. C++ upcast doesn't modify the pointer).
+        let untyped = unsafe { Gd::<Object>::from_obj_sys_or_none(ptr) }.ok()?;
+        untyped.owned_cast::<T>().ok()
+    }
+
+    /// Returns `true` if the object is still alive.
+    ///
+    /// Like [`Gd::is_instance_valid()`], this is rarely needed: prefer calling [`upgrade()`][Self::upgrade] and handling `None`.
+    pub fn is_alive(&self) -> bool {
+        self.upgrade().is_some()
+    }
+
+    /// Returns the instance ID of the referred-to object, which may no longer be valid.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+}
+
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for WeakGd<T> {}
+
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    /// Returns whether two weak pointers refer to the same object. Never panics, even if the object is dead.
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> Hash for WeakGd<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instance_id.hash(state);
+    }
+}
+
+impl<T: GodotClass> Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "WeakGd {{ id: {}, class: {} }}",
+            self.instance_id,
+            T::class_name()
+        )
+    }
+}
@@ -0,0 +1,402 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reporting of panics that occur in Rust code called by Godot.
+//!
+//! Panics in `#[func]` methods, virtual methods and other callbacks are caught at the FFI boundary. Each one produces a
+//! [`PanicReport`], which is pushed to the editor's debugger as script error, and can optionally be written to a crash report
+//! file or passed to a custom hook. See [`set_config()`].
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::builtin::Variant;
+use crate::obj::{GodotClass, InstanceId};
+use crate::sys;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public API
+
+/// Configures how panics are reported, see [`set_config()`].
+#[derive(Default)]
+pub struct PanicReportConfig {
+    /// Capture a backtrace for every panic. If `false`, backtraces are only captured if enabled by `RUST_BACKTRACE`.
+    pub force_backtrace: bool,
+
+    /// Directory in which a file is written for every panic. Godot paths like `user://crashes` are supported.
+    pub crash_report_dir: Option<String>,
+
+    /// Invoked for every panic, after it has been reported to Godot.
+    ///
+    /// The configuration is not locked during the call, so the hook may itself call [`set_config()`].
+    pub on_panic: Option<Arc<dyn Fn(&PanicReport) + Send + Sync>>,
+}
+
+/// Replaces the configuration for panic reports.
+///
+/// Typically called in [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init].
+pub fn set_config(config: PanicReportConfig) {
+    *CONFIG.write().unwrap_or_else(|e| e.into_inner()) = Some(config);
+}
+
+/// Information about a panic in Rust code called by Godot.
+#[derive(Clone, Debug)]
+pub struct PanicReport {
+    /// The panic message, or a placeholder if the payload is not a string.
+    pub message: String,
+
+    /// Source file where the panic occurred.
+    pub file: String,
+
+    /// Line where the panic occurred.
+    pub line: u32,
+
+    /// Backtrace of the panicking thread, if captured.
+    pub backtrace: Option<String>,
+
+    /// The method call during which the panic occurred, if it stems from a method call.
+    pub call: Option<CallInfo>,
+
+    /// Description of what was being done, for panics outside of method calls (e.g. `"init level Scene"`).
+    pub context: Option<String>,
+}
+
+impl PanicReport {
+    /// Name of the function that panicked, e.g. `"Player::jump"`.
+    pub fn function_name(&self) -> String {
+        match (&self.call, &self.context) {
+            (Some(call), _) => format!("{}::{}", call.class_name, call.method_name),
+            (None, Some(context)) => context.clone(),
+            (None, None) => String::from("<unknown>"),
+        }
+    }
+}
+
+impl fmt::Display for PanicReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Rust panic in {}: {}",
+            self.function_name(),
+            self.message
+        )?;
+        writeln!(f, "  at {}:{}", self.file, self.line)?;
+
+        if let Some(context) = &self.context {
+            writeln!(f, "  context: {context}")?;
+        }
+
+        if let Some(call) = &self.call {
+            if let Some(instance_id) = call.instance_id {
+                writeln!(f, "  instance: {instance_id}")?;
+            }
+            if let Some(args) = &call.args {
+                writeln!(f, "  args: [{}]", args.join(", "))?;
+            }
+        }
+
+        if let Some(backtrace) = &self.backtrace {
+            writeln!(f, "  backtrace:")?;
+            for line in backtrace.lines() {
+                writeln!(f, "    {line}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A call from Godot into a method of a Rust class.
+#[derive(Clone, Debug)]
+pub struct CallInfo {
+    pub class_name: String,
+    pub method_name: String,
+
+    /// The receiving object, for non-static methods.
+    pub instance_id: Option<InstanceId>,
+
+    /// Arguments, formatted as in GDScript. `None` if the call passed them in native form (ptrcall), which cannot be displayed.
+    pub args: Option<Vec<String>>,
+}
+
+impl CallInfo {
+    /// Describes a varcall, i.e. a call with `Variant` arguments.
+    ///
+    /// # Safety
+    /// `instance_ptr` must be null or point to the instance storage of a `T`. `args_ptr` must point to `arg_count` valid variants.
+    #[doc(hidden)]
+    pub unsafe fn from_varcall<T: GodotClass>(
+        method_name: &str,
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
+    ) -> Self {
+        let args = (0..arg_count as usize)
+            .map(|i| (*Variant::ptr_from_sys(*args_ptr.add(i))).to_string())
+            .collect();
+
+        Self {
+            args: Some(args),
+            ..Self::from_ptrcall::<T>(method_name, instance_ptr)
+        }
+    }
+
+    /// Describes a ptrcall, whose arguments cannot be displayed.
+    ///
+    /// # Safety
+    /// `instance_ptr` must be null or point to the instance storage of a `T`.
+    #[doc(hidden)]
+    pub unsafe fn from_ptrcall<T: GodotClass>(
+        method_name: &str,
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+    ) -> Self {
+        let instance_id = if instance_ptr.is_null() {
+            None
+        } else {
+            Some(crate::storage::as_storage::<T>(instance_ptr).instance_id())
+        };
+
+        Self {
+            class_name: T::class_name().to_string(),
+            method_name: method_name.to_string(),
+            instance_id,
+            args: None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Catching and reporting
+
+static CONFIG: RwLock<Option<PanicReportConfig>> = RwLock::new(None);
+
+/// What was running when a panic occurred.
+pub(crate) enum PanicContext {
+    Call(CallInfo),
+    Other(String),
+}
+
+struct PanicLocation {
+    file: String,
+    line: u32,
+    backtrace: Option<String>,
+}
+
+/// Executes `code`. If it panics, the panic is reported and `None` is returned.
+pub(crate) fn catch_panic<C, F, R>(context: C, code: F) -> Option<R>
+where
+    C: FnOnce() -> PanicContext,
+    F: FnOnce() -> R + std::panic::UnwindSafe,
+{
+    let location: Arc<Mutex<Option<PanicLocation>>> = Arc::new(Mutex::new(None));
+
+    // Back up previous hook, set new one
+    let prev_hook = std::panic::take_hook();
+    {
+        let location = location.clone();
+        std::panic::set_hook(Box::new(move |panic_info| {
+            let Some(panic_location) = panic_info.location() else {
+                println!("panic occurred but can't get location information...");
+                return;
+            };
+
+            *location.lock().unwrap() = Some(PanicLocation {
+                file: panic_location.file().to_string(),
+                line: panic_location.line(),
+                backtrace: capture_backtrace(),
+            });
+        }));
+    }
+
+    // Run code that should panic, restore hook
+    let panic = std::panic::catch_unwind(code);
+    std::panic::set_hook(prev_hook);
+
+    let payload = match panic {
+        Ok(result) => return Some(result),
+        Err(payload) => payload,
+    };
+
+    // Flush, to make sure previous Rust output (e.g. test announcement, or debug prints during app) have been printed
+    crate::private::flush_stdout();
+
+    let location = location.lock().unwrap().take();
+    let (file, line, backtrace) = match location {
+        Some(PanicLocation {
+            file,
+            line,
+            backtrace,
+        }) => (file, line, backtrace),
+        None => (String::from("<unknown>"), 0, None),
+    };
+
+    let (call, context) = match context() {
+        PanicContext::Call(call) => (Some(call), None),
+        PanicContext::Other(context) => (None, Some(context)),
+    };
+
+    let report = PanicReport {
+        message: panic_message(&*payload),
+        file,
+        line,
+        backtrace,
+        call,
+        context,
+    };
+    report_panic(&report);
+
+    None
+}
+
+fn capture_backtrace() -> Option<String> {
+    let force = CONFIG
+        .read()
+        .ok()
+        .and_then(|config| config.as_ref().map(|config| config.force_backtrace))
+        .unwrap_or(false);
+
+    let backtrace = if force {
+        Backtrace::force_capture()
+    } else {
+        Backtrace::capture()
+    };
+
+    (backtrace.status() == BacktraceStatus::Captured).then(|| backtrace.to_string())
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&'static str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        format!("Rust panic of type ID {:?}", payload.type_id())
+    }
+}
+
+fn report_panic(report: &PanicReport) {
+    push_to_debugger(report);
+
+    // Copy the settings out, so that the lock is not held while running the user's hook (which may reconfigure).
+    let (crash_report_dir, on_panic) = {
+        let config = CONFIG.read().unwrap_or_else(|e| e.into_inner());
+        match config.as_ref() {
+            Some(config) => (config.crash_report_dir.clone(), config.on_panic.clone()),
+            None => return,
+        }
+    };
+
+    if let Some(dir) = &crash_report_dir {
+        if let Err(err) = write_crash_report(dir, report) {
+            crate::log::godot_error!("failed to write crash report to `{dir}`: {err}");
+        }
+    }
+
+    if let Some(on_panic) = on_panic {
+        on_panic(report);
+    }
+}
+
+/// Shows the report in the editor's debugger (Errors tab), with the panic location as source.
+fn push_to_debugger(report: &PanicReport) {
+    let details = report.to_string();
+
+    if !unsafe { sys::is_initialized() } {
+        eprintln!("{details}");
+        return;
+    }
+
+    let message = format!("{}\0", details.trim_end());
+    let function = format!("{}\0", report.function_name());
+    let file = format!("{}\0", report.file);
+
+    // SAFETY: all strings are NUL-terminated and outlive the call; Godot copies them.
+    unsafe {
+        sys::interface_fn!(print_script_error)(
+            sys::c_str_from_str(&message),
+            sys::c_str_from_str(&function),
+            sys::c_str_from_str(&file),
+            report.line as i32,
+            false as sys::GDExtensionBool, // whether to create a toast notification in editor
+        );
+    }
+}
+
+fn write_crash_report(dir: &str, report: &PanicReport) -> std::io::Result<std::path::PathBuf> {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let dir = if unsafe { sys::is_initialized() } && dir.contains("://") {
+        crate::engine::ProjectSettings::singleton()
+            .globalize_path(dir.into())
+            .to_string()
+    } else {
+        dir.to_string()
+    };
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let file_name = format!(
+        "rust-panic-{timestamp}-{}-{}.txt",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
+
+    let path = std::path::Path::new(&dir).join(file_name);
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(&path, format!("{report}"))?;
+
+    Ok(path)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> PanicReport {
+        PanicReport {
+            message: String::from("index out of bounds"),
+            file: String::from("src/player.rs"),
+            line: 42,
+            backtrace: Some(String::from("0: player::jump\n1: main")),
+            call: Some(CallInfo {
+                class_name: String::from("Player"),
+                method_name: String::from("jump"),
+                instance_id: None,
+                args: Some(vec![String::from("3"), String::from("up")]),
+            }),
+            context: None,
+        }
+    }
+
+    #[test]
+    fn report_display() {
+        let expected = "\
+Rust panic in Player::jump: index out of bounds
+  at src/player.rs:42
+  args: [3, up]
+  backtrace:
+    0: player::jump
+    1: main
+";
+
+        assert_eq!(report().to_string(), expected);
+    }
+
+    #[test]
+    fn report_function_name() {
+        let mut report = report();
+        assert_eq!(report.function_name(), "Player::jump");
+
+        report.call = None;
+        report.context = Some(String::from("init level Scene"));
+        assert_eq!(report.function_name(), "init level Scene");
+    }
+}
@@ -6,20 +6,23 @@
 
 #![allow(dead_code)] // FIXME
 
+use crate::builder::ClassBuilderData;
+use crate::docs::{InherentImplDocs, StructDocs};
 use crate::init::InitLevel;
 use crate::log;
 use crate::obj::*;
 use crate::private::as_storage;
-use crate::storage::InstanceStorage;
+use crate::storage::{BorrowContext, InstanceStorage, ParentStorage};
 use godot_ffi as sys;
 
 use sys::interface_fn;
 
 use crate::builtin::meta::ClassName;
-use crate::builtin::StringName;
+use crate::builtin::{Dictionary, StringName};
+use crate::engine::Object;
 use crate::out;
-use std::any::Any;
-use std::collections::HashMap;
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Mutex, MutexGuard, TryLockError};
 use std::{fmt, ptr};
 
@@ -30,6 +33,24 @@ use std::{fmt, ptr};
 // happen, most likely something changed on Godot side and analysis required to adopt these changes.
 static LOADED_CLASSES: Mutex<Option<HashMap<InitLevel, Vec<ClassName>>>> = Mutex::new(None);
 
+/// Classes that were added to the editor as plugins, and must be removed again before they are unregistered.
+#[cfg(since_api = "4.1")]
+static EDITOR_PLUGINS: Mutex<Vec<ClassName>> = Mutex::new(Vec::new());
+
+/// Icon paths of registered classes, which are shown in the editor once it has started.
+#[cfg(since_api = "4.2")]
+static CLASS_ICONS: Mutex<Vec<(ClassName, &'static str)>> = Mutex::new(Vec::new());
+
+/// Callbacks of registered Rust classes, which Rust classes inheriting from them fall back to.
+static RUST_CLASSES: Mutex<Option<HashMap<ClassName, InheritedCallbacks>>> = Mutex::new(None);
+
+/// Implementations of `#[godot_dyn]` traits, by the `TypeId`s of the class and of `dyn Trait`.
+static DYN_TRAIT_IMPLS: Mutex<Option<HashMap<(TypeId, TypeId), (ClassName, ErasedDynFn)>>> =
+    Mutex::new(None);
+
+/// Converts a `Gd<Object>` into a type-erased `DynGd`, see `DynGd::try_from_gd()`.
+type ErasedDynFn = fn(Gd<Object>) -> Box<dyn Any>;
+
 // TODO(bromeon): some information coming from the proc-macro API is deferred through PluginComponent, while others is directly
 // translated to code. Consider moving more code to the PluginComponent, which allows for more dynamic registration and will
 // be easier for a future builder API.
@@ -82,6 +103,33 @@ pub enum PluginComponent {
             _class_user_data: *mut std::ffi::c_void,
             instance: sys::GDExtensionClassInstancePtr,
         ),
+
+        /// Creates the Rust part of this class inside a derived Rust class, wired up to library-generated `init`
+        generated_create_storage_fn: Option<unsafe fn(sys::GDExtensionObjectPtr) -> ParentStorage>,
+
+        /// Initializes the `OnReady` fields of the class, before `ready()` is invoked.
+        before_ready_fn: unsafe fn(sys::GDExtensionClassInstancePtr),
+
+        /// Provides a `ready()` that initializes the `OnReady` fields, for classes that have such fields. Only used if the class does
+        /// not implement its interface trait in a `#[godot_api]` block, which takes care of this otherwise.
+        onready_get_virtual_fn: Option<
+            unsafe extern "C" fn(
+                p_userdata: *mut std::os::raw::c_void,
+                p_name: sys::GDExtensionConstStringNamePtr,
+            ) -> sys::GDExtensionClassCallVirtual,
+        >,
+
+        /// Whether Godot refuses to instantiate the class, from `#[class(is_abstract)]`.
+        is_abstract: bool,
+
+        /// Whether the class is listed in the editor, e.g. in the "Create New Node" dialog. `false` for `#[class(hidden)]`.
+        is_exposed: bool,
+
+        /// Resource path of the class icon in the editor, from `#[class(icon = "...")]`.
+        icon: Option<&'static str>,
+
+        /// Doc comments of the struct and its properties, if any.
+        docs: Option<StructDocs>,
     },
 
     /// Collected from `#[godot_api] impl MyClass`
@@ -90,6 +138,9 @@ pub enum PluginComponent {
         ///
         /// Always present since that's the entire point of this `impl` block.
         generated_register_fn: ErasedRegisterFn,
+
+        /// Doc comments of the `#[func]`, `#[signal]` and `#[constant]` items, if any.
+        docs: Option<InherentImplDocs>,
     },
 
     /// Collected from `#[godot_api] impl GodotExt for MyClass`
@@ -111,6 +162,9 @@ pub enum PluginComponent {
             ) -> sys::GDExtensionClassInstancePtr,
         >,
 
+        /// Creates the Rust part of this class inside a derived Rust class, wired up to the user's `init`
+        user_create_storage_fn: Option<unsafe fn(sys::GDExtensionObjectPtr) -> ParentStorage>,
+
         /// User-defined `to_string` function
         user_to_string_fn: Option<
             unsafe extern "C" fn(
@@ -144,8 +198,30 @@ pub enum PluginComponent {
         ) -> sys::GDExtensionClassCallVirtual,
     },
 
+    /// Collected from `#[godot_dyn] impl Trait for MyClass`
+    DynTraitImpl {
+        /// `TypeId` of the class.
+        class_type_id: TypeId,
+
+        /// `TypeId` of `dyn Trait`.
+        dyn_trait_type_id: TypeId,
+
+        /// Converts an object of the class into a type-erased `DynGd` for `dyn Trait`.
+        erased_dyn_fn: fn(Gd<Object>) -> Box<dyn Any>,
+    },
+
     #[cfg(since_api = "4.1")]
     EditorPlugin,
+
+    /// Collected from `#[class(hot_reload)]`
+    #[cfg(since_api = "4.2")]
+    HotReload {
+        /// Calls `HotReload::save_state()` on the given instance.
+        save_state_fn: unsafe fn(sys::GDExtensionClassInstancePtr) -> Dictionary,
+
+        /// Calls `HotReload::restore_state()` on the given instance.
+        restore_state_fn: unsafe fn(sys::GDExtensionClassInstancePtr, Dictionary),
+    },
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -160,8 +236,21 @@ struct ClassRegistrationInfo {
     godot_params: sys::GDExtensionClassCreationInfo,
     #[cfg(since_api = "4.2")]
     godot_params: sys::GDExtensionClassCreationInfo2,
+    create_storage_fn: Option<unsafe fn(sys::GDExtensionObjectPtr) -> ParentStorage>,
+    before_ready_fn: Option<unsafe fn(sys::GDExtensionClassInstancePtr)>,
+    onready_get_virtual_fn: Option<
+        unsafe extern "C" fn(
+            p_userdata: *mut std::os::raw::c_void,
+            p_name: sys::GDExtensionConstStringNamePtr,
+        ) -> sys::GDExtensionClassCallVirtual,
+    >,
     init_level: InitLevel,
     is_editor_plugin: bool,
+    #[cfg(since_api = "4.2")]
+    hot_reload_fns: Option<HotReloadFns>,
+    #[cfg(since_api = "4.2")]
+    icon: Option<&'static str>,
+    dyn_trait_impls: Vec<(TypeId, TypeId, ErasedDynFn)>,
 }
 
 /// Registers a class with static type information.
@@ -213,10 +302,18 @@ pub fn register_class<
             raw: callbacks::register_class_by_builder::<T>,
         }),
         godot_params,
+        create_storage_fn: Some(callbacks::create_storage::<T>),
+        before_ready_fn: None,
+        onready_get_virtual_fn: None,
         init_level: T::INIT_LEVEL.unwrap_or_else(|| {
             panic!("Unknown initialization level for class {}", T::class_name())
         }),
         is_editor_plugin: false,
+        #[cfg(since_api = "4.2")]
+        hot_reload_fns: None,
+        #[cfg(since_api = "4.2")]
+        icon: None,
+        dyn_trait_impls: Vec::new(),
     });
 }
 
@@ -252,7 +349,7 @@ pub fn auto_register_classes(init_level: InitLevel) {
     let mut loaded_classes_guard = get_loaded_classes_with_mutex();
     let loaded_classes_by_level = loaded_classes_guard.get_or_insert_with(HashMap::default);
 
-    for info in map.into_values() {
+    for info in sort_by_inheritance(map) {
         out!(
             "Register class:   {} at level `{init_level:?}`",
             info.class_name
@@ -267,6 +364,13 @@ pub fn auto_register_classes(init_level: InitLevel) {
         out!("Class {} loaded", class_name);
     }
 
+    // The Editor level is initialized last, and only when running in the editor.
+    #[cfg(since_api = "4.2")]
+    if init_level == InitLevel::Editor {
+        let icons = CLASS_ICONS.lock().unwrap().clone();
+        crate::editor::set_class_icons_deferred(icons);
+    }
+
     out!("All classes for level `{init_level:?}` auto-registered.");
 }
 
@@ -277,11 +381,47 @@ pub fn unregister_classes(init_level: InitLevel) {
         .remove(&init_level)
         .unwrap_or_default();
     out!("Unregistering classes of level {init_level:?}...");
+
+    // Instances are still alive at this point; save their state in case the library is being reloaded.
+    #[cfg(since_api = "4.2")]
+    if hot_reload::is_saving_state() {
+        hot_reload::save_instance_states(&loaded_classes_current_level);
+    }
+
     for class_name in loaded_classes_current_level.iter().rev() {
         unregister_class_raw(class_name);
     }
 }
 
+/// Orders the classes such that Rust base classes are registered before the classes inheriting from them.
+fn sort_by_inheritance(
+    mut map: HashMap<ClassName, ClassRegistrationInfo>,
+) -> Vec<ClassRegistrationInfo> {
+    fn push_with_bases(
+        class_name: ClassName,
+        map: &mut HashMap<ClassName, ClassRegistrationInfo>,
+        sorted: &mut Vec<ClassRegistrationInfo>,
+    ) {
+        // Base classes outside the map are engine classes or registered at an earlier level.
+        let Some(info) = map.remove(&class_name) else {
+            return;
+        };
+
+        if let Some(parent_class_name) = info.parent_class_name {
+            push_with_bases(parent_class_name, map, sorted);
+        }
+        sorted.push(info);
+    }
+
+    let class_names: Vec<ClassName> = map.keys().copied().collect();
+    let mut sorted = Vec::with_capacity(class_names.len());
+    for class_name in class_names {
+        push_with_bases(class_name, &mut map, &mut sorted);
+    }
+
+    sorted
+}
+
 fn get_loaded_classes_with_mutex() -> MutexGuard<'static, Option<HashMap<InitLevel, Vec<ClassName>>>>
 {
     match LOADED_CLASSES.try_lock() {
@@ -305,8 +445,25 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             generated_create_fn,
             generated_recreate_fn,
             free_fn,
+            generated_create_storage_fn,
+            before_ready_fn,
+            onready_get_virtual_fn,
+            is_abstract,
+            is_exposed,
+            icon,
+            docs: _, // Collected separately, see docs::gather_xml_docs().
         } => {
             c.parent_class_name = Some(base_class_name);
+            c.godot_params.is_abstract = is_abstract as sys::GDExtensionBool;
+
+            #[cfg(since_api = "4.2")]
+            {
+                c.godot_params.is_exposed = is_exposed as sys::GDExtensionBool;
+                c.icon = icon;
+            }
+
+            #[cfg(before_api = "4.2")]
+            assert!(is_exposed && icon.is_none()); // rejected by proc-macro
 
             fill_into(
                 &mut c.godot_params.create_instance_func,
@@ -335,10 +492,14 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             assert!(generated_recreate_fn.is_none()); // not used
 
             c.godot_params.free_instance_func = Some(free_fn);
+            fill_into(&mut c.create_storage_fn, generated_create_storage_fn).unwrap();
+            c.before_ready_fn = Some(before_ready_fn);
+            c.onready_get_virtual_fn = onready_get_virtual_fn;
         }
 
         PluginComponent::UserMethodBinds {
             generated_register_fn,
+            docs: _,
         } => {
             c.generated_register_fn = Some(generated_register_fn);
         }
@@ -347,6 +508,7 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             user_register_fn,
             user_create_fn,
             user_recreate_fn,
+            user_create_storage_fn,
             user_to_string_fn,
             user_on_notification_fn,
             get_virtual_fn,
@@ -364,14 +526,36 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             #[cfg(before_api = "4.2")]
             assert!(user_recreate_fn.is_none()); // not used
 
+            fill_into(&mut c.create_storage_fn, user_create_storage_fn).unwrap();
+
             c.godot_params.to_string_func = user_to_string_fn;
             c.godot_params.notification_func = user_on_notification_fn;
             c.godot_params.get_virtual_func = Some(get_virtual_fn);
         }
+        PluginComponent::DynTraitImpl {
+            class_type_id,
+            dyn_trait_type_id,
+            erased_dyn_fn,
+        } => {
+            c.dyn_trait_impls
+                .push((class_type_id, dyn_trait_type_id, erased_dyn_fn));
+        }
+
         #[cfg(since_api = "4.1")]
         PluginComponent::EditorPlugin => {
             c.is_editor_plugin = true;
         }
+
+        #[cfg(since_api = "4.2")]
+        PluginComponent::HotReload {
+            save_state_fn,
+            restore_state_fn,
+        } => {
+            c.hot_reload_fns = Some(HotReloadFns {
+                save_state_fn,
+                restore_state_fn,
+            });
+        }
     }
     // out!("|   reg (after):     {c:?}");
     // out!();
@@ -388,14 +572,30 @@ fn fill_into<T>(dst: &mut Option<T>, src: Option<T>) -> Result<(), ()> {
 }
 
 /// Registers a class with given the dynamic type information `info`.
-fn register_class_raw(info: ClassRegistrationInfo) {
-    // First register class...
+fn register_class_raw(#[allow(unused_mut)] mut info: ClassRegistrationInfo) {
+    // First collect the members declared through the class builder, as they may affect the class's creation info...
 
     let class_name = info.class_name;
     let parent_class_name = info
         .parent_class_name
         .expect("class defined (parent_class_name)");
 
+    let mut builder_data = ClassBuilderData::default();
+    if let Some(register_fn) = info.user_register_fn {
+        (register_fn.raw)(&mut builder_data);
+    }
+
+    // Without a #[godot_api] interface impl, OnReady fields still need a ready() to be initialized. Forwards to the Rust base class.
+    let params = &mut info.godot_params;
+    params.get_virtual_func = params.get_virtual_func.or(info.onready_get_virtual_fn);
+
+    inherit_callbacks(&mut info, parent_class_name);
+
+    #[cfg(since_api = "4.2")]
+    builder_data.prepare_virtuals(class_name, parent_class_name, &mut info.godot_params);
+
+    // ...then register class...
+
     unsafe {
         // Try to register class...
 
@@ -430,21 +630,53 @@ fn register_class_raw(info: ClassRegistrationInfo) {
 
     // ...then custom symbols
 
-    //let mut class_builder = crate::builder::ClassBuilder::<?>::new();
-    let mut class_builder = 0; // TODO dummy argument; see callbacks
-
-    // First call generated (proc-macro) registration function, then user-defined one.
+    // First register generated (proc-macro) symbols, then user-defined ones from the class builder.
     // This mimics the intuition that proc-macros are running "before" normal runtime code.
     if let Some(register_fn) = info.generated_register_fn {
-        (register_fn.raw)(&mut class_builder);
+        (register_fn.raw)(&mut ());
     }
-    if let Some(register_fn) = info.user_register_fn {
-        (register_fn.raw)(&mut class_builder);
+    builder_data.register(class_name);
+
+    RUST_CLASSES
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::default)
+        .insert(
+            class_name,
+            InheritedCallbacks {
+                create_storage_fn: info.create_storage_fn,
+                before_ready_fn: info.before_ready_fn,
+                to_string_func: info.godot_params.to_string_func,
+                notification_func: info.godot_params.notification_func,
+                get_virtual_func: info.godot_params.get_virtual_func,
+            },
+        );
+
+    if !info.dyn_trait_impls.is_empty() {
+        let mut dyn_trait_impls = DYN_TRAIT_IMPLS.lock().unwrap();
+        let dyn_trait_impls = dyn_trait_impls.get_or_insert_with(HashMap::default);
+        for (class_type_id, dyn_trait_type_id, erased_dyn_fn) in info.dyn_trait_impls {
+            dyn_trait_impls.insert(
+                (class_type_id, dyn_trait_type_id),
+                (class_name, erased_dyn_fn),
+            );
+        }
     }
 
     #[cfg(since_api = "4.1")]
     if info.is_editor_plugin {
         unsafe { interface_fn!(editor_add_plugin)(class_name.string_sys()) };
+        EDITOR_PLUGINS.lock().unwrap().push(class_name);
+    }
+
+    #[cfg(since_api = "4.2")]
+    if let Some(hot_reload_fns) = info.hot_reload_fns {
+        hot_reload::register_class(class_name, hot_reload_fns);
+    }
+
+    #[cfg(since_api = "4.2")]
+    if let Some(icon) = info.icon {
+        CLASS_ICONS.lock().unwrap().push((class_name, icon));
     }
     #[cfg(before_api = "4.1")]
     assert!(!info.is_editor_plugin);
@@ -452,6 +684,34 @@ fn register_class_raw(info: ClassRegistrationInfo) {
 
 fn unregister_class_raw(class_name: &ClassName) {
     out!("Unregister class: {class_name}");
+
+    #[cfg(since_api = "4.2")]
+    hot_reload::unregister_class(class_name);
+
+    if let Some(rust_classes) = RUST_CLASSES.lock().unwrap().as_mut() {
+        rust_classes.remove(class_name);
+    }
+
+    // The conversion functions point into the library, which may be unloaded after this.
+    if let Some(dyn_trait_impls) = DYN_TRAIT_IMPLS.lock().unwrap().as_mut() {
+        dyn_trait_impls.retain(|_, (impl_class_name, _)| impl_class_name != class_name);
+    }
+
+    #[cfg(since_api = "4.2")]
+    CLASS_ICONS
+        .lock()
+        .unwrap()
+        .retain(|(name, _)| name != class_name);
+
+    #[cfg(since_api = "4.1")]
+    {
+        let mut editor_plugins = EDITOR_PLUGINS.lock().unwrap();
+        if let Some(index) = editor_plugins.iter().position(|name| name == class_name) {
+            editor_plugins.swap_remove(index);
+            unsafe { interface_fn!(editor_remove_plugin)(class_name.string_sys()) };
+        }
+    }
+
     unsafe {
         #[allow(clippy::let_unit_value)]
         let _: () = interface_fn!(classdb_unregister_extension_class)(
@@ -459,9 +719,143 @@ fn unregister_class_raw(class_name: &ClassName) {
             class_name.string_sys(),
         );
     }
+    crate::builder::unregister_class(class_name);
     out!("Class {class_name} unloaded");
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Rust base classes
+
+/// Callbacks of a registered Rust class, after inheriting the ones it does not define from its own Rust base class.
+#[derive(Copy, Clone)]
+struct InheritedCallbacks {
+    create_storage_fn: Option<unsafe fn(sys::GDExtensionObjectPtr) -> ParentStorage>,
+
+    // Not inherited: each class initializes its own OnReady fields, see callbacks::before_ready().
+    before_ready_fn: Option<unsafe fn(sys::GDExtensionClassInstancePtr)>,
+
+    to_string_func: Option<
+        unsafe extern "C" fn(
+            p_instance: sys::GDExtensionClassInstancePtr,
+            r_is_valid: *mut sys::GDExtensionBool,
+            r_out: sys::GDExtensionStringPtr,
+        ),
+    >,
+
+    #[cfg(before_api = "4.2")]
+    notification_func: Option<
+        unsafe extern "C" fn(
+            p_instance: sys::GDExtensionClassInstancePtr, //
+            p_what: i32,
+        ),
+    >,
+    #[cfg(since_api = "4.2")]
+    notification_func: Option<
+        unsafe extern "C" fn(
+            p_instance: sys::GDExtensionClassInstancePtr, //
+            p_what: i32,
+            p_reversed: sys::GDExtensionBool,
+        ),
+    >,
+
+    get_virtual_func: Option<
+        unsafe extern "C" fn(
+            p_userdata: *mut std::os::raw::c_void,
+            p_name: sys::GDExtensionConstStringNamePtr,
+        ) -> sys::GDExtensionClassCallVirtual,
+    >,
+}
+
+/// Returns the callbacks of `class_name`, if it is a registered Rust class.
+fn rust_class(class_name: &ClassName) -> Option<InheritedCallbacks> {
+    RUST_CLASSES
+        .lock()
+        .unwrap()
+        .as_ref()?
+        .get(class_name)
+        .copied()
+}
+
+/// Whether `T` is declared in Rust, as opposed to an engine class.
+fn is_rust_class<T: GodotClass>() -> bool {
+    TypeId::of::<T::Declarer>() == TypeId::of::<dom::UserDomain>()
+}
+
+/// Returns the callbacks of `T::Base`, if it is a Rust class.
+fn parent_callbacks<T: GodotClass>() -> Option<InheritedCallbacks> {
+    // Avoids locking the registry for the common case of engine base classes.
+    if !is_rust_class::<T::Base>() {
+        return None;
+    }
+
+    rust_class(&T::Base::class_name())
+}
+
+/// Returns the init level of `class_name`, if it is declared in Rust with `#[derive(GodotClass)]`.
+fn declared_init_level(class_name: &ClassName) -> Option<InitLevel> {
+    let mut init_level = None;
+    crate::private::iterate_plugins(|elem: &ClassPlugin| {
+        if elem.class_name == *class_name
+            && matches!(elem.component, PluginComponent::ClassDef { .. })
+        {
+            init_level = elem.init_level;
+        }
+    });
+
+    init_level
+}
+
+/// Lets the class fall back to the callbacks of its Rust base class, for those it does not define itself.
+///
+/// Notifications and virtual functions defined by both classes are forwarded to the base class at runtime, see `callbacks`.
+fn inherit_callbacks(info: &mut ClassRegistrationInfo, parent_class_name: ClassName) {
+    let Some(parent) = rust_class(&parent_class_name) else {
+        // Not registered yet: either an engine class, or a Rust class of a later init level, which Godot cannot inherit from.
+        if let Some(parent_level) = declared_init_level(&parent_class_name) {
+            panic!(
+                "class `{}` inherits from Rust class `{parent_class_name}`, which is only registered at init level \
+                `{parent_level:?}`; a Rust base class must be registered at the same level as the derived class or an earlier one",
+                info.class_name,
+            );
+        }
+        return;
+    };
+
+    assert!(
+        info.godot_params.create_instance_func.is_none() || parent.create_storage_fn.is_some(),
+        "class `{}` can be instantiated, but its Rust base class `{parent_class_name}` has no constructor; \
+        provide #[class(init)] or an init() function for `{parent_class_name}`",
+        info.class_name,
+    );
+
+    let params = &mut info.godot_params;
+    params.to_string_func = params.to_string_func.or(parent.to_string_func);
+    params.notification_func = params.notification_func.or(parent.notification_func);
+    params.get_virtual_func = params.get_virtual_func.or(parent.get_virtual_func);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Rust trait objects
+
+/// Finds the implementation of `dyn_trait_type_id` by the most derived class among `class_type_ids`.
+///
+/// `class_type_ids` are the Rust classes stored in an object, starting with the most derived one.
+pub(crate) fn find_dyn_trait_impl(
+    class_type_ids: &[TypeId],
+    dyn_trait_type_id: TypeId,
+) -> Option<ErasedDynFn> {
+    let dyn_trait_impls = DYN_TRAIT_IMPLS.lock().unwrap();
+    let dyn_trait_impls = dyn_trait_impls.as_ref()?;
+
+    class_type_ids.iter().find_map(|&class_type_id| {
+        dyn_trait_impls
+            .get(&(class_type_id, dyn_trait_type_id))
+            .map(|&(_, erased_dyn_fn)| erased_dyn_fn)
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Callbacks that are passed as function pointers to Godot upon class registration.
 ///
 /// Re-exported to `crate::private`
@@ -482,7 +876,17 @@ pub mod callbacks {
         _class_userdata: *mut std::ffi::c_void,
         object: sys::GDExtensionObjectPtr,
     ) -> sys::GDExtensionClassInstancePtr {
-        create_rust_part_for_existing_godot_part(T::__godot_user_init, object)
+        let instance_ptr = create_rust_part_for_existing_godot_part(T::__godot_user_init, object);
+        hot_reload::restore_instance_state(T::class_name(), object, instance_ptr);
+
+        instance_ptr
+    }
+
+    /// Creates the Rust part of `T` inside an object of a derived Rust class.
+    pub unsafe fn create_storage<T: cap::GodotDefault>(
+        base_ptr: sys::GDExtensionObjectPtr,
+    ) -> ParentStorage {
+        Box::new(construct_storage(T::__godot_user_init, base_ptr))
     }
 
     pub(crate) fn create_custom<T, F>(make_user_instance: F) -> sys::GDExtensionObjectPtr
@@ -490,7 +894,8 @@ pub mod callbacks {
         T: GodotClass,
         F: FnOnce(Base<T::Base>) -> T,
     {
-        let base_class_name = T::Base::class_name();
+        // If T inherits from other Rust classes, the Godot object is an instance of the first engine class in the chain.
+        let base_class_name = GdDerefTarget::<T>::class_name();
 
         let base_ptr =
             unsafe { interface_fn!(classdb_construct_object)(base_class_name.string_sys()) };
@@ -518,10 +923,7 @@ pub mod callbacks {
 
         //out!("create callback: {}", class_name.backing);
 
-        let base = unsafe { Base::from_sys(base_ptr) };
-        let user_instance = make_user_instance(unsafe { Base::from_base(&base) });
-
-        let instance = InstanceStorage::<T>::construct(user_instance, base);
+        let instance = construct_storage(make_user_instance, base_ptr);
         let instance_ptr = instance.into_raw();
         let instance_ptr = instance_ptr as sys::GDExtensionClassInstancePtr;
 
@@ -536,10 +938,44 @@ pub mod callbacks {
             );
         }
 
+        #[cfg(since_api = "4.2")]
+        if T::__HOT_RELOAD {
+            hot_reload::track_instance(class_name, base_ptr, instance_ptr);
+        }
+
         // std::mem::forget(class_name);
         instance_ptr
     }
 
+    /// Creates the storage of `T`, including the storages of its Rust base classes.
+    fn construct_storage<T, F>(
+        make_user_instance: F,
+        base_ptr: sys::GDExtensionObjectPtr,
+    ) -> InstanceStorage<T>
+    where
+        T: GodotClass,
+        F: FnOnce(Base<T::Base>) -> T,
+    {
+        // Base classes are constructed first, like in C++.
+        let parent = is_rust_class::<T::Base>().then(|| {
+            let create_storage_fn = parent_callbacks::<T>()
+                .and_then(|p| p.create_storage_fn)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Rust base class `{}` is not registered or has no constructor",
+                        T::Base::class_name()
+                    )
+                });
+
+            unsafe { create_storage_fn(base_ptr) }
+        });
+
+        let base = unsafe { Base::from_sys(base_ptr) };
+        let user_instance = make_user_instance(unsafe { Base::from_base(&base) });
+
+        InstanceStorage::construct(user_instance, base, parent)
+    }
+
     pub unsafe extern "C" fn free<T: GodotClass>(
         _class_user_data: *mut std::ffi::c_void,
         instance: sys::GDExtensionClassInstancePtr,
@@ -549,6 +985,11 @@ pub mod callbacks {
             storage.mark_destroyed_by_godot();
         } // Ref no longer valid once next statement is executed.
 
+        #[cfg(since_api = "4.2")]
+        if T::__HOT_RELOAD {
+            hot_reload::untrack_instance(instance);
+        }
+
         crate::storage::destroy_storage::<T>(instance);
     }
 
@@ -561,7 +1002,121 @@ pub mod callbacks {
         let method_name = borrowed_string.to_string();
         std::mem::forget(borrowed_string);
 
-        T::__virtual_call(method_name.as_str())
+        let virtual_call = T::__virtual_call(method_name.as_str());
+        if virtual_call.is_some() {
+            return virtual_call;
+        }
+
+        // Not overridden by T; maybe by one of its Rust base classes.
+        match parent_callbacks::<T>().and_then(|p| p.get_virtual_func) {
+            Some(parent_get_virtual) => parent_get_virtual(ptr::null_mut(), name),
+            None => None,
+        }
+    }
+
+    /// `get_virtual` of classes with `OnReady` fields that do not implement their interface trait. Only provides [`ready()`].
+    ///
+    /// Invoked by a function generated by `#[derive(GodotClass)]`; `is_active` is `false` for non-tool classes in the editor.
+    pub unsafe fn get_onready_virtual<T: UserClass>(
+        name: sys::GDExtensionConstStringNamePtr,
+        is_active: bool,
+    ) -> sys::GDExtensionClassCallVirtual {
+        // This string is not ours, so we cannot call the destructor on it.
+        let borrowed_string = StringName::from_string_sys(sys::force_mut_ptr(name));
+        let is_ready = borrowed_string == StringName::from("_ready");
+        std::mem::forget(borrowed_string);
+
+        if is_active && is_ready {
+            return Some(ready::<T>);
+        }
+
+        match parent_callbacks::<T>().and_then(|p| p.get_virtual_func) {
+            Some(parent_get_virtual) => parent_get_virtual(ptr::null_mut(), name),
+            None => None,
+        }
+    }
+
+    /// Initializes the `OnReady` fields of `T` and its Rust base classes, base classes first.
+    ///
+    /// Invoked before the user-defined `ready()`. Fields that are already initialized are left untouched, so calling this again when a
+    /// base class' `ready()` runs has no effect.
+    pub unsafe fn before_ready<T: UserClass>(instance: sys::GDExtensionClassInstancePtr) {
+        if let Some(parent_before_ready) = parent_callbacks::<T>().and_then(|p| p.before_ready_fn) {
+            parent_before_ready(instance);
+        }
+
+        if !T::__HAS_ONREADY {
+            return;
+        }
+
+        // The instance is only borrowed to take out the initializers and to store the values, not while user code runs.
+        let storage = as_storage::<T>(instance);
+        let initializers =
+            T::__onready_initializers(&mut *storage.get_mut_for(BorrowContext::Static("ready")));
+        if initializers.is_empty() {
+            return;
+        }
+
+        // SAFETY: only nodes have a ready() method.
+        let base = storage.base_node();
+
+        // Values are stored when the guard is dropped, also if an initializer panics.
+        let mut guard = OnReadyGuard {
+            storage,
+            pending: initializers.into(),
+            store_fns: Vec::new(),
+        };
+        while let Some(initializer) = guard.pending.front_mut() {
+            let store = initializer.compute(&base);
+            guard.pending.pop_front();
+            guard.store_fns.push(store);
+        }
+    }
+
+    /// Stores the values of completed `OnReady` initializers, and puts back those that did not complete.
+    struct OnReadyGuard<'a, T: UserClass> {
+        storage: &'a InstanceStorage<T>,
+        pending: VecDeque<OnReadyInitializer<T>>,
+        store_fns: Vec<Box<dyn FnOnce(&mut T)>>,
+    }
+
+    impl<T: UserClass> Drop for OnReadyGuard<'_, T> {
+        fn drop(&mut self) {
+            let mut instance = self.storage.get_mut_for(BorrowContext::Static("ready"));
+            for store in self.store_fns.drain(..) {
+                store(&mut *instance);
+            }
+            for initializer in self.pending.drain(..) {
+                initializer.restore(&mut *instance);
+            }
+        }
+    }
+
+    /// `ready()` of classes that do not override it, which still needs to initialize their `OnReady` fields.
+    pub unsafe extern "C" fn ready<T: UserClass>(
+        instance: sys::GDExtensionClassInstancePtr,
+        args_ptr: *const sys::GDExtensionConstTypePtr,
+        ret: sys::GDExtensionTypePtr,
+    ) {
+        let initialized = crate::private::handle_call_panic(
+            || crate::panic_report::CallInfo::from_ptrcall::<T>("ready", instance),
+            || before_ready::<T>(instance),
+        );
+        if initialized.is_none() {
+            return;
+        }
+
+        // ready() of the nearest Rust base class overriding it, if any.
+        let ready_name = StringName::from("_ready");
+        let parent_ready = parent_callbacks::<T>()
+            .and_then(|p| p.get_virtual_func)
+            .and_then(|get_virtual| {
+                get_virtual(ptr::null_mut(), sys::to_const_ptr(ready_name.string_sys()))
+            });
+
+        if let Some(parent_ready) = parent_ready {
+            parent_ready(instance, args_ptr, ret);
+        }
     }
 
     pub unsafe extern "C" fn to_string<T: cap::GodotToString>(
@@ -573,7 +1128,7 @@ pub mod callbacks {
         // is_valid output parameter thus not needed.
 
         let storage = as_storage::<T>(instance);
-        let instance = storage.get();
+        let instance = storage.get_for(BorrowContext::Static("to_string"));
         let string = T::__godot_to_string(&*instance);
 
         // Transfer ownership to Godot
@@ -585,8 +1140,14 @@ pub mod callbacks {
         instance: sys::GDExtensionClassInstancePtr,
         what: i32,
     ) {
+        // Like in Godot, base classes are notified first.
+        let parent_notification = parent_callbacks::<T>().and_then(|p| p.notification_func);
+        if let Some(parent_notification) = parent_notification {
+            parent_notification(instance, what);
+        }
+
         let storage = as_storage::<T>(instance);
-        let mut instance = storage.get_mut();
+        let mut instance = storage.get_mut_for(BorrowContext::Static("on_notification"));
 
         T::__godot_notification(&mut *instance, what);
     }
@@ -595,12 +1156,28 @@ pub mod callbacks {
     pub unsafe extern "C" fn on_notification<T: cap::GodotNotification>(
         instance: sys::GDExtensionClassInstancePtr,
         what: i32,
-        _reversed: sys::GDExtensionBool,
+        reversed: sys::GDExtensionBool,
     ) {
-        let storage = as_storage::<T>(instance);
-        let mut instance = storage.get_mut();
+        // Like in Godot, base classes are notified first, unless the order is reversed (e.g. for NOTIFICATION_PREDELETE).
+        let parent_notification = parent_callbacks::<T>().and_then(|p| p.notification_func);
+        if reversed == 0 {
+            if let Some(parent_notification) = parent_notification {
+                parent_notification(instance, what, reversed);
+            }
+        }
 
-        T::__godot_notification(&mut *instance, what);
+        {
+            let storage = as_storage::<T>(instance);
+            let mut instance = storage.get_mut_for(BorrowContext::Static("on_notification"));
+
+            T::__godot_notification(&mut *instance, what);
+        }
+
+        if reversed != 0 {
+            if let Some(parent_notification) = parent_notification {
+                parent_notification(instance, what, reversed);
+            }
+        }
     }
 
     pub unsafe extern "C" fn reference<T: GodotClass>(instance: sys::GDExtensionClassInstancePtr) {
@@ -629,14 +1206,22 @@ pub mod callbacks {
         Box::new(instance)
     }
 
-    pub fn register_class_by_builder<T: cap::GodotRegisterClass>(_class_builder: &mut dyn Any) {
-        // TODO use actual argument, once class builder carries state
-        // let class_builder = class_builder
-        //     .downcast_mut::<ClassBuilder<T>>()
-        //     .expect("bad type erasure");
+    pub fn erased_dyn_gd<T, D>(object: Gd<Object>) -> Box<dyn Any>
+    where
+        T: AsDyn<D> + Inherits<Object> + GodotClass<Declarer = dom::UserDomain>,
+        D: ?Sized + 'static,
+    {
+        crate::obj::erase_dyn_gd::<T, D>(object)
+    }
+
+    pub fn register_class_by_builder<T: cap::GodotRegisterClass>(builder_data: &mut dyn Any) {
+        let builder_data = builder_data
+            .downcast_mut::<ClassBuilderData>()
+            .expect("bad type erasure");
 
-        let mut class_builder = ClassBuilder::new();
+        let mut class_builder = ClassBuilder::<T>::new();
         T::__godot_register_class(&mut class_builder);
+        builder_data.append(class_builder.into_data());
     }
 
     pub fn register_user_binds<T: cap::ImplementsGodotApi + cap::ImplementsGodotExports>(
@@ -651,6 +1236,223 @@ pub mod callbacks {
         T::__register_constants();
         T::__register_exports();
     }
+
+    #[cfg(since_api = "4.2")]
+    pub unsafe fn save_hot_reload_state<T: HotReload>(
+        instance: sys::GDExtensionClassInstancePtr,
+    ) -> Dictionary {
+        let storage = as_storage::<T>(instance);
+        let instance = storage.get_for(BorrowContext::Static("save_state"));
+
+        instance.save_state()
+    }
+
+    #[cfg(since_api = "4.2")]
+    pub unsafe fn restore_hot_reload_state<T: HotReload>(
+        instance: sys::GDExtensionClassInstancePtr,
+        state: Dictionary,
+    ) {
+        let storage = as_storage::<T>(instance);
+        let mut instance = storage.get_mut_for(BorrowContext::Static("restore_state"));
+
+        instance.restore_state(state);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Hot reload
+
+#[cfg(since_api = "4.2")]
+#[derive(Copy, Clone, Debug)]
+struct HotReloadFns {
+    save_state_fn: unsafe fn(sys::GDExtensionClassInstancePtr) -> Dictionary,
+    restore_state_fn: unsafe fn(sys::GDExtensionClassInstancePtr, Dictionary),
+}
+
+/// Carries the state of `HotReload` instances across a reload of the library.
+///
+/// Before the classes are unregistered, the state of each live instance is stored in a dictionary on the `Engine` singleton, keyed by
+/// instance ID. Unlike the library's own memory, this survives the reload; unlike metadata on the objects themselves, it is never saved
+/// to scenes or resources. When Godot recreates a Rust instance, its entry is removed and passed to `HotReload::restore_state()`.
+/// Entries of objects that were not recreated are discarded once `GDExtensionManager` emits `extensions_reloaded`.
+#[cfg(since_api = "4.2")]
+mod hot_reload {
+    use super::*;
+    use crate::builtin::meta::{FromGodot, ToGodot};
+    use crate::builtin::{Callable, Signal, Variant};
+    use crate::engine::object::ConnectFlags;
+    use crate::engine::{Engine, GDExtensionManager};
+
+    const STATES_META_KEY: &str = "_gdext_hot_reload_states";
+
+    #[derive(Default)]
+    struct Registry {
+        classes: HashMap<ClassName, HotReloadFns>,
+
+        /// Live instances of hot-reloadable classes, by instance pointer.
+        instances: HashMap<InstancePtr, (ClassName, InstanceId)>,
+
+        /// Whether registration already checked for states saved by the previous library.
+        is_reload_checked: bool,
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+    struct InstancePtr(sys::GDExtensionClassInstancePtr);
+
+    // SAFETY: the pointer is only used as a key and dereferenced on the main thread, which creates and destroys instances.
+    unsafe impl Send for InstancePtr {}
+
+    // Like LOADED_CLASSES, only accessed from the main thread. Never locked while user code runs, as that may create or free instances.
+    static REGISTRY: Mutex<Option<Registry>> = Mutex::new(None);
+
+    fn with_registry<R>(f: impl FnOnce(&mut Registry) -> R) -> R {
+        let mut guard = REGISTRY.lock().unwrap();
+        f(guard.get_or_insert_with(Registry::default))
+    }
+
+    pub fn register_class(class_name: ClassName, fns: HotReloadFns) {
+        let is_first = with_registry(|registry| {
+            registry.classes.insert(class_name, fns);
+            !std::mem::replace(&mut registry.is_reload_checked, true)
+        });
+
+        // States only exist if this library replaces a previous one. Godot recreates the instances after all levels are initialized.
+        if is_first && Engine::singleton().has_meta(STATES_META_KEY.into()) {
+            discard_states_after_reload();
+        }
+    }
+
+    pub fn unregister_class(class_name: &ClassName) {
+        with_registry(|registry| {
+            registry.classes.remove(class_name);
+            registry
+                .instances
+                .retain(|_, (instance_class, _)| instance_class != class_name);
+        });
+    }
+
+    /// Only called for classes with `#[class(hot_reload)]`, to not lock the registry on every object construction.
+    pub fn track_instance(
+        class_name: ClassName,
+        object_ptr: sys::GDExtensionObjectPtr,
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+    ) {
+        let raw_id = unsafe { interface_fn!(object_get_instance_id)(object_ptr) };
+        let Some(instance_id) = InstanceId::try_from_u64(raw_id) else {
+            return;
+        };
+
+        with_registry(|registry| {
+            registry
+                .instances
+                .insert(InstancePtr(instance_ptr), (class_name, instance_id))
+        });
+    }
+
+    pub fn untrack_instance(instance_ptr: sys::GDExtensionClassInstancePtr) {
+        with_registry(|registry| registry.instances.remove(&InstancePtr(instance_ptr)));
+    }
+
+    /// Whether the state of instances should be saved when their classes are unregistered.
+    ///
+    /// Godot only reloads libraries in the editor, and does not announce a reload before it unloads the library. The state is thus saved
+    /// on every unload in the editor; if no reload follows, it is dropped together with the engine.
+    pub fn is_saving_state() -> bool {
+        Engine::singleton().is_editor_hint()
+    }
+
+    pub fn save_instance_states(class_names: &[ClassName]) {
+        let instances: Vec<_> = with_registry(|registry| {
+            registry
+                .instances
+                .iter()
+                .filter(|(_, (class_name, _))| class_names.contains(class_name))
+                .filter_map(|(&ptr, &(class_name, instance_id))| {
+                    let fns = registry.classes.get(&class_name)?;
+                    Some((ptr, instance_id, fns.save_state_fn))
+                })
+                .collect()
+        });
+
+        if instances.is_empty() {
+            return;
+        }
+
+        let mut states = saved_states().unwrap_or_default();
+        for (InstancePtr(instance_ptr), instance_id, save_state_fn) in instances {
+            // SAFETY: tracked instances are untracked in the `free` callback, so the pointer is still valid.
+            let state = unsafe { save_state_fn(instance_ptr) };
+            states.set(instance_id.to_i64(), state);
+        }
+
+        Engine::singleton().set_meta(STATES_META_KEY.into(), states.to_variant());
+    }
+
+    pub fn restore_instance_state(
+        class_name: ClassName,
+        object_ptr: sys::GDExtensionObjectPtr,
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+    ) {
+        let Some(mut states) = saved_states() else {
+            return;
+        };
+
+        let raw_id = unsafe { interface_fn!(object_get_instance_id)(object_ptr) };
+        let Some(instance_id) = InstanceId::try_from_u64(raw_id) else {
+            return;
+        };
+
+        // Removed even if the class no longer supports hot reloading, so that the state does not outlive the reload.
+        let Some(state) = states.remove(instance_id.to_i64()) else {
+            return;
+        };
+        store_states(states);
+
+        let Some(fns) = with_registry(|registry| registry.classes.get(&class_name).copied()) else {
+            return;
+        };
+
+        match Dictionary::try_from_variant(&state) {
+            // SAFETY: `instance_ptr` has just been created for an instance of `class_name`.
+            Ok(state) => unsafe { (fns.restore_state_fn)(instance_ptr, state) },
+            Err(err) => {
+                log::godot_error!("Hot reload: invalid state for class {class_name}: {err}")
+            }
+        }
+    }
+
+    fn saved_states() -> Option<Dictionary> {
+        let engine = Engine::singleton();
+        let key = StringName::from(STATES_META_KEY);
+        if !engine.has_meta(key.clone()) {
+            return None;
+        }
+
+        Dictionary::try_from_variant(&engine.get_meta(key)).ok()
+    }
+
+    /// Stores the states on the engine, or removes the metadata once no state is left.
+    fn store_states(states: Dictionary) {
+        let mut engine = Engine::singleton();
+        if states.is_empty() {
+            engine.remove_meta(STATES_META_KEY.into());
+        } else {
+            engine.set_meta(STATES_META_KEY.into(), states.to_variant());
+        }
+    }
+
+    /// Once the reload has completed, removes the states of objects that were freed or not recreated in the meantime.
+    fn discard_states_after_reload() {
+        let callable = Callable::from_fn("godot-rust hot reload cleanup", |_args| {
+            Engine::singleton().remove_meta(STATES_META_KEY.into());
+            Ok(Variant::nil())
+        });
+
+        // One-shot: the callable must not outlive this library, which may be reloaded again.
+        let manager = GDExtensionManager::singleton();
+        let flags = ConnectFlags::CONNECT_ONE_SHOT.ord() as i64;
+        Signal::from_object_signal(&manager, "extensions_reloaded").connect(callable, flags);
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -665,8 +1467,16 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         generated_register_fn: None,
         user_register_fn: None,
         godot_params: default_creation_info(),
+        create_storage_fn: None,
+        before_ready_fn: None,
+        onready_get_virtual_fn: None,
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
+        #[cfg(since_api = "4.2")]
+        hot_reload_fns: None,
+        #[cfg(since_api = "4.2")]
+        icon: None,
+        dyn_trait_impls: Vec::new(),
     }
 }
 
@@ -0,0 +1,132 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Custom project settings, declared with `#[derive(ProjectSettings)]`.
+//!
+//! See [`ProjectSettingsGroup`] for registration and typed access.
+
+use crate::builtin::meta::FromGodot;
+use crate::builtin::{dict, GString, Variant};
+use crate::engine::ProjectSettings;
+use crate::obj::EngineEnum;
+use crate::property::PropertyHintInfo;
+
+/// Declaration of a single custom project setting.
+#[derive(Clone, Debug)]
+pub struct SettingInfo {
+    /// Path of the setting, e.g. `"game/ai/max_agents"`.
+    pub path: &'static str,
+    /// Value used when the setting is not present in `project.godot`, and which the editor offers for reverting.
+    pub default_value: Variant,
+    /// Hint shown in the project settings dialog, e.g. a range.
+    pub hint_info: PropertyHintInfo,
+    /// Whether the editor asks for a restart once the setting is changed.
+    pub restart_if_changed: bool,
+    /// Whether the setting is visible without enabling "Advanced Settings".
+    pub basic: bool,
+}
+
+/// A group of custom project settings, with typed access. Implemented by `#[derive(ProjectSettings)]`.
+///
+/// Settings must be registered once with [`register()`][Self::register], typically in
+/// [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init]:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::settings::ProjectSettingsGroup;
+///
+/// #[derive(ProjectSettings)]
+/// struct AiSettings {
+///     #[setting(path = "game/ai/max_agents", default = 64, range = (1, 512))]
+///     max_agents: i32,
+/// }
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Scene {
+///             AiSettings::register();
+///         }
+///     }
+/// }
+///
+/// // Later, read a single setting or the whole group:
+/// let max_agents: i32 = AiSettings::max_agents();
+/// let settings = AiSettings::load();
+/// ```
+pub trait ProjectSettingsGroup: Sized {
+    /// Declarations of all settings in this group, in field order.
+    fn setting_infos() -> Vec<SettingInfo>;
+
+    /// Reads the current values of all settings in this group.
+    fn load() -> Self;
+
+    /// Registers all settings with `ProjectSettings`, including their defaults and hints.
+    ///
+    /// Values already stored in `project.godot` are kept.
+    fn register() {
+        for info in Self::setting_infos() {
+            register_setting(&info);
+        }
+    }
+
+    /// Invokes `callback` with the freshly loaded group whenever project settings change.
+    ///
+    /// Godot does not report which setting changed, so the callback also runs when settings outside this group are modified.
+    /// The connection lives as long as the returned handle.
+    #[cfg(since_api = "4.2")]
+    fn on_changed<F>(mut callback: F) -> crate::builtin::ConnectionHandle
+    where
+        Self: 'static,
+        F: FnMut(Self) + Send + Sync + 'static,
+    {
+        let settings = ProjectSettings::singleton();
+        let signal = crate::builtin::Signal::from_object_signal(&settings, "settings_changed");
+
+        signal.connect_owned(&settings, move |_args| {
+            callback(Self::load());
+            Ok(Variant::nil())
+        })
+    }
+}
+
+/// Registers one setting with `ProjectSettings`. Used by [`ProjectSettingsGroup::register()`].
+pub fn register_setting(info: &SettingInfo) {
+    let mut settings = ProjectSettings::singleton();
+    let path = GString::from(info.path);
+
+    if !settings.has_setting(path.clone()) {
+        settings.set_setting(path.clone(), info.default_value.clone());
+    }
+
+    settings.set_initial_value(path.clone(), info.default_value.clone());
+    settings.add_property_info(dict! {
+        "name": path.clone(),
+        "type": info.default_value.get_type(),
+        "hint": info.hint_info.hint.ord(),
+        "hint_string": info.hint_info.hint_string.clone(),
+    });
+    settings.set_restart_if_changed(path.clone(), info.restart_if_changed);
+    settings.set_as_basic(path, info.basic);
+}
+
+/// Reads the setting at `path`, or returns `default()` if it does not exist or has another type.
+pub fn get_setting_or<T: FromGodot>(path: &str, default: impl FnOnce() -> T) -> T {
+    let settings = ProjectSettings::singleton();
+    let path = GString::from(path);
+
+    if !settings.has_setting(path.clone()) {
+        return default();
+    }
+
+    let value = settings.get_setting(path.clone());
+    value.try_to::<T>().unwrap_or_else(|err| {
+        crate::log::godot_warn!("project setting `{path}` has unexpected type: {err}");
+        default()
+    })
+}
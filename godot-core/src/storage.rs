@@ -4,7 +4,15 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::obj::GodotClass;
+use std::any::TypeId;
+use std::fmt::{self, Debug};
+#[cfg(not(debug_assertions))]
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::sync::Arc;
+
+use crate::obj::{Gd, GodotClass};
 use crate::{godot_error, out};
 use godot_ffi as sys;
 
@@ -29,12 +37,25 @@ mod single_threaded {
     use crate::obj::{Base, Gd, GodotClass, Inherits};
     use crate::out;
 
-    use super::Lifecycle;
+    use super::{
+        BorrowContext, BorrowKind, BorrowTracker, Lifecycle, ParentStorage, StorageHeader,
+        TrackedGuard,
+    };
+
+    pub type RefGuard<'a, T> = TrackedGuard<'a, cell::Ref<'a, T>>;
+    pub type MutGuard<'a, T> = TrackedGuard<'a, cell::RefMut<'a, T>>;
 
     /// Manages storage and lifecycle of user's extension class instances.
+    #[repr(C)]
     pub struct InstanceStorage<T: GodotClass> {
+        // Must be the first field, see as_storage().
+        pub(super) header: StorageHeader,
         user_instance: cell::RefCell<T>,
         pub(super) base: Base<T::Base>,
+        pub(super) borrows: BorrowTracker,
+
+        // Declared after `user_instance`, so the state of the derived class is dropped first.
+        pub(super) parent: Option<ParentStorage>,
 
         // Declared after `user_instance`, is dropped last
         pub(super) lifecycle: cell::Cell<Lifecycle>,
@@ -43,12 +64,19 @@ mod single_threaded {
 
     /// For all Godot extension classes
     impl<T: GodotClass> InstanceStorage<T> {
-        pub fn construct(user_instance: T, base: Base<T::Base>) -> Self {
+        pub fn construct(
+            user_instance: T,
+            base: Base<T::Base>,
+            parent: Option<ParentStorage>,
+        ) -> Self {
             out!("    Storage::construct             <{}>", type_name::<T>());
 
             Self {
+                header: StorageHeader::new::<T>(parent.as_ref()),
                 user_instance: cell::RefCell::new(user_instance),
+                parent,
                 base,
+                borrows: BorrowTracker::default(),
                 lifecycle: cell::Cell::new(Lifecycle::Alive),
                 godot_ref_count: cell::Cell::new(1),
             }
@@ -56,29 +84,61 @@ mod single_threaded {
 
         pub fn is_bound(&self) -> bool {
             // Needs to borrow mutably, otherwise it succeeds if shared borrows are alive.
-            self.user_instance.try_borrow_mut().is_err()
+            self.user_instance.try_borrow_mut().is_err() || self.is_parent_bound()
         }
 
-        pub fn get(&self) -> cell::Ref<T> {
-            self.user_instance.try_borrow().unwrap_or_else(|_e| {
-                panic!(
+        #[track_caller]
+        pub fn get(&self) -> RefGuard<T> {
+            self.get_tracked(None)
+        }
+
+        /// Like [`get()`][Self::get], for a method or callback invoked by Godot.
+        #[track_caller]
+        pub fn get_for(&self, context: BorrowContext) -> RefGuard<T> {
+            self.get_tracked(Some(context))
+        }
+
+        #[track_caller]
+        pub fn get_mut(&self) -> MutGuard<T> {
+            self.get_mut_tracked(None)
+        }
+
+        /// Like [`get_mut()`][Self::get_mut], for a method or callback invoked by Godot.
+        #[track_caller]
+        pub fn get_mut_for(&self, context: BorrowContext) -> MutGuard<T> {
+            self.get_mut_tracked(Some(context))
+        }
+
+        #[track_caller]
+        fn get_tracked(&self, context: Option<BorrowContext>) -> RefGuard<T> {
+            match self.user_instance.try_borrow() {
+                Ok(guard) => {
+                    TrackedGuard::new::<T>(guard, &self.borrows, BorrowKind::Shared, context)
+                }
+                Err(_) => panic!(
                     "Gd<T>::bind() failed, already bound; T = {}.\n  \
                      Make sure there is no &mut T live at the time.\n  \
-                     This often occurs when calling a GDScript function/signal from Rust, which then calls again Rust code.",
-                    type_name::<T>()
-                )
-            })
+                     This often occurs when calling a GDScript function/signal from Rust, which then calls again Rust code.{}",
+                    type_name::<T>(),
+                    self.borrows.describe(),
+                ),
+            }
         }
 
-        pub fn get_mut(&self) -> cell::RefMut<T> {
-            self.user_instance.try_borrow_mut().unwrap_or_else(|_e| {
-                panic!(
+        #[track_caller]
+        fn get_mut_tracked(&self, context: Option<BorrowContext>) -> MutGuard<T> {
+            match self.user_instance.try_borrow_mut() {
+                Ok(guard) => {
+                    TrackedGuard::new::<T>(guard, &self.borrows, BorrowKind::Exclusive, context)
+                }
+                Err(_) => panic!(
                     "Gd<T>::bind_mut() failed, already bound; T = {}.\n  \
                      Make sure there is no &T or &mut T live at the time.\n  \
-                     This often occurs when calling a GDScript function/signal from Rust, which then calls again Rust code.",
-                    type_name::<T>()
-                )
-            })
+                     This often occurs when calling a GDScript function/signal from Rust, which then calls again Rust code.{}",
+                    type_name::<T>(),
+                    self.borrows.describe(),
+                ),
+            }
         }
 
         pub fn get_gd(&self) -> Gd<T>
@@ -126,7 +186,13 @@ mod multi_threaded {
     use crate::obj::{Base, Gd, GodotClass, Inherits};
     use crate::out;
 
-    use super::Lifecycle;
+    use super::{
+        BorrowContext, BorrowKind, BorrowTracker, Lifecycle, ParentStorage, StorageHeader,
+        TrackedGuard,
+    };
+
+    pub type RefGuard<'a, T> = TrackedGuard<'a, sync::RwLockReadGuard<'a, T>>;
+    pub type MutGuard<'a, T> = TrackedGuard<'a, sync::RwLockWriteGuard<'a, T>>;
 
     pub struct AtomicLifecycle {
         atomic: AtomicU32,
@@ -158,9 +224,16 @@ mod multi_threaded {
     }
 
     /// Manages storage and lifecycle of user's extension class instances.
+    #[repr(C)]
     pub struct InstanceStorage<T: GodotClass> {
+        // Must be the first field, see as_storage().
+        pub(super) header: StorageHeader,
         user_instance: sync::RwLock<T>,
         pub(super) base: Base<T::Base>,
+        pub(super) borrows: BorrowTracker,
+
+        // Declared after `user_instance`, so the state of the derived class is dropped first.
+        pub(super) parent: Option<ParentStorage>,
 
         // Declared after `user_instance`, is dropped last
         pub(super) lifecycle: AtomicLifecycle,
@@ -169,12 +242,19 @@ mod multi_threaded {
 
     /// For all Godot extension classes
     impl<T: GodotClass> InstanceStorage<T> {
-        pub fn construct(user_instance: T, base: Base<T::Base>) -> Self {
+        pub fn construct(
+            user_instance: T,
+            base: Base<T::Base>,
+            parent: Option<ParentStorage>,
+        ) -> Self {
             out!("    Storage::construct             <{:?}>", base);
 
             Self {
+                header: StorageHeader::new::<T>(parent.as_ref()),
                 user_instance: sync::RwLock::new(user_instance),
+                parent,
                 base,
+                borrows: BorrowTracker::default(),
                 lifecycle: AtomicLifecycle::new(Lifecycle::Alive),
                 godot_ref_count: AtomicU32::new(1),
             }
@@ -182,29 +262,61 @@ mod multi_threaded {
 
         pub fn is_bound(&self) -> bool {
             // Needs to borrow mutably, otherwise it succeeds if shared borrows are alive.
-            self.write_ignoring_poison().is_none()
+            self.write_ignoring_poison().is_none() || self.is_parent_bound()
+        }
+
+        #[track_caller]
+        pub fn get(&self) -> RefGuard<T> {
+            self.get_tracked(None)
+        }
+
+        /// Like [`get()`][Self::get], for a method or callback invoked by Godot.
+        #[track_caller]
+        pub fn get_for(&self, context: BorrowContext) -> RefGuard<T> {
+            self.get_tracked(Some(context))
+        }
+
+        #[track_caller]
+        pub fn get_mut(&self) -> MutGuard<T> {
+            self.get_mut_tracked(None)
+        }
+
+        /// Like [`get_mut()`][Self::get_mut], for a method or callback invoked by Godot.
+        #[track_caller]
+        pub fn get_mut_for(&self, context: BorrowContext) -> MutGuard<T> {
+            self.get_mut_tracked(Some(context))
         }
 
-        pub fn get(&self) -> sync::RwLockReadGuard<T> {
-            self.read_ignoring_poison().unwrap_or_else(|| {
-                panic!(
+        #[track_caller]
+        fn get_tracked(&self, context: Option<BorrowContext>) -> RefGuard<T> {
+            match self.read_ignoring_poison() {
+                Some(guard) => {
+                    TrackedGuard::new::<T>(guard, &self.borrows, BorrowKind::Shared, context)
+                }
+                None => panic!(
                     "Gd<T>::bind() failed, already bound; obj = {}.\n  \
                      Make sure there is no &mut T live at the time.\n  \
-                     This often occurs when calling a GDScript function/signal from Rust, which then calls again Rust code.",
+                     This often occurs when calling a GDScript function/signal from Rust, which then calls again Rust code.{}",
                     self.base,
-                )
-            })
+                    self.borrows.describe(),
+                ),
+            }
         }
 
-        pub fn get_mut(&self) -> sync::RwLockWriteGuard<T> {
-            self.write_ignoring_poison().unwrap_or_else(|| {
-                panic!(
+        #[track_caller]
+        fn get_mut_tracked(&self, context: Option<BorrowContext>) -> MutGuard<T> {
+            match self.write_ignoring_poison() {
+                Some(guard) => {
+                    TrackedGuard::new::<T>(guard, &self.borrows, BorrowKind::Exclusive, context)
+                }
+                None => panic!(
                     "Gd<T>::bind_mut() failed, already bound; obj = {}.\n  \
                      Make sure there is no &T or &mut T live at the time.\n  \
-                     This often occurs when calling a GDScript function/signal from Rust, which then calls again Rust code.",
+                     This often occurs when calling a GDScript function/signal from Rust, which then calls again Rust code.{}",
                     self.base,
-                )
-            })
+                    self.borrows.describe(),
+                ),
+            }
         }
 
         pub fn get_gd(&self) -> Gd<T>
@@ -284,17 +396,40 @@ impl<T: GodotClass> InstanceStorage<T> {
         format!("{:?}", self.base)
     }
 
+    /// Instance ID of the object, even if it is being destroyed.
+    pub fn instance_id(&self) -> crate::obj::InstanceId {
+        self.base.instance_id_unchecked()
+    }
+
+    /// The object as a node, for initializing `OnReady` fields.
+    ///
+    /// # Safety
+    /// `T` must inherit `Node`.
+    pub unsafe fn base_node(&self) -> Gd<crate::engine::Node> {
+        Gd::from_obj_sys(self.base.obj_sys())
+    }
+
     #[must_use]
     pub fn into_raw(self) -> *mut Self {
         Box::into_raw(Box::new(self))
     }
 
+    /// Whether the state of a Rust base class is currently bound.
+    fn is_parent_bound(&self) -> bool {
+        self.parent
+            .as_ref()
+            .map_or(false, |parent| parent.is_bound())
+    }
+
     pub fn mark_destroyed_by_godot(&self) {
         out!(
             "    Storage::mark_destroyed_by_godot", // -- {:?}",
                                                     //self.user_instance
         );
         self.lifecycle.set(Lifecycle::Destroying);
+        if let Some(parent) = &self.parent {
+            parent.mark_destroyed_by_godot();
+        }
         out!(
             "    mark;  self={:?}, val={:?}, obj={:?}",
             self as *const _,
@@ -327,17 +462,299 @@ impl<T: GodotClass> Drop for InstanceStorage<T> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Rust base classes
+
+/// Storage of a Rust base class, owned by the storage of the class inheriting from it.
+pub type ParentStorage = Box<dyn ErasedStorage>;
+
+/// Start of every `InstanceStorage<T>`.
+///
+/// Godot knows a single instance pointer per object, which points to the storage of the most derived class. If that class inherits from
+/// other Rust classes, their storages form a chain, through which the storage of each base class can be found.
+#[repr(C)]
+pub struct StorageHeader {
+    type_id: TypeId,
+
+    /// Header of the storage of the Rust base class, or null if the base class is an engine class.
+    parent: *const StorageHeader,
+}
+
+impl StorageHeader {
+    fn new<T: GodotClass>(parent: Option<&ParentStorage>) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            parent: parent.map_or(ptr::null(), |parent| parent.header()),
+        }
+    }
+}
+
+/// `InstanceStorage<T>` of a Rust base class, whose type is only known to the storage of the derived class.
+pub trait ErasedStorage {
+    fn header(&self) -> *const StorageHeader;
+    fn is_bound(&self) -> bool;
+    fn mark_destroyed_by_godot(&self);
+}
+
+impl<T: GodotClass> ErasedStorage for InstanceStorage<T> {
+    fn header(&self) -> *const StorageHeader {
+        // Same address as `self`, as the header is the first field.
+        &self.header
+    }
+
+    fn is_bound(&self) -> bool {
+        InstanceStorage::is_bound(self)
+    }
+
+    fn mark_destroyed_by_godot(&self) {
+        InstanceStorage::mark_destroyed_by_godot(self)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Borrow tracking
+
+/// Guard for the user instance, returned by `InstanceStorage::get()` and `get_mut()`.
+///
+/// Wraps the `RefCell`/`RwLock` guard `G`. In debug builds, the borrow is registered with the storage's `BorrowTracker` for as long as the
+/// guard lives, so that a conflicting borrow can report where this one was taken.
+pub struct TrackedGuard<'a, G> {
+    guard: G,
+    _token: BorrowToken<'a>,
+}
+
+impl<'a, G> TrackedGuard<'a, G> {
+    #[track_caller]
+    fn new<T>(
+        guard: G,
+        tracker: &'a BorrowTracker,
+        kind: BorrowKind,
+        context: Option<BorrowContext>,
+    ) -> Self {
+        Self {
+            guard,
+            _token: tracker.track::<T>(kind, context),
+        }
+    }
+}
+
+impl<G: Deref> Deref for TrackedGuard<'_, G> {
+    type Target = G::Target;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.deref()
+    }
+}
+
+impl<G: DerefMut> DerefMut for TrackedGuard<'_, G> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.deref_mut()
+    }
+}
+
+impl<G: Debug> Debug for TrackedGuard<'_, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.guard.fmt(f)
+    }
+}
+
+/// Method or callback on whose behalf gdext borrows a user instance, listed with the outstanding borrows.
+#[derive(Clone, Debug)]
+pub enum BorrowContext {
+    /// Name known at compile time, e.g. of a `#[func]` or a virtual method.
+    Static(&'static str),
+
+    /// Name of a method declared at runtime through `ClassBuilder`.
+    Dynamic(Arc<str>),
+}
+
+impl BorrowContext {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BorrowContext::Static(name) => name,
+            BorrowContext::Dynamic(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for BorrowContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum BorrowKind {
+    Shared,
+    Exclusive,
+}
+
+/// Records the outstanding borrows of a user instance, in debug builds.
+///
+/// `bind()`/`bind_mut()` conflicts typically arise through re-entrancy, e.g. a signal emitted while `bind_mut()` is held calls back into the
+/// same object. The panic then only shows the second borrow, while the first one is the actual culprit.
+#[derive(Default)]
+pub struct BorrowTracker {
+    #[cfg(debug_assertions)]
+    state: std::sync::Mutex<BorrowTrackerState>,
+}
+
+#[cfg(debug_assertions)]
+#[derive(Default)]
+struct BorrowTrackerState {
+    next_id: u64,
+    borrows: Vec<BorrowRecord>,
+}
+
+#[cfg(debug_assertions)]
+struct BorrowRecord {
+    id: u64,
+    kind: BorrowKind,
+    class: &'static str,
+    location: &'static std::panic::Location<'static>,
+    context: Option<BorrowContext>,
+    thread: std::thread::ThreadId,
+}
+
+#[cfg(debug_assertions)]
+impl BorrowTracker {
+    #[track_caller]
+    fn track<T>(&self, kind: BorrowKind, context: Option<BorrowContext>) -> BorrowToken {
+        let mut state = self.lock();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.borrows.push(BorrowRecord {
+            id,
+            kind,
+            class: std::any::type_name::<T>(),
+            location: std::panic::Location::caller(),
+            context,
+            thread: std::thread::current().id(),
+        });
+
+        BorrowToken { tracker: self, id }
+    }
+
+    /// Lists the outstanding borrows, to be appended to a panic message.
+    pub fn describe(&self) -> String {
+        let state = self.lock();
+        if state.borrows.is_empty() {
+            return String::new();
+        }
+
+        let current_thread = std::thread::current().id();
+
+        let mut message = String::from("\n  Outstanding borrows:");
+        for borrow in state.borrows.iter() {
+            let method = match borrow.kind {
+                BorrowKind::Shared => "bind()",
+                BorrowKind::Exclusive => "bind_mut()",
+            };
+            let context = match &borrow.context {
+                Some(context) => format!(" for `{context}`"),
+                None => String::new(),
+            };
+            let thread = if borrow.thread == current_thread {
+                String::from("current thread")
+            } else {
+                format!("thread {:?}", borrow.thread)
+            };
+
+            message += &format!(
+                "\n  - {method} of {class}{context} at {location}, {thread}",
+                class = borrow.class,
+                location = borrow.location,
+            );
+        }
+
+        message
+    }
+
+    // Ignores poisoning: a panic while a borrow is held must not hide the information for subsequent borrows.
+    fn lock(&self) -> std::sync::MutexGuard<BorrowTrackerState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl BorrowTracker {
+    fn track<T>(&self, _kind: BorrowKind, _context: Option<BorrowContext>) -> BorrowToken {
+        BorrowToken {
+            _tracker: PhantomData,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        String::from("\n  Build with debug assertions to see where outstanding borrows were taken.")
+    }
+}
+
+/// Removes a borrow from its `BorrowTracker` when dropped.
+struct BorrowToken<'a> {
+    #[cfg(debug_assertions)]
+    tracker: &'a BorrowTracker,
+    #[cfg(debug_assertions)]
+    id: u64,
+
+    #[cfg(not(debug_assertions))]
+    _tracker: PhantomData<&'a BorrowTracker>,
+}
+
+#[cfg(debug_assertions)]
+impl Drop for BorrowToken<'_> {
+    fn drop(&mut self) {
+        let mut state = self.tracker.lock();
+        state.borrows.retain(|borrow| borrow.id != self.id);
+    }
+}
+
 /// Interprets the opaque pointer as pointing to `InstanceStorage<T>`.
 ///
+/// If the instance belongs to a class inheriting from `T`, the storage of `T` is looked up in the chain of Rust base classes.
+///
 /// Note: returns reference with unbounded lifetime; intended for local usage
 ///
 /// # Safety
-/// `instance_ptr` is assumed to point to a valid instance.
+/// `instance_ptr` is assumed to point to a valid instance of `T` or a Rust class inheriting from `T`.
 // Note: unbounded ref AND &mut out of thin air is not very beautiful, but it's  -- consider using with_storage(ptr, closure) and drop_storage(ptr)
 pub unsafe fn as_storage<'u, T: GodotClass>(
     instance_ptr: sys::GDExtensionClassInstancePtr,
 ) -> &'u InstanceStorage<T> {
-    &*(instance_ptr as *mut InstanceStorage<T>)
+    let mut header = instance_ptr as *const StorageHeader;
+
+    // Fast path for classes without Rust base classes: the parent is null, so no type comparison is needed.
+    while !(*header).parent.is_null() && (*header).type_id != TypeId::of::<T>() {
+        header = (*header).parent;
+    }
+
+    debug_assert_eq!(
+        (*header).type_id,
+        TypeId::of::<T>(),
+        "instance does not contain Rust class {}",
+        std::any::type_name::<T>()
+    );
+
+    &*(header as *const InstanceStorage<T>)
+}
+
+/// Returns the `TypeId`s of the Rust classes stored in the instance, from the most derived class to its Rust base classes.
+///
+/// # Safety
+/// `instance_ptr` is assumed to point to a valid instance.
+pub(crate) unsafe fn stored_type_ids(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+) -> Vec<TypeId> {
+    let mut type_ids = Vec::new();
+
+    let mut header = instance_ptr as *const StorageHeader;
+    while !header.is_null() {
+        type_ids.push((*header).type_id);
+        header = (*header).parent;
+    }
+
+    type_ids
 }
 
 /// # Safety
@@ -367,8 +784,9 @@ pub unsafe fn destroy_storage<T: GodotClass>(instance_ptr: sys::GDExtensionClass
             "Destroyed an object from Godot side, while a bind() or bind_mut() call was active.\n  \
             This is a bug in your code that may cause UB and logic errors. Make sure that objects are not\n  \
             destroyed while you still hold a Rust reference to them, or use Gd::free() which is safe.\n  \
-            object: {}",
-            (*raw).debug_info()
+            object: {}{}",
+            (*raw).debug_info(),
+            (*raw).borrows.describe(),
         );
 
         // In Debug mode, crash which may trigger breakpoint.
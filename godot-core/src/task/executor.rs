@@ -0,0 +1,245 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Wake, Waker};
+
+use crate::builtin::{Callable, Signal, Variant};
+use crate::engine::global::Error;
+use crate::engine::{Engine, Os, SceneTree};
+use crate::obj::Gd;
+
+type BoxedTask = Pin<Box<dyn Future<Output = ()>>>;
+
+thread_local! {
+    static EXECUTOR: RefCell<Executor> = RefCell::new(Executor::default());
+}
+
+/// Tasks woken since they were last polled. Shared with wakers, which may be invoked from any thread.
+static WOKEN_TASKS: Mutex<Vec<TaskId>> = Mutex::new(Vec::new());
+
+/// Spawns a future as a task, which is polled on the main thread until completion.
+///
+/// The future is polled once immediately, so it runs synchronously up to its first `.await` that is not ready yet, just like a
+/// GDScript function up to its first `await`. Afterwards, it is polled at most once per frame, whenever it has been woken.
+///
+/// Returns a [`TaskHandle`], which can be used to query or cancel the task. Dropping the handle does _not_ cancel the task.
+///
+/// # Panics
+/// If the main loop is not a [`SceneTree`], or if not called on the main thread.
+pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+    // The executor is thread-local and only driven on the main thread; tasks spawned elsewhere would never be polled.
+    let os = Os::singleton();
+    assert_eq!(
+        os.get_thread_caller_id(),
+        os.get_main_thread_id(),
+        "task::spawn() must be called on the main thread"
+    );
+
+    let id = EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        executor.ensure_driven();
+        executor.add_task(Box::pin(future))
+    });
+
+    poll_task(id);
+    TaskHandle { id }
+}
+
+/// Handle to a task created by [`spawn()`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TaskHandle {
+    id: TaskId,
+}
+
+impl TaskHandle {
+    /// Returns `true` if the task has neither completed nor been cancelled.
+    pub fn is_pending(&self) -> bool {
+        EXECUTOR.with(|executor| executor.borrow().is_pending(self.id))
+    }
+
+    /// Cancels the task, dropping its future. The task will not be polled again.
+    ///
+    /// Has no effect if the task has already completed.
+    pub fn cancel(self) {
+        EXECUTOR.with(|executor| executor.borrow_mut().cancel(self.id));
+    }
+}
+
+impl fmt::Debug for TaskHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskHandle")
+            .field("id", &self.id.0)
+            .field("is_pending", &self.is_pending())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct TaskId(u64);
+
+#[derive(Default)]
+struct Executor {
+    tasks: HashMap<TaskId, BoxedTask>,
+    next_id: u64,
+
+    /// Task currently being polled; its future is temporarily removed from `tasks`.
+    polling: Option<TaskId>,
+    /// Whether the task currently being polled has been cancelled from within.
+    polling_cancelled: bool,
+
+    /// Wakers of futures that wait for the next frame.
+    next_frame_wakers: Vec<Waker>,
+    /// Connection to the scene tree's `process_frame` signal, if the executor is driven.
+    driver: Option<(Signal, Callable)>,
+}
+
+impl Executor {
+    fn add_task(&mut self, task: BoxedTask) -> TaskId {
+        let id = TaskId(self.next_id);
+        self.next_id += 1;
+        self.tasks.insert(id, task);
+        id
+    }
+
+    fn is_pending(&self, id: TaskId) -> bool {
+        self.tasks.contains_key(&id) || (self.polling == Some(id) && !self.polling_cancelled)
+    }
+
+    fn cancel(&mut self, id: TaskId) {
+        if self.polling == Some(id) {
+            self.polling_cancelled = true;
+        } else {
+            self.tasks.remove(&id);
+        }
+    }
+
+    fn ensure_driven(&mut self) {
+        if self.driver.is_some() {
+            return;
+        }
+
+        let callable = Callable::from_fn("godot-rust async executor", |_args| {
+            poll_frame();
+            Ok(Variant::nil())
+        });
+
+        let signal = Signal::from_object_signal(&scene_tree(), "process_frame");
+        let error = signal.connect(callable.clone(), 0);
+        assert_eq!(
+            error,
+            Error::OK,
+            "failed to connect async executor to SceneTree::process_frame"
+        );
+
+        self.driver = Some((signal, callable));
+    }
+
+    /// Disconnects from the scene tree, so that it does not keep calling into the library after it is unloaded.
+    fn stop_driving(&mut self) {
+        let Some((signal, callable)) = self.driver.take() else {
+            return;
+        };
+
+        // The tree may already be freed, e.g. when the engine shuts down.
+        if signal.object().is_some() && signal.is_connected(callable.clone()) {
+            signal.disconnect(callable);
+        }
+    }
+}
+
+struct TaskWaker {
+    id: TaskId,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        WOKEN_TASKS.lock().unwrap().push(self.id);
+    }
+}
+
+/// Returns the scene tree, which drives the executor.
+pub(super) fn scene_tree() -> Gd<SceneTree> {
+    Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>())
+        .expect("async tasks require the main loop to be a SceneTree")
+}
+
+/// Registers a waker to be woken at the beginning of the next frame.
+pub(super) fn wake_next_frame(waker: Waker) {
+    EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        executor.ensure_driven();
+        executor.next_frame_wakers.push(waker);
+    });
+}
+
+/// Invoked once per frame: polls all tasks that have been woken since the last frame.
+fn poll_frame() {
+    let next_frame_wakers =
+        EXECUTOR.with(|executor| std::mem::take(&mut executor.borrow_mut().next_frame_wakers));
+    next_frame_wakers.into_iter().for_each(Waker::wake);
+
+    let mut woken = std::mem::take(&mut *WOKEN_TASKS.lock().unwrap());
+    woken.sort_by_key(|id| id.0);
+    woken.dedup();
+
+    for id in woken {
+        poll_task(id);
+    }
+}
+
+/// Polls a single task. The executor is not borrowed during polling, so futures may spawn or cancel tasks.
+fn poll_task(id: TaskId) {
+    let task = EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        let task = executor.tasks.remove(&id)?;
+        executor.polling = Some(id);
+        executor.polling_cancelled = false;
+        Some(task)
+    });
+
+    // Already completed or cancelled.
+    let Some(mut task) = task else {
+        return;
+    };
+
+    let waker = Waker::from(Arc::new(TaskWaker { id }));
+    let mut context = Context::from_waker(&waker);
+    let poll = task.as_mut().poll(&mut context);
+
+    let is_cancelled = EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        executor.polling = None;
+        std::mem::take(&mut executor.polling_cancelled)
+    });
+
+    if poll.is_pending() && !is_cancelled {
+        EXECUTOR.with(|executor| executor.borrow_mut().tasks.insert(id, task));
+    }
+}
+
+pub(super) fn cleanup() {
+    let tasks = EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        executor.next_frame_wakers.clear();
+        executor.stop_driving();
+        std::mem::take(&mut executor.tasks)
+    });
+
+    // Drop outside of the borrow, as futures may access the executor in their destructors.
+    drop(tasks);
+    WOKEN_TASKS.lock().unwrap().clear();
+}
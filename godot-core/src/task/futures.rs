@@ -0,0 +1,145 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, Signal, Variant};
+use crate::engine::global::Error;
+use crate::engine::object::ConnectFlags;
+use crate::engine::{Engine, SceneTreeTimer};
+use crate::obj::{EngineEnum, Gd};
+
+use super::executor;
+
+/// Future that resolves with the arguments of the next emission of a signal.
+///
+/// Created by [`Signal::to_future()`]. Dropping the future before the signal is emitted removes the connection.
+///
+/// If the signal's object is freed before emitting the signal, the future never resolves.
+pub struct SignalFuture {
+    signal: Signal,
+    callable: Callable,
+    state: Arc<Mutex<SignalFutureState>>,
+
+    /// Objects that need to stay alive until the signal is emitted (e.g. a `SceneTreeTimer`).
+    _keep_alive: Option<Gd<SceneTreeTimer>>,
+}
+
+#[derive(Default)]
+struct SignalFutureState {
+    args: Option<Vec<Variant>>,
+    waker: Option<Waker>,
+}
+
+impl SignalFuture {
+    pub(crate) fn new(signal: Signal) -> Self {
+        let state = Arc::new(Mutex::new(SignalFutureState::default()));
+
+        let callable = {
+            let state = state.clone();
+            Callable::from_fn(signal.name(), move |args| {
+                let mut state = state.lock().unwrap();
+                state.args = Some(args.iter().map(|&arg| arg.clone()).collect());
+
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+
+                Ok(Variant::nil())
+            })
+        };
+
+        let flags = ConnectFlags::CONNECT_ONE_SHOT.ord() as i64;
+        let error = signal.connect(callable.clone(), flags);
+        assert_eq!(
+            error,
+            Error::OK,
+            "failed to connect future to signal `{}`",
+            signal.name()
+        );
+
+        Self {
+            signal,
+            callable,
+            state,
+            _keep_alive: None,
+        }
+    }
+}
+
+impl Future for SignalFuture {
+    type Output = Vec<Variant>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        match state.args.take() {
+            Some(args) => Poll::Ready(args),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for SignalFuture {
+    fn drop(&mut self) {
+        let is_emitted = self.state.lock().unwrap().args.is_some();
+        let is_object_alive = self
+            .signal
+            .object_id()
+            .map_or(false, |id| !crate::engine::object_ptr_from_id(id).is_null());
+
+        if !is_emitted && is_object_alive && self.signal.is_connected(self.callable.clone()) {
+            self.signal.disconnect(self.callable.clone());
+        }
+    }
+}
+
+/// Returns a future that resolves after `seconds` have passed, using a [`SceneTreeTimer`].
+///
+/// _Godot equivalent: `await get_tree().create_timer(seconds).timeout`_
+pub fn timer(seconds: f64) -> SignalFuture {
+    let timer = executor::scene_tree()
+        .create_timer(seconds)
+        .expect("SceneTree::create_timer() returned null");
+
+    let mut future = SignalFuture::new(Signal::from_object_signal(&timer, "timeout"));
+    future._keep_alive = Some(timer);
+    future
+}
+
+/// Returns a future that resolves at the beginning of the next frame.
+///
+/// _Godot equivalent: `await get_tree().process_frame`_
+pub fn next_frame() -> NextFrame {
+    NextFrame { target_frame: None }
+}
+
+/// Future that resolves at the beginning of the next frame. Created by [`next_frame()`].
+pub struct NextFrame {
+    target_frame: Option<u64>,
+}
+
+impl Future for NextFrame {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let current_frame = Engine::singleton().get_process_frames();
+        let target_frame = *self.target_frame.get_or_insert(current_frame + 1);
+
+        if current_frame >= target_frame {
+            Poll::Ready(())
+        } else {
+            executor::wake_next_frame(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,47 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Asynchronous tasks, driven by Godot's main loop.
+//!
+//! Rust futures can be spawned with [`spawn()`] and are then polled on the main thread, once per frame. Together with the futures
+//! returned by [`Signal::to_future()`][crate::builtin::Signal::to_future], [`timer()`] and [`next_frame()`], this allows expressing
+//! multi-frame logic linearly, similar to GDScript's `await`:
+//!
+//! ```no_run
+//! # use godot::prelude::*;
+//! use godot::task;
+//!
+//! fn play_cutscene(mut npc: Gd<Node2D>) {
+//!     task::spawn(async move {
+//!         npc.set_position(Vector2::new(100.0, 0.0));
+//!         task::timer(1.5).await;
+//!
+//!         npc.set_position(Vector2::new(200.0, 0.0));
+//!         task::next_frame().await;
+//!
+//!         npc.queue_free();
+//!     });
+//! }
+//! ```
+//!
+//! The executor is attached to the `process_frame` signal of the [`SceneTree`][crate::engine::SceneTree], which must therefore be
+//! the main loop. Tasks are only available since Godot 4.2.
+
+#[cfg(since_api = "4.2")]
+mod executor;
+#[cfg(since_api = "4.2")]
+mod futures;
+
+#[cfg(since_api = "4.2")]
+pub use executor::*;
+#[cfg(since_api = "4.2")]
+pub use futures::*;
+
+/// Drops all tasks that have not completed yet. Called on deinitialization, while the engine is still available.
+pub(crate) fn cleanup() {
+    #[cfg(since_api = "4.2")]
+    executor::cleanup();
+}
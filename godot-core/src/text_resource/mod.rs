@@ -0,0 +1,556 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reading and writing Godot's text resource format (`.tres` and `.tscn` files), without an engine instance.
+//!
+//! Everything in this module is implemented in Rust and does not call into Godot. It can thus be used from build scripts or
+//! command-line tools that generate scenes and resources offline, before (or without) the GDExtension library being loaded.
+//! Values are represented by [`TextValue`], which uses the Rust-side builtins such as [`Vector3`][crate::builtin::Vector3] or
+//! [`Transform3D`][crate::builtin::Transform3D], and Rust strings and collections for engine-backed types.
+//!
+//! ```no_run
+//! use godot::builtin::Vector3;
+//! use godot::text_resource::{TextResource, TextValue};
+//!
+//! let mut scene = TextResource::new_scene();
+//! let crate_scene = scene.add_ext_resource("PackedScene", "res://props/crate.tscn");
+//!
+//! scene.add_node("Level", "Node3D", None);
+//! scene
+//!     .add_node("Crate", "", Some("."))
+//!     .set_attribute("instance", TextValue::ExtResource(crate_scene))
+//!     .set_property("position", Vector3::new(1.0, 0.0, -2.0));
+//!
+//! scene.update_load_steps();
+//! std::fs::write("level.tscn", scene.to_string()).unwrap();
+//!
+//! let parsed = TextResource::parse(&std::fs::read_to_string("level.tscn").unwrap()).unwrap();
+//! assert_eq!(parsed, scene);
+//! ```
+
+mod parser;
+mod value;
+mod writer;
+
+pub use value::TextValue;
+
+use std::fmt;
+
+/// Contents of a `.tres` or `.tscn` file, as an ordered list of sections.
+///
+/// The first section is the file header, i.e. `[gd_scene ...]` or `[gd_resource ...]`. It is followed by sections such as
+/// `[ext_resource]`, `[sub_resource]`, `[resource]`, `[node]` or `[connection]`.
+///
+/// The [`Display`][fmt::Display] impl writes the resource in Godot's text format; use `to_string()` to obtain the file contents.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TextResource {
+    /// Sections in file order, starting with the header.
+    pub sections: Vec<Section>,
+}
+
+impl TextResource {
+    /// Parses the contents of a `.tres` or `.tscn` file.
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        let sections = parser::parse_sections(source)?;
+        Ok(Self { sections })
+    }
+
+    /// Creates an empty scene, consisting only of the `[gd_scene]` header.
+    pub fn new_scene() -> Self {
+        let mut header = Section::new("gd_scene");
+        header.set_attribute("format", 3);
+
+        Self {
+            sections: vec![header],
+        }
+    }
+
+    /// Creates a resource of class `type_name`, with a `[gd_resource]` header and an empty `[resource]` section.
+    pub fn new_resource(type_name: &str) -> Self {
+        let mut header = Section::new("gd_resource");
+        header
+            .set_attribute("type", type_name)
+            .set_attribute("format", 3);
+
+        Self {
+            sections: vec![header, Section::new("resource")],
+        }
+    }
+
+    /// The file header, i.e. the `[gd_scene]` or `[gd_resource]` section.
+    pub fn header(&self) -> Option<&Section> {
+        self.sections.first()
+    }
+
+    /// Whether this is a scene (`.tscn`) rather than a resource (`.tres`).
+    pub fn is_scene(&self) -> bool {
+        self.header()
+            .map_or(false, |header| header.tag == "gd_scene")
+    }
+
+    /// All sections with the given tag, e.g. `"node"`.
+    pub fn sections_with_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a Section> + 'a {
+        self.sections
+            .iter()
+            .filter(move |section| section.tag == tag)
+    }
+
+    /// All `[ext_resource]` sections.
+    pub fn ext_resources(&self) -> impl Iterator<Item = &Section> {
+        self.sections_with_tag("ext_resource")
+    }
+
+    /// All `[sub_resource]` sections.
+    pub fn sub_resources(&self) -> impl Iterator<Item = &Section> {
+        self.sections_with_tag("sub_resource")
+    }
+
+    /// All `[node]` sections of a scene.
+    pub fn nodes(&self) -> impl Iterator<Item = &Section> {
+        self.sections_with_tag("node")
+    }
+
+    /// The `[resource]` section of a `.tres` file, holding the properties of the main resource.
+    pub fn resource(&self) -> Option<&Section> {
+        self.sections_with_tag("resource").next()
+    }
+
+    /// Mutable access to the `[resource]` section.
+    pub fn resource_mut(&mut self) -> Option<&mut Section> {
+        self.sections
+            .iter_mut()
+            .find(|section| section.tag == "resource")
+    }
+
+    /// Finds the `[sub_resource]` or `[ext_resource]` section referenced by `reference`.
+    pub fn resolve(&self, reference: &TextValue) -> Option<&Section> {
+        let (tag, id) = match reference {
+            TextValue::ExtResource(id) => ("ext_resource", id),
+            TextValue::SubResource(id) => ("sub_resource", id),
+            _ => return None,
+        };
+
+        self.sections_with_tag(tag)
+            .find(|section| section.attribute("id").and_then(TextValue::as_str) == Some(id))
+    }
+
+    /// Adds an `[ext_resource]` section referring to the file at `path`, and returns its ID.
+    ///
+    /// The section is inserted after the existing external resources. Use the ID with [`TextValue::ExtResource`].
+    pub fn add_ext_resource(&mut self, type_name: &str, path: &str) -> String {
+        let id = self.unused_id("ext_resource", "");
+
+        let mut section = Section::new("ext_resource");
+        section
+            .set_attribute("type", type_name)
+            .set_attribute("path", path)
+            .set_attribute("id", id.as_str());
+
+        let index = self.insertion_index(&["ext_resource"]);
+        self.sections.insert(index, section);
+        id
+    }
+
+    /// Adds a `[sub_resource]` section of class `type_name`, and returns it for setting properties.
+    ///
+    /// The section is inserted after the existing external and sub-resources. Its ID can be read via `attribute("id")` and used with
+    /// [`TextValue::SubResource`].
+    pub fn add_sub_resource(&mut self, type_name: &str) -> &mut Section {
+        let id = self.unused_id("sub_resource", &format!("{type_name}_"));
+
+        let mut section = Section::new("sub_resource");
+        section
+            .set_attribute("type", type_name)
+            .set_attribute("id", id.as_str());
+
+        let index = self.insertion_index(&["ext_resource", "sub_resource"]);
+        self.sections.insert(index, section);
+        &mut self.sections[index]
+    }
+
+    /// Adds a `[node]` section at the end of the scene, and returns it for setting properties.
+    ///
+    /// The root node has no `parent`; its direct children use `Some(".")`, and deeper nodes the path relative to the root, e.g.
+    /// `Some("Body/Arm")`. For instanced scenes, pass an empty `type_name` and set the `instance` attribute instead.
+    pub fn add_node(&mut self, name: &str, type_name: &str, parent: Option<&str>) -> &mut Section {
+        let mut section = Section::new("node");
+        section.set_attribute("name", name);
+
+        if !type_name.is_empty() {
+            section.set_attribute("type", type_name);
+        }
+        if let Some(parent) = parent {
+            section.set_attribute("parent", parent);
+        }
+
+        self.sections.push(section);
+        self.sections.last_mut().unwrap()
+    }
+
+    /// Updates the `load_steps` attribute of the header to the number of external and sub-resources, like Godot does on saving.
+    pub fn update_load_steps(&mut self) {
+        let steps = self.ext_resources().count() + self.sub_resources().count() + 1;
+
+        let Some(header) = self.sections.first_mut() else {
+            return;
+        };
+
+        if steps <= 1 {
+            header.remove_attribute("load_steps");
+        } else if let Some(existing) = find_mut(&mut header.attributes, "load_steps") {
+            *existing = TextValue::Int(steps as i64);
+        } else {
+            // Godot writes `load_steps` right before `format`.
+            let index = header
+                .attributes
+                .iter()
+                .position(|(key, _)| key == "format")
+                .unwrap_or(header.attributes.len());
+
+            let entry = ("load_steps".to_string(), TextValue::Int(steps as i64));
+            header.attributes.insert(index, entry);
+        }
+    }
+
+    fn unused_id(&self, tag: &str, prefix: &str) -> String {
+        let existing: Vec<&str> = self
+            .sections_with_tag(tag)
+            .filter_map(|section| section.attribute("id").and_then(TextValue::as_str))
+            .collect();
+
+        (1..)
+            .map(|n| format!("{prefix}{n}"))
+            .find(|id| !existing.contains(&id.as_str()))
+            .unwrap()
+    }
+
+    /// Index after the last section with one of the given tags, or after the header if there is none.
+    fn insertion_index(&self, tags: &[&str]) -> usize {
+        self.sections
+            .iter()
+            .rposition(|section| tags.contains(&section.tag.as_str()))
+            .map_or(self.sections.len().min(1), |index| index + 1)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Section of a text resource, such as `[node name="Player" type="CharacterBody3D" parent="."]` with the properties that follow it.
+///
+/// Attributes and properties are kept in file order.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Section {
+    /// Section tag, e.g. `node`.
+    pub tag: String,
+
+    /// Key-value pairs inside the brackets, e.g. `name="Player"`.
+    pub attributes: Vec<(String, TextValue)>,
+
+    /// Key-value pairs on the lines after the header, e.g. `position = Vector3(0, 1, 0)`.
+    pub properties: Vec<(String, TextValue)>,
+}
+
+impl Section {
+    /// Creates a section without attributes or properties.
+    pub fn new(tag: &str) -> Self {
+        Self {
+            tag: tag.to_string(),
+            attributes: Vec::new(),
+            properties: Vec::new(),
+        }
+    }
+
+    /// Returns the attribute with the given key, if present.
+    pub fn attribute(&self, key: &str) -> Option<&TextValue> {
+        find(&self.attributes, key)
+    }
+
+    /// Sets an attribute, replacing an existing one with the same key.
+    pub fn set_attribute(&mut self, key: &str, value: impl Into<TextValue>) -> &mut Self {
+        insert(&mut self.attributes, key, value.into());
+        self
+    }
+
+    /// Removes an attribute, returning its previous value.
+    pub fn remove_attribute(&mut self, key: &str) -> Option<TextValue> {
+        remove(&mut self.attributes, key)
+    }
+
+    /// Returns the property with the given name, if present.
+    pub fn property(&self, name: &str) -> Option<&TextValue> {
+        find(&self.properties, name)
+    }
+
+    /// Sets a property, replacing an existing one with the same name.
+    pub fn set_property(&mut self, name: &str, value: impl Into<TextValue>) -> &mut Self {
+        insert(&mut self.properties, name, value.into());
+        self
+    }
+
+    /// Removes a property, returning its previous value.
+    pub fn remove_property(&mut self, name: &str) -> Option<TextValue> {
+        remove(&mut self.properties, name)
+    }
+}
+
+fn find<'a>(entries: &'a [(String, TextValue)], key: &str) -> Option<&'a TextValue> {
+    entries
+        .iter()
+        .find(|(entry_key, _)| entry_key == key)
+        .map(|(_, value)| value)
+}
+
+fn find_mut<'a>(entries: &'a mut [(String, TextValue)], key: &str) -> Option<&'a mut TextValue> {
+    entries
+        .iter_mut()
+        .find(|(entry_key, _)| entry_key == key)
+        .map(|(_, value)| value)
+}
+
+fn insert(entries: &mut Vec<(String, TextValue)>, key: &str, value: TextValue) {
+    match find_mut(entries, key) {
+        Some(existing) => *existing = value,
+        None => entries.push((key.to_string(), value)),
+    }
+}
+
+fn remove(entries: &mut Vec<(String, TextValue)>, key: &str) -> Option<TextValue> {
+    let index = entries.iter().position(|(entry_key, _)| entry_key == key)?;
+    Some(entries.remove(index).1)
+}
+
+impl std::str::FromStr for TextValue {
+    type Err = ParseError;
+
+    /// Parses a single value in text resource syntax, e.g. `Vector3(1, 2, 3)` or `{ "key": [1, 2] }`.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parser::parse_value(s)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error while parsing a text resource.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ParseError {
+    line: usize,
+    message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// The 1-based line on which the error occurred.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Description of the error, without line information.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builtin::{Basis, Color, Transform3D, Vector2, Vector3};
+
+    const SCENE: &str = r#"[gd_scene load_steps=3 format=3 uid="uid://c4ghk1ep7ow3d"]
+
+[ext_resource type="Script" path="res://player.gd" id="1_x2kqm"]
+[ext_resource type="Texture2D" path="res://icon.svg" id="2_ab3cd"]
+
+[sub_resource type="BoxMesh" id="BoxMesh_q1w2e"]
+size = Vector3(2, 1, 0.5)
+
+[node name="Player" type="Node3D"]
+transform = Transform3D(-4.37114e-08, 0, 1, 0, 1, 0, -1, 0, -4.37114e-08, 3, 0, -1.5)
+script = ExtResource("1_x2kqm")
+speed = 4.5
+lives = 3
+title = "Hero \"One\""
+tags = Array[StringName]([&"player", &"alive"])
+; comment between properties
+metadata/stats = {
+"hp": 100,
+"color": Color(1, 0, 0, 1)
+}
+
+[node name="Mesh" type="MeshInstance3D" parent="."]
+mesh = SubResource("BoxMesh_q1w2e")
+target = NodePath("../Player")
+points = PackedVector2Array(0, 0, 1, 2.5)
+
+[connection signal="ready" from="." to="." method="_on_ready"]
+"#;
+
+    #[test]
+    fn parse_scene() {
+        let scene = TextResource::parse(SCENE).expect("parse");
+
+        assert!(scene.is_scene());
+        assert_eq!(scene.ext_resources().count(), 2);
+        assert_eq!(scene.sub_resources().count(), 1);
+        assert_eq!(scene.nodes().count(), 2);
+
+        let header = scene.header().unwrap();
+        assert_eq!(header.attribute("load_steps"), Some(&TextValue::Int(3)));
+        assert_eq!(
+            header.attribute("uid").and_then(TextValue::as_str),
+            Some("uid://c4ghk1ep7ow3d")
+        );
+
+        let player = scene.nodes().next().unwrap();
+        let expected_transform = Transform3D::new(
+            Basis::from_rows(
+                Vector3::new(-4.37114e-08, 0.0, 1.0),
+                Vector3::new(0.0, 1.0, 0.0),
+                Vector3::new(-1.0, 0.0, -4.37114e-08),
+            ),
+            Vector3::new(3.0, 0.0, -1.5),
+        );
+        assert_eq!(
+            player.property("transform"),
+            Some(&TextValue::Transform3D(expected_transform))
+        );
+        assert_eq!(player.property("speed"), Some(&TextValue::Float(4.5)));
+        assert_eq!(player.property("lives"), Some(&TextValue::Int(3)));
+        assert_eq!(
+            player.property("title").and_then(TextValue::as_str),
+            Some("Hero \"One\"")
+        );
+        assert_eq!(
+            player.property("tags"),
+            Some(&TextValue::TypedArray {
+                element_type: "StringName".to_string(),
+                elements: vec![
+                    TextValue::StringName("player".to_string()),
+                    TextValue::StringName("alive".to_string()),
+                ],
+            })
+        );
+        assert_eq!(
+            player.property("metadata/stats"),
+            Some(&TextValue::Dictionary(vec![
+                (TextValue::from("hp"), TextValue::Int(100)),
+                (
+                    TextValue::from("color"),
+                    TextValue::Color(Color::from_rgba(1.0, 0.0, 0.0, 1.0))
+                ),
+            ]))
+        );
+
+        let script = scene.resolve(player.property("script").unwrap()).unwrap();
+        assert_eq!(
+            script.attribute("path").and_then(TextValue::as_str),
+            Some("res://player.gd")
+        );
+
+        let mesh_node = scene.nodes().nth(1).unwrap();
+        let mesh = scene.resolve(mesh_node.property("mesh").unwrap()).unwrap();
+        assert_eq!(
+            mesh.property("size"),
+            Some(&TextValue::Vector3(Vector3::new(2.0, 1.0, 0.5)))
+        );
+        assert_eq!(
+            mesh_node.property("target"),
+            Some(&TextValue::NodePath("../Player".to_string()))
+        );
+        assert_eq!(
+            mesh_node.property("points"),
+            Some(&TextValue::PackedVector2Array(vec![
+                Vector2::new(0.0, 0.0),
+                Vector2::new(1.0, 2.5)
+            ]))
+        );
+    }
+
+    #[test]
+    fn write_and_reparse() {
+        let scene = TextResource::parse(SCENE).expect("parse");
+        let written = scene.to_string();
+
+        assert_eq!(TextResource::parse(&written).expect("reparse"), scene);
+        assert!(written.contains(
+            "[ext_resource type=\"Script\" path=\"res://player.gd\" id=\"1_x2kqm\"]\n[ext_resource"
+        ));
+        assert!(written.contains("speed = 4.5\n"));
+        assert!(written.contains("size = Vector3(2, 1, 0.5)\n"));
+    }
+
+    #[test]
+    fn build_resource() {
+        let mut resource = TextResource::new_resource("StandardMaterial3D");
+        let texture = resource.add_ext_resource("Texture2D", "res://albedo.png");
+        resource
+            .resource_mut()
+            .unwrap()
+            .set_property("albedo_color", Color::from_rgba(0.5, 0.25, 1.0, 1.0))
+            .set_property("albedo_texture", TextValue::ExtResource(texture))
+            .set_property("roughness", 1.0);
+        resource.update_load_steps();
+
+        let expected = r#"[gd_resource type="StandardMaterial3D" load_steps=2 format=3]
+
+[ext_resource type="Texture2D" path="res://albedo.png" id="1"]
+
+[resource]
+albedo_color = Color(0.5, 0.25, 1, 1)
+albedo_texture = ExtResource("1")
+roughness = 1.0
+"#;
+        assert_eq!(resource.to_string(), expected);
+    }
+
+    #[test]
+    fn parse_values() {
+        let parse = |s: &str| s.parse::<TextValue>().expect(s);
+
+        assert_eq!(parse("null"), TextValue::Nil);
+        assert_eq!(parse("-12"), TextValue::Int(-12));
+        assert_eq!(parse("1e-05"), TextValue::Float(1e-5));
+        assert_eq!(parse("inf_neg"), TextValue::Float(f64::NEG_INFINITY));
+        assert_eq!(parse("\"a\\nb\\u00e9\""), TextValue::from("a\nb\u{e9}"));
+        assert_eq!(
+            parse("PackedInt32Array(1, -2, 3)"),
+            TextValue::PackedInt32Array(vec![1, -2, 3])
+        );
+        assert_eq!(
+            parse(r#"Object(InputEventKey,"keycode":65,"pressed":true)"#),
+            TextValue::Object {
+                class_name: "InputEventKey".to_string(),
+                properties: vec![
+                    ("keycode".to_string(), TextValue::Int(65)),
+                    ("pressed".to_string(), TextValue::Bool(true)),
+                ],
+            }
+        );
+
+        let err = "Vector3(1, 2)".parse::<TextValue>().unwrap_err();
+        assert_eq!(err.line(), 1);
+        assert!(err.message().contains("expected 3 arguments"));
+
+        let err = TextResource::parse("[gd_scene format=3]\n\n[node name=\"A\"]\nvalue = Foo(1)\n")
+            .unwrap_err();
+        assert_eq!(err.line(), 4);
+    }
+}
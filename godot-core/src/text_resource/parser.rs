@@ -0,0 +1,650 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    real, Aabb, Basis, Color, Plane, Projection, Quaternion, RealConv, Rect2, Rect2i, Transform2D,
+    Transform3D, Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
+};
+
+use super::{ParseError, Section, TextValue};
+
+/// Parses the sections of a text resource.
+pub(super) fn parse_sections(source: &str) -> Result<Vec<Section>, ParseError> {
+    let mut parser = Parser::new(source);
+    let mut sections: Vec<Section> = Vec::new();
+
+    loop {
+        parser.skip_whitespace_and_comments();
+
+        match parser.peek() {
+            None => break,
+            Some('[') => sections.push(parser.parse_section_header()?),
+            Some(_) => {
+                let line = parser.line;
+                let (key, value) = parser.parse_property()?;
+
+                let Some(section) = sections.last_mut() else {
+                    return Err(ParseError::new(
+                        line,
+                        format!("property `{key}` appears before the first section"),
+                    ));
+                };
+                section.properties.push((key, value));
+            }
+        }
+    }
+
+    Ok(sections)
+}
+
+/// Parses a single value, e.g. `Vector3(1, 2, 3)`.
+pub(super) fn parse_value(source: &str) -> Result<TextValue, ParseError> {
+    let mut parser = Parser::new(source);
+    let value = parser.parse_value()?;
+
+    parser.skip_whitespace_and_comments();
+    match parser.peek() {
+        None => Ok(value),
+        Some(c) => Err(parser.error(format!("unexpected `{c}` after value"))),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            pos: 0,
+            line: 1,
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, message)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.source[self.pos..].chars().nth(1)
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(self.error(format!("expected `{expected}`, found `{c}`"))),
+            None => Err(self.error(format!("expected `{expected}`, found end of file"))),
+        }
+    }
+
+    /// Consumes `c` if it is the next non-whitespace character.
+    fn accept(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().map_or(false, char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(';') {
+                while self.peek().map_or(false, |c| c != '\n') {
+                    self.next();
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn take_while(&mut self, mut predicate: impl FnMut(char) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().map_or(false, &mut predicate) {
+            self.next();
+        }
+        &self.source[start..self.pos]
+    }
+
+    fn parse_identifier(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        let ident = self.take_while(|c| c.is_alphanumeric() || c == '_');
+        if ident.is_empty() {
+            match self.peek() {
+                Some(c) => Err(self.error(format!("expected identifier, found `{c}`"))),
+                None => Err(self.error("expected identifier, found end of file")),
+            }
+        } else {
+            Ok(ident)
+        }
+    }
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Sections and properties
+
+    fn parse_section_header(&mut self) -> Result<Section, ParseError> {
+        self.expect('[')?;
+        let mut section = Section::new(self.parse_identifier()?);
+
+        while !self.accept(']') {
+            let key = self.parse_identifier()?;
+            self.expect('=')?;
+            let value = self.parse_value()?;
+            section.attributes.push((key.to_string(), value));
+        }
+
+        Ok(section)
+    }
+
+    fn parse_property(&mut self) -> Result<(String, TextValue), ParseError> {
+        let key = if self.peek() == Some('"') {
+            self.parse_string()?
+        } else {
+            let key = self.take_while(|c| c != '=' && c != '\n').trim_end();
+            if key.is_empty() {
+                return Err(self.error("expected property name"));
+            }
+            key.to_string()
+        };
+
+        self.expect('=')?;
+        let value = self.parse_value()?;
+        Ok((key, value))
+    }
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Values
+
+    fn parse_value(&mut self) -> Result<TextValue, ParseError> {
+        self.skip_whitespace_and_comments();
+
+        match self.peek() {
+            None => Err(self.error("expected value, found end of file")),
+            Some('"') => Ok(TextValue::String(self.parse_string()?)),
+            Some('&') if self.peek_second() == Some('"') => {
+                self.next();
+                Ok(TextValue::StringName(self.parse_string()?))
+            }
+            Some('^') if self.peek_second() == Some('"') => {
+                self.next();
+                Ok(TextValue::NodePath(self.parse_string()?))
+            }
+            Some('[') => Ok(TextValue::Array(self.parse_array()?)),
+            Some('{') => self.parse_dictionary(),
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => {
+                self.parse_number()
+            }
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                let ident = self.parse_identifier()?;
+                self.parse_keyword_or_constructor(ident)
+            }
+            Some(c) => Err(self.error(format!("unexpected `{c}`, expected value"))),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut result = String::new();
+
+        loop {
+            match self.next() {
+                None => return Err(self.error("unterminated string")),
+                Some('"') => return Ok(result),
+                Some('\\') => {
+                    let escaped = match self.next() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('"') => '"',
+                        Some('\'') => '\'',
+                        Some('\\') => '\\',
+                        Some('u') => self.parse_unicode_escape(4)?,
+                        Some('U') => self.parse_unicode_escape(6)?,
+                        Some(c) => {
+                            return Err(self.error(format!("invalid escape sequence `\\{c}`")))
+                        }
+                        None => return Err(self.error("unterminated string")),
+                    };
+                    result.push(escaped);
+                }
+                Some(c) => result.push(c),
+            }
+        }
+    }
+
+    fn parse_unicode_escape(&mut self, digits: usize) -> Result<char, ParseError> {
+        let start = self.pos;
+        for _ in 0..digits {
+            match self.next() {
+                Some(c) if c.is_ascii_hexdigit() => {}
+                _ => return Err(self.error("invalid unicode escape sequence")),
+            }
+        }
+
+        u32::from_str_radix(&self.source[start..self.pos], 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error("invalid unicode code point"))
+    }
+
+    fn parse_number(&mut self) -> Result<TextValue, ParseError> {
+        let mut prev = ' ';
+        let text = self.take_while(|c| {
+            let accepted = c.is_ascii_alphanumeric()
+                || c == '.'
+                || ((c == '-' || c == '+') && (prev == ' ' || prev == 'e' || prev == 'E'));
+            prev = c;
+            accepted
+        });
+
+        let (sign, digits) = match text.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, text.strip_prefix('+').unwrap_or(text)),
+        };
+
+        if let Some(hex) = digits.strip_prefix("0x") {
+            return i64::from_str_radix(hex, 16)
+                .map(|value| TextValue::Int(sign * value))
+                .map_err(|_| self.error(format!("invalid hexadecimal number `{text}`")));
+        }
+
+        if digits.contains(['.', 'e', 'E']) {
+            text.parse::<f64>()
+                .map(TextValue::Float)
+                .map_err(|_| self.error(format!("invalid number `{text}`")))
+        } else {
+            // Integers too large for i64 can occur in float-only contexts, e.g. `Vector2(1e20, 0)` written without exponent.
+            text.parse::<i64>()
+                .map(TextValue::Int)
+                .or_else(|_| text.parse::<f64>().map(TextValue::Float))
+                .map_err(|_| self.error(format!("invalid number `{text}`")))
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Vec<TextValue>, ParseError> {
+        self.expect('[')?;
+        let mut elements = Vec::new();
+
+        loop {
+            self.skip_whitespace_and_comments();
+            if self.accept(']') {
+                return Ok(elements);
+            }
+
+            elements.push(self.parse_value()?);
+
+            self.skip_whitespace_and_comments();
+            if !self.accept(',') {
+                self.expect(']')?;
+                return Ok(elements);
+            }
+        }
+    }
+
+    fn parse_dictionary(&mut self) -> Result<TextValue, ParseError> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+
+        loop {
+            self.skip_whitespace_and_comments();
+            if self.accept('}') {
+                return Ok(TextValue::Dictionary(entries));
+            }
+
+            let key = self.parse_value()?;
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            entries.push((key, value));
+
+            self.skip_whitespace_and_comments();
+            if !self.accept(',') {
+                self.expect('}')?;
+                return Ok(TextValue::Dictionary(entries));
+            }
+        }
+    }
+
+    /// Parses the comma-separated arguments of a constructor, after the identifier.
+    fn parse_arguments(&mut self) -> Result<Vec<TextValue>, ParseError> {
+        self.expect('(')?;
+        let mut args = Vec::new();
+
+        loop {
+            self.skip_whitespace_and_comments();
+            if self.accept(')') {
+                return Ok(args);
+            }
+
+            args.push(self.parse_value()?);
+
+            if !self.accept(',') {
+                self.expect(')')?;
+                return Ok(args);
+            }
+        }
+    }
+
+    fn parse_keyword_or_constructor(&mut self, ident: &str) -> Result<TextValue, ParseError> {
+        let value = match ident {
+            "null" | "nil" => TextValue::Nil,
+            "true" => TextValue::Bool(true),
+            "false" => TextValue::Bool(false),
+            "inf" => TextValue::Float(f64::INFINITY),
+            "inf_neg" => TextValue::Float(f64::NEG_INFINITY),
+            "nan" => TextValue::Float(f64::NAN),
+
+            "Array" => self.parse_typed_array()?,
+            "Object" => self.parse_object()?,
+
+            _ => {
+                let args = self.parse_arguments()?;
+                construct(ident, args).map_err(|message| self.error(message))?
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn parse_typed_array(&mut self) -> Result<TextValue, ParseError> {
+        self.expect('[')?;
+        let element_type = self.take_while(|c| c != ']').trim().to_string();
+        self.expect(']')?;
+
+        self.expect('(')?;
+        let elements = self.parse_array()?;
+        self.expect(')')?;
+
+        Ok(TextValue::TypedArray {
+            element_type,
+            elements,
+        })
+    }
+
+    fn parse_object(&mut self) -> Result<TextValue, ParseError> {
+        self.expect('(')?;
+        let class_name = self.parse_identifier()?.to_string();
+        let mut properties = Vec::new();
+
+        while self.accept(',') {
+            self.skip_whitespace_and_comments();
+            let key = self.parse_string()?;
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            properties.push((key, value));
+        }
+        self.expect(')')?;
+
+        Ok(TextValue::Object {
+            class_name,
+            properties,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Constructors
+
+/// Builds a value from a constructor expression like `Vector3(1, 2, 3)`.
+fn construct(name: &str, args: Vec<TextValue>) -> Result<TextValue, String> {
+    let value = match name {
+        "NodePath" => TextValue::NodePath(single_string(name, args)?),
+        "StringName" => TextValue::StringName(single_string(name, args)?),
+        "ExtResource" => TextValue::ExtResource(single_string(name, args)?),
+        "SubResource" => TextValue::SubResource(single_string(name, args)?),
+
+        "Vector2" => {
+            let [x, y] = reals(name, &args)?;
+            TextValue::Vector2(Vector2::new(x, y))
+        }
+        "Vector2i" => {
+            let [x, y] = ints(name, &args)?;
+            TextValue::Vector2i(Vector2i::new(x, y))
+        }
+        "Rect2" => {
+            let [x, y, width, height] = reals(name, &args)?;
+            TextValue::Rect2(Rect2::from_components(x, y, width, height))
+        }
+        "Rect2i" => {
+            let [x, y, width, height] = ints(name, &args)?;
+            TextValue::Rect2i(Rect2i::from_components(x, y, width, height))
+        }
+        "Vector3" => {
+            let [x, y, z] = reals(name, &args)?;
+            TextValue::Vector3(Vector3::new(x, y, z))
+        }
+        "Vector3i" => {
+            let [x, y, z] = ints(name, &args)?;
+            TextValue::Vector3i(Vector3i::new(x, y, z))
+        }
+        "Transform2D" => {
+            let [ax, ay, bx, by, ox, oy] = reals(name, &args)?;
+            TextValue::Transform2D(Transform2D::from_cols(
+                Vector2::new(ax, ay),
+                Vector2::new(bx, by),
+                Vector2::new(ox, oy),
+            ))
+        }
+        "Vector4" => {
+            let [x, y, z, w] = reals(name, &args)?;
+            TextValue::Vector4(Vector4::new(x, y, z, w))
+        }
+        "Vector4i" => {
+            let [x, y, z, w] = ints(name, &args)?;
+            TextValue::Vector4i(Vector4i::new(x, y, z, w))
+        }
+        "Plane" => {
+            let [a, b, c, d] = reals(name, &args)?;
+            TextValue::Plane(Plane {
+                normal: Vector3::new(a, b, c),
+                d,
+            })
+        }
+        "Quaternion" => {
+            let [x, y, z, w] = reals(name, &args)?;
+            TextValue::Quaternion(Quaternion::new(x, y, z, w))
+        }
+        "AABB" => {
+            let [px, py, pz, sx, sy, sz] = reals(name, &args)?;
+            TextValue::Aabb(Aabb::new(
+                Vector3::new(px, py, pz),
+                Vector3::new(sx, sy, sz),
+            ))
+        }
+        "Basis" => {
+            let values: [real; 9] = reals(name, &args)?;
+            TextValue::Basis(basis_from_rows(&values))
+        }
+        "Transform3D" => {
+            let values: [real; 12] = reals(name, &args)?;
+            let origin = Vector3::new(values[9], values[10], values[11]);
+            TextValue::Transform3D(Transform3D::new(basis_from_rows(&values[..9]), origin))
+        }
+        "Projection" => {
+            let v: [real; 16] = reals(name, &args)?;
+            TextValue::Projection(Projection::from_cols(
+                Vector4::new(v[0], v[1], v[2], v[3]),
+                Vector4::new(v[4], v[5], v[6], v[7]),
+                Vector4::new(v[8], v[9], v[10], v[11]),
+                Vector4::new(v[12], v[13], v[14], v[15]),
+            ))
+        }
+        "Color" => {
+            let [r, g, b, a] = floats::<4>(name, &args)?.map(|c| c as f32);
+            TextValue::Color(Color::from_rgba(r, g, b, a))
+        }
+
+        "PackedByteArray" => TextValue::PackedByteArray(packed_ints(name, &args)?),
+        "PackedInt32Array" => TextValue::PackedInt32Array(packed_ints(name, &args)?),
+        "PackedInt64Array" => TextValue::PackedInt64Array(packed_ints(name, &args)?),
+        "PackedFloat32Array" => TextValue::PackedFloat32Array(
+            packed_floats(name, &args)?
+                .into_iter()
+                .map(|f| f as f32)
+                .collect(),
+        ),
+        "PackedFloat64Array" => TextValue::PackedFloat64Array(packed_floats(name, &args)?),
+        "PackedStringArray" => {
+            let strings = args
+                .into_iter()
+                .map(|arg| match arg {
+                    TextValue::String(s) => Ok(s),
+                    other => Err(format!("{name}: expected string, found {other:?}")),
+                })
+                .collect::<Result<_, _>>()?;
+            TextValue::PackedStringArray(strings)
+        }
+        "PackedVector2Array" => {
+            let components = packed_reals(name, &args, 2)?;
+            TextValue::PackedVector2Array(
+                components
+                    .chunks_exact(2)
+                    .map(|c| Vector2::new(c[0], c[1]))
+                    .collect(),
+            )
+        }
+        "PackedVector3Array" => {
+            let components = packed_reals(name, &args, 3)?;
+            TextValue::PackedVector3Array(
+                components
+                    .chunks_exact(3)
+                    .map(|c| Vector3::new(c[0], c[1], c[2]))
+                    .collect(),
+            )
+        }
+        "PackedColorArray" => {
+            let components = packed_floats(name, &args)?;
+            if components.len() % 4 != 0 {
+                return Err(format!(
+                    "{name}: number of components must be a multiple of 4"
+                ));
+            }
+            TextValue::PackedColorArray(
+                components
+                    .chunks_exact(4)
+                    .map(|c| Color::from_rgba(c[0] as f32, c[1] as f32, c[2] as f32, c[3] as f32))
+                    .collect(),
+            )
+        }
+
+        _ => return Err(format!("unknown constructor `{name}`")),
+    };
+
+    Ok(value)
+}
+
+/// Godot stores basis matrices row by row.
+fn basis_from_rows(values: &[real]) -> Basis {
+    Basis::from_rows(
+        Vector3::new(values[0], values[1], values[2]),
+        Vector3::new(values[3], values[4], values[5]),
+        Vector3::new(values[6], values[7], values[8]),
+    )
+}
+
+fn single_string(name: &str, args: Vec<TextValue>) -> Result<String, String> {
+    match <[TextValue; 1]>::try_from(args) {
+        Ok([TextValue::String(s)]) => Ok(s),
+        _ => Err(format!("{name}: expected a single string argument")),
+    }
+}
+
+fn to_float(name: &str, arg: &TextValue) -> Result<f64, String> {
+    arg.as_float()
+        .ok_or_else(|| format!("{name}: expected number, found {arg:?}"))
+}
+
+fn to_int<T: TryFrom<i64>>(name: &str, arg: &TextValue) -> Result<T, String> {
+    let int = arg
+        .as_int()
+        .ok_or_else(|| format!("{name}: expected integer, found {arg:?}"))?;
+
+    T::try_from(int).map_err(|_| format!("{name}: integer {int} out of range"))
+}
+
+fn check_count(name: &str, args: &[TextValue], expected: usize) -> Result<(), String> {
+    if args.len() == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "{name}: expected {expected} arguments, found {}",
+            args.len()
+        ))
+    }
+}
+
+fn floats<const N: usize>(name: &str, args: &[TextValue]) -> Result<[f64; N], String> {
+    check_count(name, args, N)?;
+
+    let mut result = [0.0; N];
+    for (slot, arg) in result.iter_mut().zip(args) {
+        *slot = to_float(name, arg)?;
+    }
+    Ok(result)
+}
+
+fn reals<const N: usize>(name: &str, args: &[TextValue]) -> Result<[real; N], String> {
+    floats::<N>(name, args).map(|values| values.map(real::from_f64))
+}
+
+fn ints<const N: usize>(name: &str, args: &[TextValue]) -> Result<[i32; N], String> {
+    check_count(name, args, N)?;
+
+    let mut result = [0; N];
+    for (slot, arg) in result.iter_mut().zip(args) {
+        *slot = to_int(name, arg)?;
+    }
+    Ok(result)
+}
+
+fn packed_ints<T: TryFrom<i64>>(name: &str, args: &[TextValue]) -> Result<Vec<T>, String> {
+    args.iter().map(|arg| to_int(name, arg)).collect()
+}
+
+fn packed_floats(name: &str, args: &[TextValue]) -> Result<Vec<f64>, String> {
+    args.iter().map(|arg| to_float(name, arg)).collect()
+}
+
+fn packed_reals(name: &str, args: &[TextValue], dimension: usize) -> Result<Vec<real>, String> {
+    if args.len() % dimension != 0 {
+        return Err(format!(
+            "{name}: number of components must be a multiple of {dimension}"
+        ));
+    }
+
+    args.iter()
+        .map(|arg| to_float(name, arg).map(real::from_f64))
+        .collect()
+}
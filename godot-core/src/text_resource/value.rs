@@ -0,0 +1,156 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    Aabb, Basis, Color, Plane, Projection, Quaternion, Rect2, Rect2i, Transform2D, Transform3D,
+    Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
+};
+
+/// Value of a property or section attribute in a text resource.
+///
+/// Mirrors the variant types that Godot can store in `.tres`/`.tscn` files, but is implemented purely in Rust. Engine-backed types
+/// like `GString`, `NodePath`, `Array` or `Dictionary` are represented with their Rust counterparts, so that values can be created
+/// and inspected without a running engine.
+#[derive(Clone, PartialEq, Debug)]
+pub enum TextValue {
+    /// `null`
+    Nil,
+    /// `true` or `false`
+    Bool(bool),
+    /// Integer, e.g. `42`
+    Int(i64),
+    /// Float, e.g. `0.5`, `inf` or `nan`
+    Float(f64),
+    /// String, e.g. `"text"`
+    String(String),
+    /// String name, e.g. `&"name"`
+    StringName(String),
+    /// Node path, e.g. `NodePath("Path/To:property")`
+    NodePath(String),
+
+    Vector2(Vector2),
+    Vector2i(Vector2i),
+    Rect2(Rect2),
+    Rect2i(Rect2i),
+    Vector3(Vector3),
+    Vector3i(Vector3i),
+    Transform2D(Transform2D),
+    Vector4(Vector4),
+    Vector4i(Vector4i),
+    Plane(Plane),
+    Quaternion(Quaternion),
+    Aabb(Aabb),
+    Basis(Basis),
+    Transform3D(Transform3D),
+    Projection(Projection),
+    Color(Color),
+
+    /// Untyped array, e.g. `[1, "two"]`
+    Array(Vec<TextValue>),
+    /// Typed array, e.g. `Array[int]([1, 2])`.
+    ///
+    /// `element_type` is the type as written in the file, e.g. `int`, `StringName` or `ExtResource("1_ab2cd")`.
+    TypedArray {
+        element_type: String,
+        elements: Vec<TextValue>,
+    },
+    /// Dictionary, e.g. `{ "key": 1 }`. Entries are kept in file order.
+    Dictionary(Vec<(TextValue, TextValue)>),
+
+    PackedByteArray(Vec<u8>),
+    PackedInt32Array(Vec<i32>),
+    PackedInt64Array(Vec<i64>),
+    PackedFloat32Array(Vec<f32>),
+    PackedFloat64Array(Vec<f64>),
+    PackedStringArray(Vec<String>),
+    PackedVector2Array(Vec<Vector2>),
+    PackedVector3Array(Vec<Vector3>),
+    PackedColorArray(Vec<Color>),
+
+    /// Reference to an `[ext_resource]` section by its ID, e.g. `ExtResource("1_ab2cd")`
+    ExtResource(String),
+    /// Reference to a `[sub_resource]` section by its ID, e.g. `SubResource("BoxMesh_x1y2z")`
+    SubResource(String),
+    /// Inline object, e.g. `Object(InputEventKey, "keycode": 65)`
+    Object {
+        class_name: String,
+        properties: Vec<(String, TextValue)>,
+    },
+}
+
+impl TextValue {
+    /// Returns the value as `bool`, if it is one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as `i64`, if it is an integer.
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as `f64`, if it is a float or an integer.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(value) => Some(*value),
+            Self::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the contained text, if the value is a string, string name or node path.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) | Self::StringName(value) | Self::NodePath(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_from_for_text_value {
+    ($($Type:ty => $Enumerator:ident),* $(,)?) => {
+        $(
+            impl From<$Type> for TextValue {
+                fn from(value: $Type) -> Self {
+                    Self::$Enumerator(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_text_value!(
+    bool => Bool,
+    i32 => Int,
+    i64 => Int,
+    f32 => Float,
+    f64 => Float,
+    String => String,
+    &str => String,
+    Vector2 => Vector2,
+    Vector2i => Vector2i,
+    Rect2 => Rect2,
+    Rect2i => Rect2i,
+    Vector3 => Vector3,
+    Vector3i => Vector3i,
+    Transform2D => Transform2D,
+    Vector4 => Vector4,
+    Vector4i => Vector4i,
+    Plane => Plane,
+    Quaternion => Quaternion,
+    Aabb => Aabb,
+    Basis => Basis,
+    Transform3D => Transform3D,
+    Projection => Projection,
+    Color => Color,
+    Vec<TextValue> => Array,
+);
@@ -0,0 +1,310 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{self, Display, Write};
+
+use crate::builtin::real;
+
+use super::{Section, TextResource, TextValue};
+
+impl Display for TextResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut prev_tag: Option<&str> = None;
+
+        for section in &self.sections {
+            // Godot groups consecutive [ext_resource] lines without blank lines in between.
+            let grouped = section.tag == "ext_resource" && prev_tag == Some("ext_resource");
+            if prev_tag.is_some() && !grouped {
+                f.write_char('\n')?;
+            }
+
+            write!(f, "{section}")?;
+            prev_tag = Some(&section.tag);
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}", self.tag)?;
+        for (key, value) in &self.attributes {
+            write!(f, " {key}={value}")?;
+        }
+        f.write_str("]\n")?;
+
+        for (key, value) in &self.properties {
+            if is_plain_key(key) {
+                writeln!(f, "{key} = {value}")?;
+            } else {
+                write_string(f, key)?;
+                writeln!(f, " = {value}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for TextValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nil => f.write_str("null"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write_float(f, *value),
+            Self::String(value) => write_string(f, value),
+            Self::StringName(value) => {
+                f.write_char('&')?;
+                write_string(f, value)
+            }
+            Self::NodePath(value) => write_constructor(f, "NodePath", |f| write_string(f, value)),
+
+            Self::Vector2(v) => write_reals(f, "Vector2", &[v.x, v.y]),
+            Self::Vector2i(v) => write_ints(f, "Vector2i", &[v.x, v.y]),
+            Self::Rect2(r) => write_reals(
+                f,
+                "Rect2",
+                &[r.position.x, r.position.y, r.size.x, r.size.y],
+            ),
+            Self::Rect2i(r) => write_ints(
+                f,
+                "Rect2i",
+                &[r.position.x, r.position.y, r.size.x, r.size.y],
+            ),
+            Self::Vector3(v) => write_reals(f, "Vector3", &[v.x, v.y, v.z]),
+            Self::Vector3i(v) => write_ints(f, "Vector3i", &[v.x, v.y, v.z]),
+            Self::Transform2D(t) => write_reals(
+                f,
+                "Transform2D",
+                &[t.a.x, t.a.y, t.b.x, t.b.y, t.origin.x, t.origin.y],
+            ),
+            Self::Vector4(v) => write_reals(f, "Vector4", &[v.x, v.y, v.z, v.w]),
+            Self::Vector4i(v) => write_ints(f, "Vector4i", &[v.x, v.y, v.z, v.w]),
+            Self::Plane(p) => write_reals(f, "Plane", &[p.normal.x, p.normal.y, p.normal.z, p.d]),
+            Self::Quaternion(q) => write_reals(f, "Quaternion", &[q.x, q.y, q.z, q.w]),
+            Self::Aabb(b) => write_reals(
+                f,
+                "AABB",
+                &[
+                    b.position.x,
+                    b.position.y,
+                    b.position.z,
+                    b.size.x,
+                    b.size.y,
+                    b.size.z,
+                ],
+            ),
+            Self::Basis(b) => {
+                let rows = b.rows;
+                write_reals(
+                    f,
+                    "Basis",
+                    &[
+                        rows[0].x, rows[0].y, rows[0].z, //
+                        rows[1].x, rows[1].y, rows[1].z, //
+                        rows[2].x, rows[2].y, rows[2].z,
+                    ],
+                )
+            }
+            Self::Transform3D(t) => {
+                let rows = t.basis.rows;
+                write_reals(
+                    f,
+                    "Transform3D",
+                    &[
+                        rows[0].x, rows[0].y, rows[0].z, //
+                        rows[1].x, rows[1].y, rows[1].z, //
+                        rows[2].x, rows[2].y, rows[2].z, //
+                        t.origin.x, t.origin.y, t.origin.z,
+                    ],
+                )
+            }
+            Self::Projection(p) => {
+                let components: Vec<real> = p
+                    .cols
+                    .iter()
+                    .flat_map(|col| [col.x, col.y, col.z, col.w])
+                    .collect();
+                write_reals(f, "Projection", &components)
+            }
+            Self::Color(c) => write_list(f, "Color", &[c.r, c.g, c.b, c.a], |f, c| {
+                write_component(f, *c)
+            }),
+
+            Self::Array(elements) => write_array(f, elements),
+            Self::TypedArray {
+                element_type,
+                elements,
+            } => {
+                write!(f, "Array[{element_type}](")?;
+                write_array(f, elements)?;
+                f.write_char(')')
+            }
+            Self::Dictionary(entries) => {
+                if entries.is_empty() {
+                    return f.write_str("{}");
+                }
+
+                f.write_str("{\n")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(",\n")?;
+                    }
+                    write!(f, "{key}: {value}")?;
+                }
+                f.write_str("\n}")
+            }
+
+            Self::PackedByteArray(values) => write_ints(f, "PackedByteArray", values),
+            Self::PackedInt32Array(values) => write_ints(f, "PackedInt32Array", values),
+            Self::PackedInt64Array(values) => write_ints(f, "PackedInt64Array", values),
+            Self::PackedFloat32Array(values) => {
+                write_list(f, "PackedFloat32Array", values, |f, v| {
+                    write_component(f, *v)
+                })
+            }
+            Self::PackedFloat64Array(values) => {
+                write_list(f, "PackedFloat64Array", values, |f, v| {
+                    write_component(f, *v)
+                })
+            }
+            Self::PackedStringArray(values) => {
+                write_list(f, "PackedStringArray", values, |f, s| write_string(f, s))
+            }
+            Self::PackedVector2Array(values) => {
+                let components: Vec<real> = values.iter().flat_map(|v| [v.x, v.y]).collect();
+                write_reals(f, "PackedVector2Array", &components)
+            }
+            Self::PackedVector3Array(values) => {
+                let components: Vec<real> = values.iter().flat_map(|v| [v.x, v.y, v.z]).collect();
+                write_reals(f, "PackedVector3Array", &components)
+            }
+            Self::PackedColorArray(values) => {
+                let components: Vec<f32> =
+                    values.iter().flat_map(|c| [c.r, c.g, c.b, c.a]).collect();
+                write_list(f, "PackedColorArray", &components, |f, c| {
+                    write_component(f, *c)
+                })
+            }
+
+            Self::ExtResource(id) => write_constructor(f, "ExtResource", |f| write_string(f, id)),
+            Self::SubResource(id) => write_constructor(f, "SubResource", |f| write_string(f, id)),
+            Self::Object {
+                class_name,
+                properties,
+            } => {
+                write!(f, "Object({class_name}")?;
+                for (key, value) in properties {
+                    f.write_char(',')?;
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char(')')
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Whether a property name can be written without quotes.
+fn is_plain_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '/' | ':' | '.' | '-'))
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            _ => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// Writes a standalone float, which always carries a decimal point so it is read back as float.
+fn write_float(f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+    if !value.is_finite() {
+        return write_component(f, value);
+    }
+
+    let text = value.to_string();
+    if text.contains(['.', 'e']) {
+        f.write_str(&text)
+    } else {
+        write!(f, "{text}.0")
+    }
+}
+
+/// Writes a float inside a constructor, where integral values are written without decimal point.
+fn write_component<T>(f: &mut fmt::Formatter<'_>, value: T) -> fmt::Result
+where
+    T: Copy + Display + Into<f64>,
+{
+    let as_f64: f64 = value.into();
+    if as_f64.is_nan() {
+        f.write_str("nan")
+    } else if as_f64 == f64::INFINITY {
+        f.write_str("inf")
+    } else if as_f64 == f64::NEG_INFINITY {
+        f.write_str("inf_neg")
+    } else {
+        write!(f, "{value}")
+    }
+}
+
+fn write_constructor(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    write_args: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    write!(f, "{name}(")?;
+    write_args(f)?;
+    f.write_char(')')
+}
+
+fn write_list<T>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    values: &[T],
+    mut write_value: impl FnMut(&mut fmt::Formatter<'_>, &T) -> fmt::Result,
+) -> fmt::Result {
+    write_constructor(f, name, |f| {
+        for (i, value) in values.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            write_value(f, value)?;
+        }
+        Ok(())
+    })
+}
+
+fn write_reals(f: &mut fmt::Formatter<'_>, name: &str, values: &[real]) -> fmt::Result {
+    write_list(f, name, values, |f, v| write_component(f, *v))
+}
+
+fn write_ints<T: Display>(f: &mut fmt::Formatter<'_>, name: &str, values: &[T]) -> fmt::Result {
+    write_list(f, name, values, |f, v| write!(f, "{v}"))
+}
+
+fn write_array(f: &mut fmt::Formatter<'_>, elements: &[TextValue]) -> fmt::Result {
+    f.write_char('[')?;
+    for (i, element) in elements.iter().enumerate() {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{element}")?;
+    }
+    f.write_char(']')
+}
@@ -5,6 +5,7 @@
  */
 
 use crate::class::{FieldExport, FieldVar};
+use crate::{docs, util};
 use proc_macro2::{Ident, TokenStream};
 
 pub struct Field {
@@ -13,6 +14,12 @@ pub struct Field {
     pub default: Option<TokenStream>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+    /// Whether the field has a `#[hot_reload]` attribute.
+    pub is_hot_reload: bool,
+    /// Whether the field is of type `OnReady<T>`, initialized before `ready()`.
+    pub is_onready: bool,
+    /// The field's `///` doc comment, shown in the editor help for properties.
+    pub doc: Option<String>,
 }
 
 impl Field {
@@ -23,6 +30,9 @@ impl Field {
             default: None,
             var: None,
             export: None,
+            is_hot_reload: false,
+            is_onready: util::is_onready_type(&field.ty),
+            doc: docs::extract_doc_comment(&field.attributes),
         }
     }
 }
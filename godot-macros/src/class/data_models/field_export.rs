@@ -31,7 +31,7 @@ pub enum FieldExport {
     Range {
         min: TokenStream,
         max: TokenStream,
-        step: TokenStream,
+        step: Option<TokenStream>,
         or_greater: bool,
         or_less: bool,
         exp: bool,
@@ -269,11 +269,8 @@ impl FieldExport {
         // TODO: During parser refactor, try to remove the need for `is_next_ident` there. Currently needed only for this functionality.
         // See discussion for rationale here: https://github.com/godot-rust/gdext/pull/484#pullrequestreview-1738612069
         let step = match parser.is_next_ident() {
-            Some(false) => {
-                let value = parser.next_expr()?;
-                quote! { Some(#value) }
-            }
-            _ => quote! { None },
+            Some(false) => Some(parser.next_expr()?),
+            _ => None,
         };
 
         let mut options = HashSet::new();
@@ -378,9 +375,16 @@ impl FieldExport {
                 radians,
                 degrees,
                 hide_slider,
-            } => quote_export_func! {
-                export_range(#min, #max, #step, #or_greater, #or_less, #exp, #radians, #degrees, #hide_slider)
-            },
+            } => {
+                let step = match step {
+                    Some(step) => quote! { Some(#step) },
+                    None => quote! { None },
+                };
+
+                quote_export_func! {
+                    export_range(#min, #max, #step, #or_greater, #or_less, #exp, #radians, #degrees, #hide_slider)
+                }
+            }
 
             FieldExport::Enum { variants } => {
                 let variants = variants.iter().map(ValueWithKey::to_tuple_expression);
@@ -202,6 +202,8 @@ impl GetterSetterImpl {
                 external_attributes: Vec::new(),
                 rename: None,
                 has_gd_self: false,
+                param_defaults: Vec::new(),
+                on_error: None,
             },
         );
 
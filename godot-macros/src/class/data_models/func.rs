@@ -17,6 +17,10 @@ pub struct FuncDefinition {
     /// The name the function will be exposed as in Godot. If `None`, the Rust function name is used.
     pub rename: Option<String>,
     pub has_gd_self: bool,
+    /// Default value expressions from `#[opt(default = ...)]`, one entry per parameter (excluding receiver and `gd_self`).
+    pub param_defaults: Vec<Option<TokenStream>>,
+    /// Maps the `Err` value of a `Result`-returning method to a `Variant`, from `#[func(on_error = ...)]`.
+    pub on_error: Option<TokenStream>,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -34,7 +38,18 @@ pub fn make_virtual_method_callback(
     let sig_tuple =
         util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
 
-    let invocation = make_ptrcall_invocation(method_name, &sig_tuple, &wrapped_method, true);
+    let mut invocation = make_ptrcall_invocation(method_name, &sig_tuple, &wrapped_method, true);
+    let call_info = make_ptrcall_info(class_name, method_name);
+
+    // OnReady fields are initialized right before the user's ready().
+    if method_name == "ready" {
+        invocation = quote! {
+            {
+                ::godot::private::callbacks::before_ready::<#class_name>(instance_ptr);
+                #invocation
+            }
+        };
+    }
 
     quote! {
         {
@@ -45,7 +60,11 @@ pub fn make_virtual_method_callback(
                 args_ptr: *const sys::GDExtensionConstTypePtr,
                 ret: sys::GDExtensionTypePtr,
             ) {
-                #invocation;
+                // Virtual calls have no means to report errors; a panic is reported and the return value left untouched.
+                let _ = ::godot::private::handle_call_panic(
+                    || #call_info,
+                    || #invocation
+                );
             }
             Some(function)
         }
@@ -57,19 +76,52 @@ pub fn make_method_registration(
     class_name: &Ident,
     func_definition: FuncDefinition,
 ) -> TokenStream {
-    let signature_info = get_signature_info(&func_definition.func, func_definition.has_gd_self);
+    let mut signature_info = get_signature_info(&func_definition.func, func_definition.has_gd_self);
+    split_varargs(&mut signature_info);
+    let has_varargs = signature_info.varargs_ident.is_some();
+
+    // Fallible methods are registered as returning `Variant`, so that errors can be mapped to nil or a custom value.
+    if func_definition
+        .func
+        .return_ty
+        .as_ref()
+        .is_some_and(util::is_result_type)
+    {
+        let on_error = func_definition.on_error.clone().unwrap_or_else(|| {
+            quote! { |_| ::godot::builtin::Variant::nil() }
+        });
+
+        signature_info.ret_type = quote! { ::godot::builtin::Variant };
+        signature_info.result_on_error = Some(on_error);
+    }
+
     let sig_tuple =
         util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
 
     let method_name = &signature_info.method_name;
     let param_idents = &signature_info.param_idents;
 
-    let method_flags = make_method_flags(signature_info.receiver_type);
+    let method_flags = make_method_flags(signature_info.receiver_type, has_varargs);
 
     let forwarding_closure = make_forwarding_closure(class_name, &signature_info);
 
-    let varcall_func = make_varcall_func(method_name, &sig_tuple, &forwarding_closure);
-    let ptrcall_func = make_ptrcall_func(method_name, &sig_tuple, &forwarding_closure);
+    let default_args = make_default_args(&signature_info, &func_definition.param_defaults);
+    let varcall_func = make_varcall_func(
+        class_name,
+        method_name,
+        &sig_tuple,
+        &forwarding_closure,
+        has_varargs,
+    );
+
+    // Godot never ptrcalls vararg methods.
+    let ptrcall_func = if has_varargs {
+        quote! { None }
+    } else {
+        let ptrcall_func =
+            make_ptrcall_func(class_name, method_name, &sig_tuple, &forwarding_closure);
+        quote! { Some(#ptrcall_func) }
+    };
 
     // String literals
     let class_name_str = class_name.to_string();
@@ -96,6 +148,10 @@ pub fn make_method_registration(
 
             type Sig = #sig_tuple;
 
+            fn default_args() -> Vec<Variant> {
+                #default_args
+            }
+
             let method_name = StringName::from(#method_name_str);
 
             let varcall_func = #varcall_func;
@@ -109,12 +165,12 @@ pub fn make_method_registration(
                 #class_name::class_name(),
                 method_name,
                 Some(varcall_func),
-                Some(ptrcall_func),
+                ptrcall_func,
                 #method_flags,
                 &[
                     #( #param_ident_strs ),*
                 ],
-                Vec::new()
+                default_args()
                 )
             };
 
@@ -146,20 +202,62 @@ struct SignatureInfo {
     pub receiver_type: ReceiverType,
     pub param_idents: Vec<Ident>,
     pub param_types: Vec<venial::TyExpr>,
+    /// Name of the trailing `&[Variant]` parameter, if the method accepts varargs.
+    pub varargs_ident: Option<Ident>,
     pub ret_type: TokenStream,
+    /// For methods returning `Result`: expression of type `fn(&E) -> Variant`, invoked on errors.
+    pub result_on_error: Option<TokenStream>,
 }
 
 /// Returns a closure expression that forwards the parameters to the Rust instance.
 fn make_forwarding_closure(class_name: &Ident, signature_info: &SignatureInfo) -> TokenStream {
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
+    let varargs = signature_info
+        .varargs_ident
+        .as_ref()
+        .map(|ident| quote! { , #ident });
+    let call_args = params.iter().chain(&signature_info.varargs_ident);
+
+    let call = match &signature_info.receiver_type {
+        ReceiverType::Ref | ReceiverType::Mut => quote! {
+            instance.#method_name(#(#call_args),*)
+        },
+        ReceiverType::GdSelf => quote! {
+            <#class_name>::#method_name(storage.get_gd(), #(#call_args),*)
+        },
+        ReceiverType::Static => quote! {
+            <#class_name>::#method_name(#(#call_args),*)
+        },
+    };
+
+    let call_result = match &signature_info.result_on_error {
+        Some(on_error) => {
+            let method_path = format!("{class_name}::{method_name}");
+            quote! {
+                ::godot::private::func_result_to_variant(
+                    #call,
+                    #method_path,
+                    |err| {
+                        use ::godot::private::{FuncErrorChain as _, FuncErrorDisplay as _};
+                        (&&::godot::private::FuncError(err)).describe()
+                    },
+                    #on_error,
+                )
+            }
+        }
+        None => call,
+    };
 
+    // Shown in borrow errors, e.g. when a signal emitted by this method calls back into the same instance.
+    let borrow_context = method_name.to_string();
     let instance_decl = match &signature_info.receiver_type {
         ReceiverType::Ref => quote! {
-            let instance = storage.get();
+            let instance = storage.get_for(::godot::private::BorrowContext::Static(#borrow_context));
         },
         ReceiverType::Mut => quote! {
-            let mut instance = storage.get_mut();
+            let mut instance =
+                storage.get_mut_for(::godot::private::BorrowContext::Static(#borrow_context));
         },
         _ => quote! {},
     };
@@ -167,34 +265,34 @@ fn make_forwarding_closure(class_name: &Ident, signature_info: &SignatureInfo) -
     match signature_info.receiver_type {
         ReceiverType::Ref | ReceiverType::Mut => {
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #varargs| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
                     #instance_decl
 
-                    instance.#method_name(#(#params),*)
+                    #call_result
                 }
             }
         }
         ReceiverType::GdSelf => {
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #varargs| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
-                    <#class_name>::#method_name(storage.get_gd(), #(#params),*)
+                    #call_result
                 }
             }
         }
         ReceiverType::Static => {
             quote! {
-                |_, params| {
+                |_, params #varargs| {
                     let ( #(#params,)* ) = params;
-                    <#class_name>::#method_name(#(#params),*)
+                    #call_result
                 }
             }
         }
@@ -254,28 +352,74 @@ fn get_signature_info(signature: &venial::Function, has_gd_self: bool) -> Signat
         receiver_type,
         param_idents,
         param_types,
+        varargs_ident: None,
         ret_type,
+        result_on_error: None,
     }
 }
 
-fn make_method_flags(method_type: ReceiverType) -> TokenStream {
-    match method_type {
+/// If the last parameter is `&[Variant]`, removes it from the regular parameters and treats it as varargs.
+fn split_varargs(signature_info: &mut SignatureInfo) {
+    if signature_info
+        .param_types
+        .last()
+        .is_some_and(util::is_varargs_type)
+    {
+        signature_info.param_types.pop();
+        signature_info.varargs_ident = signature_info.param_idents.pop();
+    }
+}
+
+/// Returns an expression evaluating to a `Vec<Variant>` with the default values of the trailing parameters.
+fn make_default_args(
+    signature_info: &SignatureInfo,
+    param_defaults: &[Option<TokenStream>],
+) -> TokenStream {
+    let default_values = signature_info
+        .param_types
+        .iter()
+        .zip(param_defaults)
+        .filter_map(|(ty, default)| {
+            let default = default.as_ref()?;
+            Some(quote! {
+                {
+                    let value: #ty = #default;
+                    ::godot::builtin::meta::ToGodot::to_variant(&value)
+                }
+            })
+        });
+
+    quote! {
+        vec![ #( #default_values ),* ]
+    }
+}
+
+fn make_method_flags(method_type: ReceiverType, has_varargs: bool) -> TokenStream {
+    let flags = match method_type {
         ReceiverType::Ref | ReceiverType::Mut | ReceiverType::GdSelf => {
             quote! { ::godot::engine::global::MethodFlags::METHOD_FLAGS_DEFAULT }
         }
         ReceiverType::Static => {
             quote! { ::godot::engine::global::MethodFlags::METHOD_FLAG_STATIC }
         }
+    };
+
+    if has_varargs {
+        quote! { #flags | ::godot::engine::global::MethodFlags::METHOD_FLAG_VARARG }
+    } else {
+        flags
     }
 }
 
 /// Generate code for a C FFI function that performs a varcall.
 fn make_varcall_func(
+    class_name: &Ident,
     method_name: &Ident,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
+    has_varargs: bool,
 ) -> TokenStream {
-    let invocation = make_varcall_invocation(method_name, sig_tuple, wrapped_method);
+    let invocation = make_varcall_invocation(method_name, sig_tuple, wrapped_method, has_varargs);
     let method_name_str = method_name.to_string();
 
     quote! {
@@ -284,12 +428,17 @@ fn make_varcall_func(
                 _method_data: *mut std::ffi::c_void,
                 instance_ptr: sys::GDExtensionClassInstancePtr,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
-                _arg_count: sys::GDExtensionInt,
+                arg_count: sys::GDExtensionInt,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
             ) {
-                let success = ::godot::private::handle_panic(
-                    || #method_name_str,
+                let success = ::godot::private::handle_call_panic(
+                    || ::godot::panic_report::CallInfo::from_varcall::<#class_name>(
+                        #method_name_str,
+                        instance_ptr,
+                        args_ptr,
+                        arg_count,
+                    ),
                     || #invocation
                 );
 
@@ -309,11 +458,13 @@ fn make_varcall_func(
 
 /// Generate code for a C FFI function that performs a ptrcall.
 fn make_ptrcall_func(
+    class_name: &Ident,
     method_name: &Ident,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
 ) -> TokenStream {
     let invocation = make_ptrcall_invocation(method_name, sig_tuple, wrapped_method, false);
+    let call_info = make_ptrcall_info(class_name, method_name);
 
     quote! {
         {
@@ -323,8 +474,8 @@ fn make_ptrcall_func(
                 args_ptr: *const sys::GDExtensionConstTypePtr,
                 ret: sys::GDExtensionTypePtr,
             ) {
-                let success = ::godot::private::handle_panic(
-                    || stringify!(#method_name),
+                let success = ::godot::private::handle_call_panic(
+                    || #call_info,
                     || #invocation
                 );
 
@@ -338,6 +489,15 @@ fn make_ptrcall_func(
     }
 }
 
+/// Generate an expression describing a ptrcall for panic reports.
+fn make_ptrcall_info(class_name: &Ident, method_name: &Ident) -> TokenStream {
+    let method_name_str = method_name.to_string();
+
+    quote! {
+        ::godot::panic_report::CallInfo::from_ptrcall::<#class_name>(#method_name_str, instance_ptr)
+    }
+}
+
 /// Generate code for a `ptrcall` call expression.
 fn make_ptrcall_invocation(
     method_name: &Ident,
@@ -370,14 +530,31 @@ fn make_varcall_invocation(
     method_name: &Ident,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
+    has_varargs: bool,
 ) -> TokenStream {
     let method_name_str = method_name.to_string();
 
+    if has_varargs {
+        return quote! {
+            <#sig_tuple as ::godot::builtin::meta::VarcallSignatureTuple>::in_varcall_varargs(
+                instance_ptr,
+                #method_name_str,
+                args_ptr,
+                arg_count,
+                ret,
+                err,
+                #wrapped_method,
+            )
+        };
+    }
+
     quote! {
         <#sig_tuple as ::godot::builtin::meta::VarcallSignatureTuple>::in_varcall(
             instance_ptr,
             #method_name_str,
             args_ptr,
+            arg_count,
+            default_args,
             ret,
             err,
             #wrapped_method,
@@ -4,13 +4,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Punct, TokenStream};
-use quote::{format_ident, quote};
+use proc_macro2::{Ident, Punct, TokenStream, TokenTree};
+use quote::quote;
 use venial::{Declaration, NamedField, Struct, StructFields};
 
 use crate::class::{make_property_impl, Field, FieldExport, FieldVar, Fields};
-use crate::util::{bail, ident, KvParser};
-use crate::{util, ParseResult};
+use crate::util::{bail, KvParser};
+use crate::{docs, util, ParseResult};
 
 pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
     let class = decl
@@ -28,13 +28,15 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
     let class_name_cstr = util::cstr_u8_slice(&class_name_str);
     let class_name_obj = util::class_name_obj(class_name);
 
-    let base_ty = &struct_cfg.base_ty;
-    let base_class = quote! { ::godot::engine::#base_ty };
-    let base_class_name_obj = util::class_name_obj(&base_class);
-    let inherits_macro = format_ident!("inherits_transitive_{}", base_ty);
+    let base_class = &struct_cfg.base_ty;
+    let base_class_name_obj = util::class_name_obj(base_class);
 
     let prv = quote! { ::godot::private };
     let godot_exports_impl = make_property_impl(class_name, &fields);
+    let fields_for_hot_reload = hot_reload_field_names(&fields);
+    let with_base_field_impl = make_with_base_field_impl(class_name, &fields);
+    let user_class_impl = make_user_class_impl(class_name, &fields);
+    let docs = docs::make_struct_docs(&class.attributes, &fields.all_fields);
 
     let editor_plugin = if struct_cfg.is_editor_plugin {
         quote! {
@@ -50,7 +52,7 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
         quote! {}
     };
 
-    let (godot_init_impl, create_fn, recreate_fn);
+    let (godot_init_impl, create_fn, recreate_fn, create_storage_fn);
     if struct_cfg.has_generated_init {
         godot_init_impl = make_godot_init_impl(class_name, fields);
         create_fn = quote! { Some(#prv::callbacks::create::<#class_name>) };
@@ -59,13 +61,28 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
         } else {
             recreate_fn = quote! { None };
         }
+        create_storage_fn = quote! { Some(#prv::callbacks::create_storage::<#class_name>) };
     } else {
         godot_init_impl = TokenStream::new();
         create_fn = quote! { None };
         recreate_fn = quote! { None };
+        create_storage_fn = quote! { None };
     };
 
     let config_impl = make_config_impl(class_name, struct_cfg.is_tool);
+    let onready_get_virtual_fn = make_onready_get_virtual_fn(class_name, &fields);
+    let is_abstract = struct_cfg.is_abstract;
+    let is_exposed = !struct_cfg.is_hidden;
+    let icon = match &struct_cfg.icon {
+        Some(icon) => quote! { Some(#icon) },
+        None => quote! { None },
+    };
+    let hot_reload = make_hot_reload(class_name, &fields_for_hot_reload, struct_cfg.hot_reload);
+    let hot_reload_const = if struct_cfg.hot_reload == HotReloadMode::Off {
+        TokenStream::new()
+    } else {
+        quote! { const __HOT_RELOAD: bool = true; }
+    };
 
     Ok(quote! {
         unsafe impl ::godot::obj::GodotClass for #class_name {
@@ -73,17 +90,16 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
             type Declarer = ::godot::obj::dom::UserDomain;
             type Mem = <Self::Base as ::godot::obj::GodotClass>::Mem;
             const INIT_LEVEL: Option<::godot::init::InitLevel> = <#base_class as ::godot::obj::GodotClass>::INIT_LEVEL;
+            #hot_reload_const
 
             fn class_name() -> ::godot::builtin::meta::ClassName {
                 ::godot::builtin::meta::ClassName::from_ascii_cstr(#class_name_cstr)
             }
         }
-        impl ::godot::obj::UserClass for #class_name {
-
-        }
-
+        #user_class_impl
         #godot_init_impl
         #godot_exports_impl
+        #with_base_field_impl
         #config_impl
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
@@ -93,13 +109,31 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
                 generated_create_fn: #create_fn,
                 generated_recreate_fn: #recreate_fn,
                 free_fn: #prv::callbacks::free::<#class_name>,
+                generated_create_storage_fn: #create_storage_fn,
+                before_ready_fn: #prv::callbacks::before_ready::<#class_name>,
+                onready_get_virtual_fn: #onready_get_virtual_fn,
+                is_abstract: #is_abstract,
+                is_exposed: #is_exposed,
+                icon: #icon,
+                docs: #docs,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
 
         #editor_plugin
-
-        #prv::class_macros::#inherits_macro!(#class_name);
+        #hot_reload
+
+        // Inherits every class that the base inherits, whether it is an engine or a Rust class.
+        impl<B: ::godot::obj::GodotClass> ::godot::obj::Inherits<B> for #class_name
+        where
+            #base_class: ::godot::obj::Inherits<B>,
+        {}
+
+        // Only holds if the base is exportable. The higher-ranked bound defers the check to the places where it is needed.
+        impl ::godot::obj::ExportableObject for #class_name
+        where
+            for<'a> #base_class: ::godot::obj::ExportableObject,
+        {}
     })
 }
 
@@ -117,15 +151,20 @@ pub fn make_existence_check(ident: &Ident) -> TokenStream {
 
 /// Returns the name of the base and the default mode
 fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
-    let mut base_ty = ident("RefCounted");
+    let mut base_ty = quote! { ::godot::engine::RefCounted };
     let mut has_generated_init = false;
     let mut is_tool = false;
     let mut is_editor_plugin = false;
+    let mut is_abstract = false;
+    let mut is_hidden = false;
+    let mut icon: Option<TokenTree> = None;
     let mut rename: Option<Ident> = None;
+    let mut hot_reload = HotReloadMode::Off;
 
     // #[class] attribute on struct
     if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
-        if let Some(base) = parser.handle_ident("base")? {
+        // The base is either an engine class or a Rust class, resolved like any other type in scope of the struct.
+        if let Some(base) = parser.handle_expr("base")? {
             base_ty = base;
         }
 
@@ -143,6 +182,54 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
         }
         rename = parser.handle_ident("rename")?;
 
+        if parser.handle_alone("is_abstract")? {
+            is_abstract = true;
+        }
+
+        // #[class(hidden)] and #[class(icon = "res://...")] are only supported by GDExtensionClassCreationInfo2.
+        if let Some(key) = parser.handle_alone_ident("hidden")? {
+            if !cfg!(since_api = "4.2") {
+                return bail!(key, "hidden classes require Godot 4.2 or later");
+            }
+            is_hidden = true;
+        }
+
+        if let Some((key, value)) = parser.handle_any_entry("icon") {
+            if !cfg!(since_api = "4.2") {
+                return bail!(key, "class icons require Godot 4.2 or later");
+            }
+
+            let Some(value) = value else {
+                return bail!(key, "expected `icon = \"res://path/to/icon.svg\"`");
+            };
+            let path = value.single()?;
+            if !matches!(&path, TokenTree::Literal(lit) if lit.to_string().starts_with('"')) {
+                return bail!(
+                    &path,
+                    "expected string literal with the icon's resource path"
+                );
+            }
+            icon = Some(path);
+        }
+
+        // #[class(hot_reload)] or #[class(hot_reload = manual)]
+        if let Some((key, value)) = parser.handle_any_entry("hot_reload") {
+            if !cfg!(since_api = "4.2") {
+                return bail!(key, "hot reloading requires Godot 4.2 or later");
+            }
+
+            hot_reload = match value {
+                None => HotReloadMode::Generated,
+                Some(value) => {
+                    let mode = value.ident()?;
+                    if mode != "manual" {
+                        return bail!(mode, "expected `hot_reload` or `hot_reload = manual`");
+                    }
+                    HotReloadMode::Manual
+                }
+            };
+        }
+
         parser.finish()?;
     }
 
@@ -151,7 +238,11 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
         has_generated_init,
         is_tool,
         is_editor_plugin,
+        is_abstract,
+        is_hidden,
+        icon,
         rename,
+        hot_reload,
     })
 }
 
@@ -193,6 +284,27 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "init")? {
             let default = parser.handle_expr("default")?;
             field.default = default;
+
+            // #[init(node = "NodePath")]
+            if let Some(node_path) = parser.handle_expr("node")? {
+                if !field.is_onready {
+                    bail!(
+                        parser.span(),
+                        "#[init(node = ...)] requires a field of type `OnReady<Gd<T>>`",
+                    )?;
+                }
+                if field.default.is_some() {
+                    bail!(
+                        parser.span(),
+                        "#[init] can have either `default` or `node`, not both",
+                    )?;
+                }
+
+                field.default = Some(quote! {
+                    ::godot::obj::OnReady::node(#node_path)
+                });
+            }
+
             parser.finish()?;
         }
 
@@ -210,6 +322,12 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
             parser.finish()?;
         }
 
+        // #[hot_reload]
+        if let Some(parser) = KvParser::parse(&named_field.attributes, "hot_reload")? {
+            field.is_hot_reload = true;
+            parser.finish()?;
+        }
+
         // Exported or Rust-only fields
         if is_base {
             base_field = Some(field);
@@ -228,11 +346,24 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
 // General helpers
 
 struct ClassAttributes {
-    base_ty: Ident,
+    base_ty: TokenStream,
     has_generated_init: bool,
     is_tool: bool,
     is_editor_plugin: bool,
+    is_abstract: bool,
+    is_hidden: bool,
+    icon: Option<TokenTree>,
     rename: Option<Ident>,
+    hot_reload: HotReloadMode,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum HotReloadMode {
+    Off,
+    /// `HotReload` impl is generated from `#[var]`, `#[export]` and `#[hot_reload]` fields.
+    Generated,
+    /// `HotReload` is implemented by the user.
+    Manual,
 }
 
 fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
@@ -263,6 +394,78 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
     }
 }
 
+fn make_user_class_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+    let onready_fields = fields
+        .all_fields
+        .iter()
+        .filter(|field| field.is_onready)
+        .map(|field| &field.name)
+        .collect::<Vec<_>>();
+
+    let before_ready_impl = if onready_fields.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! {
+            const __HAS_ONREADY: bool = true;
+
+            fn __onready_initializers(&mut self) -> Vec<::godot::obj::OnReadyInitializer<Self>> {
+                let mut initializers = Vec::new();
+                #(
+                    initializers.extend(::godot::obj::OnReady::take_initializer(
+                        &mut self.#onready_fields,
+                        |this: &mut Self| &mut this.#onready_fields,
+                    ));
+                )*
+                initializers
+            }
+        }
+    };
+
+    quote! {
+        impl ::godot::obj::UserClass for #class_name {
+            #before_ready_impl
+        }
+    }
+}
+
+fn make_with_base_field_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+    let Some(Field { name, .. }) = &fields.base_field else {
+        return TokenStream::new();
+    };
+
+    quote! {
+        impl ::godot::obj::cap::WithBaseField for #class_name {
+            fn __godot_base(&self) -> &::godot::obj::Gd<Self::Base> {
+                &self.#name
+            }
+
+            fn __godot_base_mut(&mut self) -> &mut ::godot::obj::Gd<Self::Base> {
+                &mut self.#name
+            }
+        }
+    }
+}
+
+/// Provides `ready()` for classes with `OnReady` fields, in case they have no `#[godot_api]` interface impl (which would provide it).
+fn make_onready_get_virtual_fn(class_name: &Ident, fields: &Fields) -> TokenStream {
+    if !fields.all_fields.iter().any(|field| field.is_onready) {
+        return quote! { None };
+    }
+
+    quote! {
+        {
+            unsafe extern "C" fn get_virtual(
+                _class_user_data: *mut ::std::ffi::c_void,
+                name: ::godot::sys::GDExtensionConstStringNamePtr,
+            ) -> ::godot::sys::GDExtensionClassCallVirtual {
+                let is_active = !::godot::private::is_class_inactive(#class_name::__config().is_tool);
+                ::godot::private::callbacks::get_onready_virtual::<#class_name>(name, is_active)
+            }
+            Some(get_virtual)
+        }
+    }
+}
+
 fn make_config_impl(class_name: &Ident, is_tool: bool) -> TokenStream {
     quote! {
         impl #class_name {
@@ -275,3 +478,64 @@ fn make_config_impl(class_name: &Ident, is_tool: bool) -> TokenStream {
         }
     }
 }
+
+/// Returns the fields whose values are carried over by a generated `HotReload` impl.
+fn hot_reload_field_names(fields: &Fields) -> Vec<Ident> {
+    fields
+        .all_fields
+        .iter()
+        .filter(|field| field.var.is_some() || field.export.is_some() || field.is_hot_reload)
+        .map(|field| field.name.clone())
+        .collect()
+}
+
+fn make_hot_reload(class_name: &Ident, field_names: &[Ident], mode: HotReloadMode) -> TokenStream {
+    let prv = quote! { ::godot::private };
+    let class_name_obj = util::class_name_obj(class_name);
+
+    let hot_reload_impl = match mode {
+        HotReloadMode::Off => return TokenStream::new(),
+        HotReloadMode::Manual => TokenStream::new(),
+        HotReloadMode::Generated => {
+            let field_strs = field_names.iter().map(|name| name.to_string());
+            let field_strs2 = field_strs.clone();
+
+            quote! {
+                impl ::godot::obj::HotReload for #class_name {
+                    #[allow(unused_mut)]
+                    fn save_state(&self) -> ::godot::builtin::Dictionary {
+                        let mut state = ::godot::builtin::Dictionary::new();
+                        #(
+                            state.set(#field_strs, ::godot::builtin::meta::ToGodot::to_variant(&self.#field_names));
+                        )*
+                        state
+                    }
+
+                    #[allow(unused_variables)]
+                    fn restore_state(&mut self, state: ::godot::builtin::Dictionary) {
+                        #(
+                            if let Some(value) = state.get(#field_strs2) {
+                                if let Ok(value) = ::godot::builtin::meta::FromGodot::try_from_variant(&value) {
+                                    self.#field_names = value;
+                                }
+                            }
+                        )*
+                    }
+                }
+            }
+        }
+    };
+
+    quote! {
+        #hot_reload_impl
+
+        ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+            class_name: #class_name_obj,
+            component: #prv::PluginComponent::HotReload {
+                save_state_fn: #prv::callbacks::save_hot_reload_state::<#class_name>,
+                restore_state_fn: #prv::callbacks::restore_hot_reload_state::<#class_name>,
+            },
+            init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
+        });
+    }
+}
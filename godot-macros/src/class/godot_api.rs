@@ -5,16 +5,16 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
 use quote::spanned::Spanned;
+use quote::{format_ident, quote};
 use venial::{
     Attribute, AttributeValue, Constant, Declaration, Error, FnParam, Function, Impl, ImplMember,
     TyExpr,
 };
 
 use crate::class::{make_method_registration, make_virtual_method_callback, FuncDefinition};
-use crate::util;
 use crate::util::{bail, KvParser};
+use crate::{docs, util};
 
 pub fn attribute_godot_api(input_decl: Declaration) -> Result<TokenStream, Error> {
     let decl = match input_decl {
@@ -50,6 +50,7 @@ enum BoundAttrType {
     Func {
         rename: Option<String>,
         has_gd_self: bool,
+        on_error: Option<TokenStream>,
     },
     Signal(AttributeValue),
     Const(AttributeValue),
@@ -68,12 +69,12 @@ impl BoundAttr {
 }
 
 /// Holds information known from a signal's definition
-struct SignalDefinition {
+pub struct SignalDefinition {
     /// The signal's function signature.
-    signature: Function,
+    pub signature: Function,
 
     /// The signal's non-gdext attributes (all except #[signal]).
-    external_attributes: Vec<Attribute>,
+    pub external_attributes: Vec<Attribute>,
 }
 
 /// Codegen for `#[godot_api] impl MyType`
@@ -86,6 +87,8 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     let mut signal_name_strs: Vec<String> = Vec::new();
     let mut signal_parameters_count: Vec<usize> = Vec::new();
     let mut signal_parameters: Vec<TokenStream> = Vec::new();
+    let mut signal_idents: Vec<Ident> = Vec::new();
+    let mut signal_param_tuples: Vec<TokenStream> = Vec::new();
 
     for signal in signals.iter() {
         let SignalDefinition {
@@ -127,15 +130,27 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
         signal_name_strs.push(signature.name.to_string());
         signal_parameters_count.push(param_names.len());
         signal_parameters.push(param_array_decl);
+        signal_idents.push(signature.name.clone());
+        signal_param_tuples.push(quote! { ( #( #param_types, )* ) });
     }
 
+    let signals_collection = make_signals_collection(
+        &class_name,
+        &signal_cfg_attrs,
+        &signal_idents,
+        &signal_name_strs,
+        &signal_param_tuples,
+    );
+
     let prv = quote! { ::godot::private };
 
+    let consts = process_godot_constants(&mut decl)?;
+    let docs = docs::make_inherent_impl_docs(&funcs, &signals, &consts);
+
     let methods_registration = funcs
         .into_iter()
         .map(|func_def| make_method_registration(&class_name, func_def));
 
-    let consts = process_godot_constants(&mut decl)?;
     let mut integer_constant_cfg_attrs = Vec::new();
     let mut integer_constant_names = Vec::new();
     let mut integer_constant_values = Vec::new();
@@ -185,6 +200,7 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
 
     let result = quote! {
         #decl
+        #signals_collection
 
         impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
             fn __register_methods() {
@@ -230,6 +246,7 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
                 generated_register_fn: #prv::ErasedRegisterFn {
                     raw: #prv::callbacks::register_user_binds::<#class_name>,
                 },
+                docs: #docs,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
@@ -238,6 +255,50 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     Ok(result)
 }
 
+/// Generates the `signals()` accessor and the struct holding one typed signal per `#[signal]` declaration.
+fn make_signals_collection(
+    class_name: &Ident,
+    signal_cfg_attrs: &[Vec<&Attribute>],
+    signal_idents: &[Ident],
+    signal_name_strs: &[String],
+    signal_param_tuples: &[TokenStream],
+) -> TokenStream {
+    if signal_idents.is_empty() {
+        return TokenStream::new();
+    }
+
+    let collection_name = format_ident!("{}Signals", class_name);
+    let collection_doc =
+        format!("Typed signals declared by [`{class_name}`], returned by its `signals()` method.");
+
+    quote! {
+        impl #class_name {
+            /// Returns the typed signals declared in this class.
+            ///
+            /// Requires a `#[base]` field.
+            pub fn signals(&self) -> #collection_name {
+                #collection_name {
+                    object: <Self as ::godot::obj::cap::WithBaseField>::__godot_base(self).clone(),
+                }
+            }
+        }
+
+        #[doc = #collection_doc]
+        pub struct #collection_name {
+            object: ::godot::obj::Gd<<#class_name as ::godot::obj::GodotClass>::Base>,
+        }
+
+        impl #collection_name {
+            #(
+                #(#signal_cfg_attrs)*
+                pub fn #signal_idents(&self) -> ::godot::builtin::TypedSignal<#signal_param_tuples> {
+                    ::godot::builtin::TypedSignal::new(&self.object, #signal_name_strs)
+                }
+            )*
+        }
+    }
+}
+
 fn process_godot_fns(
     decl: &mut Impl,
 ) -> Result<(Vec<FuncDefinition>, Vec<SignalDefinition>), Error> {
@@ -274,8 +335,30 @@ fn process_godot_fns(
                 BoundAttrType::Func {
                     rename,
                     has_gd_self,
+                    on_error,
                 } => {
                     let external_attributes = method.attributes.clone();
+                    let mut param_defaults = extract_param_defaults(method)?;
+
+                    let has_varargs = method.params.inner.last().is_some_and(|(param, _)| {
+                        matches!(param, FnParam::Typed(param) if util::is_varargs_type(&param.ty))
+                    });
+                    if has_varargs && param_defaults.iter().any(Option::is_some) {
+                        return attr.bail(
+                            "#[opt(default = ...)] cannot be combined with a `&[Variant]` varargs parameter",
+                            method,
+                        );
+                    }
+
+                    let returns_result =
+                        method.return_ty.as_ref().is_some_and(util::is_result_type);
+                    if on_error.is_some() && !returns_result {
+                        return attr.bail(
+                            "attribute key `on_error` requires the method to return a `Result`",
+                            method,
+                        );
+                    }
+
                     // Signatures are the same thing without body
                     let mut sig = util::reduce_to_signature(method);
                     if *has_gd_self {
@@ -284,12 +367,21 @@ fn process_godot_fns(
                         } else {
                             sig.params.inner.remove(0);
                         }
+
+                        if param_defaults.remove(0).is_some() {
+                            return attr.bail(
+                                "the `gd_self` parameter cannot have a default value",
+                                method,
+                            );
+                        }
                     }
                     func_definitions.push(FuncDefinition {
                         func: sig,
                         external_attributes,
                         rename: rename.clone(),
                         has_gd_self: *has_gd_self,
+                        param_defaults,
+                        on_error: on_error.clone(),
                     });
                 }
                 BoundAttrType::Signal(ref _attr_val) => {
@@ -324,6 +416,42 @@ fn process_godot_fns(
     Ok((func_definitions, signal_definitions))
 }
 
+/// Parses `#[opt(default = ...)]` attributes on the parameters of a `#[func]`, and removes them from the method.
+///
+/// Returns one entry per typed (non-receiver) parameter.
+fn extract_param_defaults(method: &mut Function) -> Result<Vec<Option<TokenStream>>, Error> {
+    let mut param_defaults = vec![];
+
+    for (param, _punct) in method.params.inner.iter_mut() {
+        let FnParam::Typed(param) = param else {
+            continue;
+        };
+
+        let default = match KvParser::parse(&param.attributes, "opt")? {
+            Some(mut parser) => {
+                let default = parser.handle_expr_required("default")?;
+                parser.finish()?;
+                Some(default)
+            }
+            None => None,
+        };
+
+        if default.is_none() && param_defaults.iter().any(Option::is_some) {
+            return bail!(
+                &param.name,
+                "parameters without #[opt(default = ...)] must not follow parameters with default values",
+            );
+        }
+
+        param
+            .attributes
+            .retain(|attr| !matches!(attr.get_single_path_segment(), Some(name) if name == "opt"));
+        param_defaults.push(default);
+    }
+
+    Ok(param_defaults)
+}
+
 fn process_godot_constants(decl: &mut Impl) -> Result<Vec<Constant>, Error> {
     let mut constant_signatures = vec![];
 
@@ -372,13 +500,12 @@ where
 
         let new_found = match attr_name {
             name if name == "func" => {
-                // TODO you-win (August 8, 2023): handle default values here as well?
-
                 // Safe unwrap since #[func] must be present if we got to this point
                 let mut parser = KvParser::parse(attributes, "func")?.unwrap();
 
                 let rename = parser.handle_expr("rename")?.map(|ts| ts.to_string());
                 let has_gd_self = parser.handle_alone("gd_self")?;
+                let on_error = parser.handle_expr("on_error")?;
 
                 BoundAttr {
                     attr_name: attr_name.clone(),
@@ -386,19 +513,15 @@ where
                     ty: BoundAttrType::Func {
                         rename,
                         has_gd_self,
+                        on_error,
                     },
                 }
             }
-            name if name == "signal" => {
-                // TODO once parameters are supported, this should probably be moved to the struct definition
-                // E.g. a zero-sized type Signal<(i32, String)> with a provided emit(i32, String) method
-                // This could even be made public (callable on the struct obj itself)
-                BoundAttr {
-                    attr_name: attr_name.clone(),
-                    index,
-                    ty: BoundAttrType::Signal(attr.value.clone()),
-                }
-            }
+            name if name == "signal" => BoundAttr {
+                attr_name: attr_name.clone(),
+                index,
+                ty: BoundAttrType::Signal(attr.value.clone()),
+            },
             name if name == "constant" => BoundAttr {
                 attr_name: attr_name.clone(),
                 index,
@@ -461,6 +584,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
     let mut register_fn = None;
     let mut create_fn = None;
     let mut recreate_fn = None;
+    let mut create_storage_fn = None;
     let mut to_string_fn = None;
     let mut on_notification_fn = None;
 
@@ -498,7 +622,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
 
                     #(#cfg_attrs)*
                     impl ::godot::obj::cap::GodotRegisterClass for #class_name {
-                        fn __godot_register_class(builder: &mut ::godot::builder::GodotBuilder<Self>) {
+                        fn __godot_register_class(builder: &mut ::godot::builder::ClassBuilder<Self>) {
                             <Self as #trait_name>::register_class(builder)
                         }
                     }
@@ -544,6 +668,11 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                         () => Some(#prv::callbacks::recreate::<#class_name>),
                     });
                 }
+                create_storage_fn = Some(quote! {
+                    #create_storage_fn
+                    #(#cfg_attrs)*
+                    () => Some(#prv::callbacks::create_storage::<#class_name>),
+                });
             }
 
             "to_string" => {
@@ -628,6 +757,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
     let register_fn = convert_to_match_expression_or_none(register_fn);
     let create_fn = convert_to_match_expression_or_none(create_fn);
     let recreate_fn = convert_to_match_expression_or_none(recreate_fn);
+    let create_storage_fn = convert_to_match_expression_or_none(create_storage_fn);
     let to_string_fn = convert_to_match_expression_or_none(to_string_fn);
     let on_notification_fn = convert_to_match_expression_or_none(on_notification_fn);
 
@@ -648,13 +778,23 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                     return None;
                 }
 
-                match name {
+                let virtual_call = match name {
                     #(
                        #(#virtual_method_cfg_attrs)*
                        #virtual_method_names => #virtual_method_callbacks,
                     )*
                     _ => None,
+                };
+
+                // Without a user-defined ready(), OnReady fields still need to be initialized.
+                if virtual_call.is_none()
+                    && name == "_ready"
+                    && <#class_name as ::godot::obj::UserClass>::__HAS_ONREADY
+                {
+                    return Some(#prv::callbacks::ready::<#class_name>);
                 }
+
+                virtual_call
             }
         }
 
@@ -664,6 +804,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                 user_register_fn: #register_fn,
                 user_create_fn: #create_fn,
                 user_recreate_fn: #recreate_fn,
+                user_create_storage_fn: #create_storage_fn,
                 user_to_string_fn: #to_string_fn,
                 user_on_notification_fn: #on_notification_fn,
                 get_virtual_fn: #prv::callbacks::get_virtual::<#class_name>,
@@ -0,0 +1,64 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use venial::Declaration;
+
+use crate::util::bail;
+use crate::{util, ParseResult};
+
+pub fn attribute_godot_dyn(input_decl: Declaration) -> ParseResult<TokenStream> {
+    let decl = match input_decl {
+        Declaration::Impl(decl) => decl,
+        _ => bail!(
+            input_decl,
+            "#[godot_dyn] can only be applied on impl blocks",
+        )?,
+    };
+
+    if decl.impl_generic_params.is_some() {
+        bail!(
+            &decl,
+            "#[godot_dyn] currently does not support generic parameters",
+        )?;
+    }
+
+    let Some(trait_ty) = decl.trait_ty.clone() else {
+        return bail!(
+            decl,
+            "#[godot_dyn] requires a trait impl, e.g. `impl MyTrait for MyClass`",
+        );
+    };
+
+    let class_name = util::validate_impl(&decl, None, "godot_dyn")?;
+    let class_name_obj = util::class_name_obj(&class_name);
+    let prv = quote! { ::godot::private };
+
+    Ok(quote! {
+        #decl
+
+        impl ::godot::obj::AsDyn<dyn #trait_ty> for #class_name {
+            fn dyn_upcast(&self) -> &(dyn #trait_ty + 'static) {
+                self
+            }
+
+            fn dyn_upcast_mut(&mut self) -> &mut (dyn #trait_ty + 'static) {
+                self
+            }
+        }
+
+        ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+            class_name: #class_name_obj,
+            component: #prv::PluginComponent::DynTraitImpl {
+                class_type_id: ::std::any::TypeId::of::<#class_name>(),
+                dyn_trait_type_id: ::std::any::TypeId::of::<dyn #trait_ty>(),
+                erased_dyn_fn: #prv::callbacks::erased_dyn_gd::<#class_name, dyn #trait_ty>,
+            },
+            init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
+        });
+    })
+}
@@ -6,6 +6,7 @@
 
 mod derive_godot_class;
 mod godot_api;
+mod godot_dyn;
 mod data_models {
     pub mod field;
     pub mod field_export;
@@ -21,3 +22,4 @@ pub(crate) use data_models::func::*;
 pub(crate) use data_models::property::*;
 pub(crate) use derive_godot_class::*;
 pub(crate) use godot_api::*;
+pub(crate) use godot_dyn::*;
@@ -0,0 +1,178 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use venial::{Declaration, NamedField, StructFields};
+
+use crate::class::{FieldExport, FieldHint};
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+pub fn derive_project_settings(decl: Declaration) -> ParseResult<TokenStream> {
+    let struct_ = match decl {
+        Declaration::Struct(s) => s,
+        Declaration::Enum(e) => {
+            return bail!(e.tk_enum, "ProjectSettings can only be derived on structs")
+        }
+        Declaration::Union(u) => {
+            return bail!(u.tk_union, "ProjectSettings can only be derived on structs")
+        }
+        _ => unreachable!(),
+    };
+
+    if let Some(generic_params) = &struct_.generic_params {
+        return bail!(
+            generic_params,
+            "ProjectSettings cannot be derived on generic structs"
+        );
+    }
+
+    let named_fields: Vec<NamedField> = match &struct_.fields {
+        StructFields::Named(fields) => fields.fields.inner.iter().map(|(f, _)| f.clone()).collect(),
+        StructFields::Unit => vec![],
+        StructFields::Tuple(_) => {
+            return bail!(
+                &struct_.fields,
+                "ProjectSettings can only be derived on structs with named fields"
+            )
+        }
+    };
+
+    let name = &struct_.name;
+    let vis = &struct_.vis_marker;
+
+    let mut setting_infos = Vec::new();
+    let mut field_inits = Vec::new();
+    let mut getters = Vec::new();
+
+    for field in named_fields {
+        let setting = Setting::parse(&field)?;
+        let field_ident = &field.name;
+        let field_type = &field.ty;
+
+        let Setting {
+            path,
+            default,
+            restart_if_changed,
+            basic,
+            export,
+        } = setting;
+
+        let default = match default {
+            Some(default) => quote! { { let default: #field_type = #default; default } },
+            None => quote! { <#field_type as ::std::default::Default>::default() },
+        };
+
+        let hint_info = match export.to_field_hint() {
+            FieldHint::HintFromExportFunction(expression) => expression,
+            _ => quote! { <#field_type as ::godot::bind::property::Export>::default_export_info() },
+        };
+
+        setting_infos.push(quote! {
+            ::godot::settings::SettingInfo {
+                path: #path,
+                default_value: ::godot::builtin::meta::ToGodot::to_variant(&#default),
+                hint_info: #hint_info,
+                restart_if_changed: #restart_if_changed,
+                basic: #basic,
+            }
+        });
+
+        field_inits.push(quote! {
+            #field_ident: Self::#field_ident()
+        });
+
+        let getter_doc = format!(
+            "Reads the project setting `{}`, falling back to its default if it is absent.",
+            path.to_string().trim_matches('"')
+        );
+        getters.push(quote! {
+            #[doc = #getter_doc]
+            #vis fn #field_ident() -> #field_type {
+                ::godot::settings::get_setting_or(#path, || #default)
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl ::godot::settings::ProjectSettingsGroup for #name {
+            // `range = (1, 512)` is allowed for integer settings, so bounds are cast.
+            #[allow(clippy::unnecessary_cast)]
+            fn setting_infos() -> Vec<::godot::settings::SettingInfo> {
+                vec![
+                    #( #setting_infos, )*
+                ]
+            }
+
+            fn load() -> Self {
+                Self {
+                    #( #field_inits, )*
+                }
+            }
+        }
+
+        impl #name {
+            #( #getters )*
+        }
+    })
+}
+
+/// Parsed `#[setting(...)]` attribute of a single field.
+struct Setting {
+    path: TokenStream,
+    default: Option<TokenStream>,
+    restart_if_changed: bool,
+    basic: bool,
+    export: FieldExport,
+}
+
+impl Setting {
+    fn parse(field: &NamedField) -> ParseResult<Self> {
+        let mut parser = KvParser::parse_required(&field.attributes, "setting", &field.name)?;
+
+        let path = parser.handle_expr_required("path")?;
+        let default = parser.handle_expr("default")?;
+        let restart_if_changed = parser.handle_alone("restart_if_changed")?;
+        let basic = parser.handle_alone("basic")?;
+
+        // Hints use the same syntax as `#[export]`, e.g. `range = (1, 512)` or `enum = (Low, High)`.
+        let export = match FieldExport::new_from_kv(&mut parser)? {
+            FieldExport::Range {
+                min,
+                max,
+                step,
+                or_greater,
+                or_less,
+                exp,
+                radians,
+                degrees,
+                hide_slider,
+            } => FieldExport::Range {
+                min: quote! { (#min) as f64 },
+                max: quote! { (#max) as f64 },
+                step: step.map(|step| quote! { (#step) as f64 }),
+                or_greater,
+                or_less,
+                exp,
+                radians,
+                degrees,
+                hide_slider,
+            },
+            export => export,
+        };
+
+        parser.finish()?;
+
+        Ok(Self {
+            path,
+            default,
+            restart_if_changed,
+            basic,
+            export,
+        })
+    }
+}
@@ -0,0 +1,560 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Collects `///` doc comments of user classes for Godot's XML class reference format.
+//!
+//! Type names are not known to the proc-macro; the generated docs look them up at runtime through `GodotType`.
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use venial::{Attribute, AttributeValue, FnParam, Function};
+
+use crate::class::{Field, FuncDefinition, SignalDefinition};
+use crate::util;
+
+/// Returns the expression `Option<StructDocs>` for a `#[derive(GodotClass)]` struct.
+///
+/// `None` if neither the struct nor any of its properties is documented.
+pub fn make_struct_docs(attributes: &[Attribute], fields: &[Field]) -> TokenStream {
+    let class_doc = extract_doc_comment(attributes);
+    let properties: Vec<&Field> = fields
+        .iter()
+        .filter(|field| field.var.is_some() || field.export.is_some())
+        .collect();
+
+    if class_doc.is_none() && properties.iter().all(|field| field.doc.is_none()) {
+        return quote! { None };
+    }
+
+    let (brief, description) = split_brief(class_doc.as_deref().unwrap_or_default());
+    let brief = xml_escape(&markdown_to_bbcode(brief));
+    let description = xml_escape(&markdown_to_bbcode(description));
+
+    let members = properties.iter().map(|field| {
+        let name = field.name.to_string();
+        let ty = &field.ty;
+        let description = make_description(field.doc.as_deref());
+
+        quote! {
+            ::godot::private::MemberDocs {
+                name: #name,
+                type_name: ::godot::private::docs_property_type_name::<#ty>,
+                description: #description,
+            }
+        }
+    });
+
+    quote! {
+        Some(::godot::private::StructDocs {
+            brief: #brief,
+            description: #description,
+            members: &[ #( #members, )* ],
+        })
+    }
+}
+
+/// Returns the expression `Option<InherentImplDocs>` for a `#[godot_api] impl MyClass` block.
+///
+/// `None` if none of the registered items is documented.
+pub fn make_inherent_impl_docs(
+    funcs: &[FuncDefinition],
+    signals: &[SignalDefinition],
+    constants: &[venial::Constant],
+) -> TokenStream {
+    let func_docs: Vec<_> = funcs
+        .iter()
+        .map(|func| extract_doc_comment(&func.external_attributes))
+        .collect();
+    let signal_docs: Vec<_> = signals
+        .iter()
+        .map(|signal| extract_doc_comment(&signal.external_attributes))
+        .collect();
+    let constant_docs: Vec<_> = constants
+        .iter()
+        .map(|constant| extract_doc_comment(&constant.attributes))
+        .collect();
+
+    if func_docs
+        .iter()
+        .chain(&signal_docs)
+        .chain(&constant_docs)
+        .all(Option::is_none)
+    {
+        return quote! { None };
+    }
+
+    // #[cfg] attributes are transported to the array elements, so that conditionally removed items are not documented.
+    let methods = funcs.iter().zip(&func_docs).map(|(func, doc)| {
+        let cfg_attrs = util::extract_cfg_attrs(&func.external_attributes).into_iter();
+        let method = make_method_docs(func, doc.as_deref());
+        quote! { #(#cfg_attrs)* #method }
+    });
+
+    let signals = signals.iter().zip(&signal_docs).map(|(signal, doc)| {
+        let cfg_attrs = util::extract_cfg_attrs(&signal.external_attributes).into_iter();
+        let name = signal.signature.name.to_string();
+        let params = make_params_docs(&signal.signature);
+        let description = make_description(doc.as_deref());
+
+        quote! {
+            #(#cfg_attrs)*
+            ::godot::private::SignalDocs {
+                name: #name,
+                params: #params,
+                description: #description,
+            }
+        }
+    });
+
+    let constants = constants.iter().zip(&constant_docs).map(|(constant, doc)| {
+        let cfg_attrs = util::extract_cfg_attrs(&constant.attributes).into_iter();
+        let value = constant
+            .initializer
+            .as_ref()
+            .map(tokens_to_string)
+            .unwrap_or_default();
+        let xml = format!(
+            r#"<constant name="{name}" value="{value}">{doc}</constant>"#,
+            name = constant.name,
+            value = xml_escape(&value),
+            doc = make_description(doc.as_deref()),
+        );
+        quote! { #(#cfg_attrs)* #xml }
+    });
+
+    quote! {
+        Some(::godot::private::InherentImplDocs {
+            methods: &[ #( #methods, )* ],
+            signals: &[ #( #signals, )* ],
+            constants: &[ #( #constants, )* ],
+        })
+    }
+}
+
+/// Concatenates all `#[doc = "..."]` attributes (which is what `///` comments desugar to). `None` if there are none.
+pub fn extract_doc_comment(attributes: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attributes
+        .iter()
+        .filter(|attr| util::path_is_single(&attr.path, "doc"))
+        .filter_map(|attr| match &attr.value {
+            // `#[doc = include_str!(...)]` and similar cannot be evaluated by a proc-macro; ignore them.
+            AttributeValue::Equals(_, tokens) => match tokens.as_slice() {
+                [TokenTree::Literal(literal)] => unquote_str_literal(&literal.to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    // `/// text` becomes `#[doc = " text"]`; remove the leading space, but keep indentation beyond it.
+    let text = lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Docs generation
+
+/// Returns a `MethodDocs` expression. Type names are only known after type resolution, so they are looked up at runtime.
+fn make_method_docs(func: &FuncDefinition, doc: Option<&str>) -> TokenStream {
+    let signature = &func.func;
+    let name = func
+        .rename
+        .clone()
+        .unwrap_or_else(|| signature.name.to_string());
+
+    let is_static = !func.has_gd_self
+        && !signature
+            .params
+            .inner
+            .iter()
+            .any(|(param, _)| matches!(param, FnParam::Receiver(_)));
+    let is_vararg = typed_params(signature)
+        .last()
+        .is_some_and(|param| util::is_varargs_type(&param.ty));
+
+    let qualifiers = [(is_static, "static"), (is_vararg, "vararg")]
+        .into_iter()
+        .filter_map(|(is_set, qualifier)| is_set.then_some(qualifier))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // `Result` return types are registered as `Variant`, see `#[func]` docs.
+    let return_ty = match &signature.return_ty {
+        Some(ty) if util::is_result_type(ty) => quote! { ::godot::builtin::Variant },
+        Some(ty) => quote! { #ty },
+        None => quote! { () },
+    };
+
+    let params = make_params_docs(signature);
+    let description = make_description(doc);
+
+    quote! {
+        ::godot::private::MethodDocs {
+            name: #name,
+            qualifiers: #qualifiers,
+            return_type_name: ::godot::private::docs_type_name::<#return_ty>,
+            params: #params,
+            description: #description,
+        }
+    }
+}
+
+/// Returns a `&[ParamDocs]` expression. A trailing `&[Variant]` parameter is not listed, as it collects the varargs.
+fn make_params_docs(signature: &Function) -> TokenStream {
+    let params = typed_params(signature)
+        .filter(|param| !util::is_varargs_type(&param.ty))
+        .map(|param| {
+            let name = param.name.to_string();
+            let ty = &param.ty;
+
+            quote! {
+                ::godot::private::ParamDocs {
+                    name: #name,
+                    type_name: ::godot::private::docs_type_name::<#ty>,
+                }
+            }
+        });
+
+    quote! { &[ #( #params, )* ] }
+}
+
+fn typed_params(signature: &Function) -> impl Iterator<Item = &venial::FnTypedParam> {
+    signature
+        .params
+        .inner
+        .iter()
+        .filter_map(|(param, _)| match param {
+            FnParam::Typed(param) => Some(param),
+            FnParam::Receiver(_) => None,
+        })
+}
+
+fn make_description(doc: Option<&str>) -> String {
+    xml_escape(&markdown_to_bbcode(doc.unwrap_or_default()))
+}
+
+/// Renders a type or expression as compact Rust source, e.g. `Gd<Node>` instead of `Gd < Node >`.
+fn tokens_to_string(tokens: &impl ToTokens) -> String {
+    let spaced = tokens.to_token_stream().to_string();
+    let chars: Vec<char> = spaced.chars().collect();
+
+    let is_word = |c: Option<&char>| c.map_or(false, |c| c.is_alphanumeric() || *c == '_');
+
+    chars
+        .iter()
+        .enumerate()
+        .filter(|&(i, c)| {
+            // Keep spaces only where they separate two words, e.g. `dyn Trait` or `mut T`.
+            *c != ' ' || (i > 0 && is_word(chars.get(i - 1)) && is_word(chars.get(i + 1)))
+        })
+        .map(|(_, c)| c)
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Text conversion
+
+/// Parses the source representation of a string literal (`"..."` or `r#"..."#`) into its value.
+fn unquote_str_literal(literal: &str) -> Option<String> {
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let content = raw.get(hashes + 1..raw.len().checked_sub(hashes + 1)?)?;
+        return Some(content.to_string());
+    }
+
+    let content = literal.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => result.push('\n'),
+            'r' => result.push('\r'),
+            't' => result.push('\t'),
+            '0' => result.push('\0'),
+            '\\' => result.push('\\'),
+            '\'' => result.push('\''),
+            '"' => result.push('"'),
+            'x' => {
+                let code: String = chars.by_ref().take(2).collect();
+                result.push(char::from(u8::from_str_radix(&code, 16).ok()?));
+            }
+            'u' => {
+                let code: String = chars
+                    .by_ref()
+                    .skip_while(|&c| c == '{')
+                    .take_while(|&c| c != '}')
+                    .collect();
+                result.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+            }
+            '\n' => {
+                // Line continuation: skip leading whitespace of the next line.
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            }
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+/// Splits a doc comment into its first paragraph and the rest.
+fn split_brief(doc: &str) -> (&str, &str) {
+    let mut offset = 0;
+    for line in doc.split_inclusive('\n') {
+        if line.trim().is_empty() || line.trim_start().starts_with("```") {
+            break;
+        }
+        offset += line.len();
+    }
+
+    (doc[..offset].trim(), doc[offset..].trim())
+}
+
+/// Converts the Markdown subset commonly used in doc comments to Godot's BBCode dialect.
+///
+/// Supported are paragraphs, headings, lists, fenced code blocks, inline code, bold text and links. Intra-doc links
+/// such as ``[`Gd`]`` keep only their text.
+pub(crate) fn markdown_to_bbcode(markdown: &str) -> String {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut code_block: Option<Vec<&str>> = None;
+    let mut is_rust_code = false;
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<String>| {
+        if !paragraph.is_empty() {
+            blocks.push(convert_inline(&paragraph.join(" ")));
+            paragraph.clear();
+        }
+    };
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+
+        if let Some(code) = code_block.as_mut() {
+            if trimmed.starts_with("```") {
+                blocks.push(format!("[codeblock]\n{}\n[/codeblock]", code.join("\n")));
+                code_block = None;
+            } else if !(is_rust_code && (trimmed == "#" || trimmed.starts_with("# "))) {
+                // Lines starting with `# ` are hidden in rustdoc.
+                code.push(line);
+            }
+            continue;
+        }
+
+        if let Some(lang) = trimmed.strip_prefix("```") {
+            flush(&mut paragraph, &mut blocks);
+            let lang = lang.split(',').next().unwrap_or_default().trim();
+            is_rust_code = matches!(lang, "" | "rust" | "no_run" | "ignore" | "should_panic");
+            code_block = Some(Vec::new());
+        } else if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else if trimmed.starts_with('#') {
+            flush(&mut paragraph, &mut blocks);
+            let heading = trimmed.trim_start_matches('#').trim();
+            blocks.push(format!("[b]{}[/b]", convert_inline(heading)));
+        } else if let Some(item) = trimmed
+            .strip_prefix("* ")
+            .or_else(|| trimmed.strip_prefix("- "))
+        {
+            flush(&mut paragraph, &mut blocks);
+            paragraph.push("-");
+            paragraph.push(item);
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+
+    // Unterminated code block: keep its content.
+    if let Some(code) = code_block {
+        blocks.push(format!("[codeblock]\n{}\n[/codeblock]", code.join("\n")));
+    }
+    flush(&mut paragraph, &mut blocks);
+
+    blocks.join("\n")
+}
+
+/// Converts inline Markdown elements of a single paragraph.
+fn convert_inline(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("**") {
+            let (bold, remainder) = match after.find("**") {
+                Some(end) => (&after[..end], &after[end + 2..]),
+                None => (after, ""),
+            };
+            out.push_str(&format!("[b]{}[/b]", convert_inline(bold)));
+            rest = remainder;
+        } else if c == '`' {
+            match rest[1..].find('`') {
+                Some(end) => {
+                    let code = &rest[1..end + 1];
+                    out.push_str(&format!("[code]{}[/code]", escape_brackets(code)));
+                    rest = &rest[end + 2..];
+                }
+                None => {
+                    out.push('`');
+                    rest = &rest[1..];
+                }
+            }
+        } else if c == '[' {
+            let Some(close) = find_closing_bracket(rest) else {
+                out.push_str("[lb]");
+                rest = &rest[1..];
+                continue;
+            };
+
+            let label = convert_inline(&rest[1..close]);
+            let after = &rest[close + 1..];
+
+            if let Some(url) = after
+                .strip_prefix('(')
+                .and_then(|url| url.find(')').map(|end| &url[..end]))
+            {
+                // Intra-doc links like [`Gd`](crate::obj::Gd) cannot be resolved; only keep web links.
+                if url.contains("://") {
+                    out.push_str(&format!("[url={url}]{label}[/url]"));
+                } else {
+                    out.push_str(&label);
+                }
+                rest = &after[url.len() + 2..];
+            } else if let Some(reference) = after
+                .strip_prefix('[')
+                .and_then(|r| r.find(']').map(|end| &r[..end]))
+            {
+                out.push_str(&label);
+                rest = &after[reference.len() + 2..];
+            } else {
+                out.push_str(&label);
+                rest = after;
+            }
+        } else if c == ']' {
+            out.push_str("[rb]");
+            rest = &rest[1..];
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    out
+}
+
+/// Returns the index of the `]` matching the `[` at the start of `text`.
+fn find_closing_bracket(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_code = false;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            '`' => in_code = !in_code,
+            '[' if !in_code => depth += 1,
+            ']' if !in_code => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn escape_brackets(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '[' => "[lb]".to_string(),
+            ']' => "[rb]".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unquote_literals() {
+        assert_eq!(
+            unquote_str_literal(r#"" Doc with \"quotes\" and \\ and \u{e9}""#).as_deref(),
+            Some(" Doc with \"quotes\" and \\ and \u{e9}")
+        );
+        assert_eq!(
+            unquote_str_literal(r##"r#" raw "text""#"##).as_deref(),
+            Some(r#" raw "text""#)
+        );
+        assert_eq!(unquote_str_literal("42"), None);
+    }
+
+    #[test]
+    fn brief_split() {
+        assert_eq!(
+            split_brief("Short summary\nacross lines.\n\nDetails."),
+            ("Short summary\nacross lines.", "Details.")
+        );
+        assert_eq!(split_brief("Only brief."), ("Only brief.", ""));
+    }
+
+    #[test]
+    fn markdown_conversion() {
+        let markdown = "\
+# Example
+Moves the `player` by **two** units,
+see [`Gd`] and [the book](https://godot-rust.github.io/book).
+
+* first
+* second
+
+```
+# use godot::prelude::*;
+let x = [1, 2];
+```";
+
+        let expected = "\
+[b]Example[/b]
+Moves the [code]player[/code] by [b]two[/b] units, see [code]Gd[/code] and [url=https://godot-rust.github.io/book]the book[/url].
+- first
+- second
+[codeblock]
+let x = [1, 2];
+[/codeblock]";
+
+        assert_eq!(markdown_to_bbcode(markdown), expected);
+    }
+
+    #[test]
+    fn xml_escaping() {
+        assert_eq!(
+            xml_escape("Vec<i32> && \"x\""),
+            "Vec&lt;i32&gt; &amp;&amp; &quot;x&quot;"
+        );
+    }
+}
@@ -7,6 +7,7 @@
 mod bench;
 mod class;
 mod derive;
+mod docs;
 mod gdextension;
 mod itest;
 mod util;
@@ -70,6 +71,29 @@ use crate::util::ident;
 /// # }
 /// ```
 ///
+/// Fields of type [`OnReady<Gd<T>>`](../obj/struct.OnReady.html) can instead refer to a node in the scene tree, which is looked up
+/// right before `ready()` is called. The path is relative to the node itself:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[init(node = "Body/Sprite")]
+///     sprite: OnReady<Gd<Node2D>>,
+///
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Player {
+///     fn ready(&mut self) {
+///         self.sprite.set_visible(true);
+///     }
+/// }
+/// ```
+///
 /// # Inheritance
 ///
 /// Unlike C++, Rust doesn't really have inheritance, but the GDExtension API lets us "inherit"
@@ -78,7 +102,9 @@ use crate::util::ident;
 /// By default, classes created with this library inherit from `RefCounted`.
 ///
 /// To specify a different class to inherit from, add `#[class(base = Base)]` as an annotation on
-/// your `struct`:
+/// your `struct`. The base is resolved like any other type where the struct is declared, so it must be
+/// in scope (the prelude contains the most common classes), or be written as a path such as
+/// `base = godot::engine::Sprite2D`:
 ///
 /// ```
 /// use godot::prelude::*;
@@ -104,6 +130,41 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// The base can also be another Rust class, as long as it is in scope of the struct. The derived class contains
+/// its own Rust state next to that of the base class, which is accessible through `base.bind()` and `base.bind_mut()`. Its `#[func]`s,
+/// as well as virtual functions and `to_string()` not overridden by the derived class, are inherited:
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node2D)]
+/// struct Enemy {
+///     health: i32,
+///     #[base]
+///     base: Base<Node2D>,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base = Enemy)]
+/// struct Boss {
+///     phase: i32,
+///     #[base]
+///     base: Base<Enemy>,
+/// }
+///
+/// fn heal(boss: &mut Gd<Boss>) {
+///     boss.bind_mut().base.bind_mut().health += 10;
+///
+///     // Works like for engine base classes.
+///     let enemy: Gd<Enemy> = boss.clone().upcast();
+///     let node: Gd<Node2D> = enemy.upcast();
+/// }
+/// ```
+///
+/// To instantiate a derived class, its Rust base class needs a constructor as well (`#[class(init)]` or an `init()` function).
+/// The Rust base class must be registered at the same [`InitLevel`](../init/enum.InitLevel.html) as the derived class, or an earlier one.
+///
 ///
 /// # Properties and exports
 ///
@@ -305,8 +366,39 @@ use crate::util::ident;
 ///
 /// # Signals
 ///
-/// The `#[signal]` attribute is accepted, but not yet implemented. See [issue
-/// #8](https://github.com/godot-rust/gdext/issues/8).
+/// Signals are declared as function signatures without body, inside the `#[godot_api]` block. Each signal is registered with
+/// Godot, and can additionally be accessed in a type-safe way through the generated `signals()` method (requires a `#[base]` field).
+/// It returns a struct with one method per signal, each returning a [`TypedSignal`](../builtin/struct.TypedSignal.html).
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn hit(damage: i64);
+///
+///     #[func]
+///     fn take_damage(&mut self, damage: i64) {
+///         // Arguments are checked at compile time.
+///         self.signals().hit().emit(damage);
+///     }
+///
+///     #[func]
+///     fn log_hits(&self) {
+///         self.signals().hit().connect(|damage| {
+///             godot_print!("Received {damage} damage");
+///         });
+///     }
+/// }
+/// ```
 ///
 ///
 /// # Running code in the editor
@@ -331,7 +423,36 @@ use crate::util::ident;
 /// automatically enables it when the library is loaded.
 ///
 /// This should usually be combined with `#[class(tool)]` so that the code you write will actually run in the
-/// editor.
+/// editor. The plugin is removed from the editor again when the library is unloaded.
+///
+/// Inspector plugins, dock panels, bottom panels and class icons that the plugin adds in `enter_tree()` can be
+/// tracked with [`EditorAdditions`](../editor/struct.EditorAdditions.html), which removes all of them in
+/// `exit_tree()`. Custom property editors are provided by a class inheriting `EditorInspectorPlugin`, which
+/// creates `EditorProperty` subclasses in its `parse_property()` callback.
+///
+/// # Editor Appearance
+///
+/// The following keys control how a class appears in the editor:
+///
+/// - `icon = "res://path/to/icon.svg"` shows the given texture next to the class, e.g. in the scene tree and the
+///   "Create New Node" dialog.
+/// - `is_abstract` prevents Godot from creating instances of the class, typically for base classes that only
+///   exist to be inherited from. The class is still listed, but cannot be selected in the "Create New Node" dialog,
+///   and `ClassDB.instantiate()` as well as `.new()` in GDScript fail. Rust code is not affected: `new_gd()` and
+///   `alloc_gd()` still create instances, given an `init` constructor.
+/// - `hidden` removes the class from the editor's class lists altogether.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D, icon = "res://icons/enemy.svg")]
+/// struct Enemy {
+///     #[base]
+///     base: Base<Node2D>,
+/// }
+/// ```
+///
+/// `icon` and `hidden` require Godot 4.2 or later.
 ///
 /// # Class Renaming
 ///
@@ -356,7 +477,61 @@ use crate::util::ident;
 /// ```
 ///
 /// These classes will appear in the Godot editor and GDScript as "AnimalToad" or "NpcToad".
-#[proc_macro_derive(GodotClass, attributes(class, base, var, export, init, signal))]
+///
+/// # Documentation
+///
+/// Doc comments (`///`) on the struct and on `#[var]`/`#[export]` fields, as well as on `#[func]`, `#[signal]` and `#[constant]`
+/// items in the `#[godot_api]` block, are shown in the editor's help pages (since Godot 4.3). The first paragraph of the struct's
+/// doc comment is used as the brief description. Common Markdown (code, bold text, links, lists, code blocks) is converted to
+/// Godot's BBCode; intra-doc links keep only their text.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// /// A player character.
+/// ///
+/// /// Moves around and collects `Coin` nodes.
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct Player {
+///     /// Movement speed, in pixels per second.
+///     #[export]
+///     speed: f32,
+/// }
+/// ```
+///
+/// # Hot reloading
+///
+/// Since Godot 4.2, the extension library can be reloaded while the editor is running, if `reloadable = true` is set in the
+/// `.gdextension` file. Godot then recreates the Rust instances of all objects with the new code. By default, only properties
+/// stored in scenes survive this; all other fields are re-initialized.
+///
+/// With `#[class(hot_reload)]`, the values of all `#[var]`, `#[export]` and `#[hot_reload]` fields are carried over to the recreated
+/// instance. Field types must implement `ToGodot` and `FromGodot`; fields that were renamed or changed their type keep their
+/// initial value.
+///
+/// ```ignore
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, hot_reload)]
+/// pub struct Enemy {
+///     #[export]
+///     speed: f32,
+///
+///     // Not visible to Godot, but kept across reloads.
+///     #[hot_reload]
+///     hits_taken: i32,
+///
+///     #[base]
+///     base: Base<Node>,
+/// }
+/// ```
+///
+/// For custom logic, use `#[class(hot_reload = manual)]` and implement the
+/// [`HotReload`](../obj/trait.HotReload.html) trait yourself.
+#[proc_macro_derive(
+    GodotClass,
+    attributes(class, base, var, export, init, signal, hot_reload)
+)]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
@@ -437,11 +612,136 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// ## Default parameter values
+///
+/// Trailing parameters of a `#[func]` can be given default values with `#[opt(default = expr)]`. The expression must
+/// evaluate to the parameter's type. Callers (e.g. GDScript) may then omit those arguments, and the defaults are shown
+/// in the editor's method documentation.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Spawner;
+///
+/// #[godot_api]
+/// impl Spawner {
+///     #[func]
+///     fn spawn(&self, count: i32, #[opt(default = 1.5)] speed: f32) {
+///         godot_print!("Spawning {count} at speed {speed}");
+///     }
+/// }
+/// ```
+///
+/// Once a parameter has a default value, all following parameters must have one as well.
+///
+/// ## Variadic functions
+///
+/// If the last parameter of a `#[func]` has type `&[Variant]`, the function accepts any number of additional arguments,
+/// similar to GDScript's `print()`. All arguments after the regular parameters are collected into this slice.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Logger;
+///
+/// #[godot_api]
+/// impl Logger {
+///     // Called from GDScript as `logger.log_all("prefix", 1, "a", [2, 3])`.
+///     #[func]
+///     fn log_all(&self, prefix: GString, args: &[Variant]) {
+///         for arg in args {
+///             godot_print!("{prefix}: {arg}");
+///         }
+///     }
+/// }
+/// ```
+///
+/// Variadic functions cannot have parameters with default values.
+///
+/// ## Fallible functions
+///
+/// A `#[func]` may return `Result<T, E>`, where `T: ToGodot` and `E: Display`. The function is then registered as returning
+/// `Variant`. `Ok` values are converted to a variant; on `Err`, a script error with the error message is pushed to Godot, and the
+/// caller receives `null`. If `E` implements `std::error::Error`, the message lists every cause returned by `source()`; otherwise
+/// it uses alternate `{:#}` formatting, which shows the chain for types like `anyhow::Error`.
+///
+/// To return something else on error, provide a function `fn(&E) -> Variant` with the `on_error` key:
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Parser;
+///
+/// #[godot_api]
+/// impl Parser {
+///     #[func(on_error = invalid_number)]
+///     fn parse(&self, text: GString) -> Result<i64, std::num::ParseIntError> {
+///         text.to_string().parse()
+///     }
+/// }
+///
+/// fn invalid_number(_err: &std::num::ParseIntError) -> Variant {
+///     (-1).to_variant()
+/// }
+/// ```
+///
+/// The return type is detected syntactically, so it must be spelled `Result<..>` (path prefixes such as `std::io::Result<T>`
+/// are fine, other type aliases are not).
 #[proc_macro_attribute]
 pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
     translate(input, class::attribute_godot_api)
 }
 
+/// Makes a Rust trait implemented by a user class available through `DynGd`.
+///
+/// Annotate the `impl Trait for MyClass` block. Given any `Gd<T>` pointing to an instance of `MyClass` (or a Rust class inheriting
+/// from it), `Gd::try_dyn::<dyn Trait>()` then returns a `DynGd<T, dyn Trait>`, through which the trait methods can be called
+/// without knowing the concrete class:
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// trait Interactable {
+///     fn interact(&mut self) -> GString;
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node3D)]
+/// struct Door {
+///     open: bool,
+///     #[base]
+///     base: Base<Node3D>,
+/// }
+///
+/// #[godot_dyn]
+/// impl Interactable for Door {
+///     fn interact(&mut self) -> GString {
+///         self.open = !self.open;
+///         if self.open { "opened".into() } else { "closed".into() }
+///     }
+/// }
+///
+/// fn interact_with(node: Gd<Node3D>) {
+///     match node.try_dyn::<dyn Interactable>() {
+///         Ok(mut obj) => godot_print!("{}", obj.dyn_bind_mut().interact()),
+///         Err(node) => godot_print!("{node} cannot be interacted with"),
+///     }
+/// }
+/// ```
+///
+/// The trait must be object-safe and `'static`. Generic impls are not supported.
+#[proc_macro_attribute]
+pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
+    translate(input, class::attribute_godot_dyn)
+}
+
 #[proc_macro_derive(GodotConvert)]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
@@ -565,6 +865,45 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for [ProjectSettingsGroup](../settings/trait.ProjectSettingsGroup.html), declaring custom project settings.
+///
+/// Every field needs a `#[setting]` attribute with the following keys:
+///
+/// | Key                  | Meaning                                                                                   |
+/// |----------------------|-------------------------------------------------------------------------------------------|
+/// | `path = "a/b/c"`     | Path of the setting (required).                                                           |
+/// | `default = expr`     | Value if the setting is absent; `Default::default()` if omitted.                         |
+/// | `restart_if_changed` | The editor asks for a restart when the setting is changed.                                |
+/// | `basic`              | The setting is shown without enabling "Advanced Settings".                                |
+/// | hint                 | Any hint accepted by `#[export]`, e.g. `range = (1, 512)` or `enum = (Low, High)`. |
+///
+/// Without a hint, the one from the field type's [`Export`](../bind/property/trait.Export.html) impl is used.
+///
+/// For each field, an associated function of the same name returns the current value. `load()` reads all of them at once.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::settings::ProjectSettingsGroup;
+///
+/// #[derive(ProjectSettings)]
+/// pub struct AiSettings {
+///     #[setting(path = "game/ai/max_agents", default = 64, range = (1, 512))]
+///     max_agents: i32,
+///
+///     #[setting(path = "game/ai/debug_draw", basic)]
+///     debug_draw: bool,
+/// }
+///
+/// // In ExtensionLibrary::on_level_init(InitLevel::Scene):
+/// AiSettings::register();
+///
+/// let max_agents: i32 = AiSettings::max_agents();
+/// ```
+#[proc_macro_derive(ProjectSettings, attributes(setting))]
+pub fn derive_project_settings(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_project_settings)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
@@ -111,6 +111,36 @@ pub fn make_signature_tuple_type(
     }
 }
 
+/// Returns whether `ty` is a slice of variants (`&[Variant]`), which collects the trailing arguments of a vararg `#[func]`.
+pub(crate) fn is_varargs_type(ty: &venial::TyExpr) -> bool {
+    let (Some(first), Some(TokenTree::Group(group))) = (ty.tokens.first(), ty.tokens.last()) else {
+        return false;
+    };
+
+    let elem_path: Vec<TokenTree> = group.stream().into_iter().collect();
+    is_punct(first, '&')
+        && group.delimiter() == Delimiter::Bracket
+        && path_ends_with(&elem_path, "Variant")
+}
+
+/// Returns whether `ty` is syntactically a `Result<..>`, possibly path-qualified (e.g. `std::io::Result<T>`).
+pub(crate) fn is_result_type(ty: &venial::TyExpr) -> bool {
+    is_generic_type(ty, "Result")
+}
+
+/// Returns whether `ty` is syntactically an `OnReady<..>`, possibly path-qualified.
+pub(crate) fn is_onready_type(ty: &venial::TyExpr) -> bool {
+    is_generic_type(ty, "OnReady")
+}
+
+fn is_generic_type(ty: &venial::TyExpr, expected: &str) -> bool {
+    let Some(generic_start) = ty.tokens.iter().position(|tt| is_punct(tt, '<')) else {
+        return false;
+    };
+
+    path_ends_with(&ty.tokens[..generic_start], expected)
+}
+
 fn is_punct(tt: &TokenTree, c: char) -> bool {
     match tt {
         TokenTree::Punct(punct) => punct.as_char() == c,
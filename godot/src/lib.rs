@@ -135,6 +135,12 @@
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!   <br><br>
 //!
+//! * **`log`**, **`tracing`**
+//!
+//!   Forward records of the [log](https://docs.rs/log) and [tracing](https://docs.rs/tracing) crates to Godot's output, with file, line and
+//!   module of the call site. Install with `godot::log::init_log()` or `godot::log::GodotTracingLayer`, respectively. The most verbose level
+//!   that is forwarded is configured by the project setting `debug/rust/log_level`.<br><br>
+//!
 //! * **`experimental-threads`**
 //!
 //!   Experimental threading support. This enables `Send`/`Sync` traits for `Gd<T>` and makes the guard types `Gd`/`GdMut` aware of
@@ -158,6 +164,18 @@
 //!   truly available. Function calls may thus panic only at runtime, possibly in deeply nested code paths.
 //!   This feature is not yet thread-safe and can thus not be combined with `experimental-threads`.
 //!
+//! # Selecting generated classes
+//!
+//! By default, bindings for all engine classes are generated, which dominates clean build times. The environment variable
+//! `GODOT4_CODEGEN_CLASSES` restricts code generation to the listed classes (comma- or whitespace-separated), given either by
+//! Godot name (`Node2D`), Rust name (`Os`) or module name (`node_2d`). Classes reachable from the listed ones are generated as
+//! well: their base classes, the classes appearing in their method signatures (transitively), and the classes needed by gdext
+//! itself (including those re-exported by the prelude). The variable can be set permanently in `.cargo/config.toml`:
+//! ```toml
+//! [env]
+//! GODOT4_CODEGEN_CLASSES = "Node2D, Sprite2D, Input"
+//! ```
+//!
 //! # Public API
 //!
 //! Some symbols in the API are not intended for users, however Rust's visibility feature is not strong enough to express that in all cases
@@ -175,7 +193,9 @@
 //! This allows us to decide whether it fits the scope of the library and to design proper APIs for it.
 
 #[doc(inline)]
-pub use godot_core::{builtin, engine, log, obj};
+pub use godot_core::{
+    builder, builtin, editor, engine, log, obj, panic_report, settings, task, text_resource,
+};
 
 #[doc(hidden)]
 pub use godot_core::sys;
@@ -197,7 +217,8 @@ pub mod init {
 pub mod bind {
     pub use godot_core::property;
     pub use godot_macros::{
-        godot_api, Export, FromGodot, GodotClass, GodotConvert, Property, ToGodot,
+        godot_api, godot_dyn, Export, FromGodot, GodotClass, GodotConvert, ProjectSettings,
+        Property, ToGodot,
     };
 }
 
@@ -214,7 +235,8 @@ pub use godot_core::private;
 pub mod prelude {
     pub use super::bind::property::{Export, Property, TypeStringHint};
     pub use super::bind::{
-        godot_api, Export, FromGodot, GodotClass, GodotConvert, Property, ToGodot,
+        godot_api, godot_dyn, Export, FromGodot, GodotClass, GodotConvert, ProjectSettings,
+        Property, ToGodot,
     };
 
     pub use super::builtin::math::FloatExt as _;
@@ -229,7 +251,9 @@ pub mod prelude {
     };
     pub use super::init::{gdextension, ExtensionLibrary, InitLevel};
     pub use super::log::*;
-    pub use super::obj::{Base, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, Share};
+    pub use super::obj::{
+        Base, DynGd, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, OnReady, Share, WeakGd,
+    };
 
     // Make trait methods available
     pub use super::engine::NodeExt as _;
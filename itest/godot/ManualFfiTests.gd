@@ -338,3 +338,10 @@ func test_callable_refcount():
 	var method := Callable(self, "sample_func")
 	assert(method.is_valid())
 	test_obj.free()
+
+func test_func_varargs():
+	var obj := FuncVarargs.new()
+	assert_eq(obj.join_all(", ", 1, "a", Vector2i(1, 2)), "1, a, (1, 2)")
+	assert_eq(obj.join_all(""), "")
+	assert_eq(FuncVarargs.count_varargs(), 0)
+	assert_eq(FuncVarargs.count_varargs(true, null, [1]), 3)
@@ -47,3 +47,23 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+# Test that async tasks are resumed on the next frame and after a timer elapsed. Frames cannot pass during a Rust itest.
+func test_async_next_frame_and_timer():
+	# Async tasks are only available since Godot 4.2.
+	if not ClassDB.class_exists("AsyncTaskTest"):
+		return
+
+	var tree: SceneTree = Engine.get_main_loop()
+	var tester = ClassDB.instantiate("AsyncTaskTest")
+
+	tester.start(0.1)
+	assert_eq(tester.stage(), 1)
+
+	await tree.process_frame
+	await tree.process_frame
+	assert_eq(tester.stage(), 2)
+
+	await tree.create_timer(0.3).timeout
+	await tree.process_frame
+	await tree.process_frame
+	assert_eq(tester.stage(), 3)
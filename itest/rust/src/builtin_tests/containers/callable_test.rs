@@ -8,7 +8,7 @@ use godot::bind::{godot_api, GodotClass};
 use godot::builtin::inner::InnerCallable;
 use godot::builtin::meta::ToGodot;
 use godot::builtin::{varray, Callable, GString, StringName, Variant};
-use godot::engine::{Node2D, Object};
+use godot::engine::{Node, Node2D, Object, RefCounted};
 use godot::obj::UserClass;
 
 use crate::framework::itest;
@@ -7,9 +7,9 @@
 use std::cell::Cell;
 
 use godot::bind::{godot_api, GodotClass};
-use godot::builtin::{GString, Variant};
+use godot::builtin::{GString, Signal, Variant};
 
-use godot::engine::Object;
+use godot::engine::{Node, Object};
 use godot::obj::{Base, Gd, UserClass};
 use godot::sys;
 
@@ -87,3 +87,187 @@ fn signals() {
     receiver.free();
     emitter.free();
 }
+
+#[itest]
+fn signal_untyped_api() {
+    let emitter = TypedEmitter::alloc_gd();
+    let receiver = Receiver::alloc_gd();
+
+    let signal = Signal::from_object_signal(&emitter, "typed_1_arg");
+    assert!(!signal.is_null());
+    assert_eq!(signal.name(), "typed_1_arg".into());
+    assert_eq!(signal.object_id(), Some(emitter.instance_id()));
+
+    let callable = receiver.callable("receive_1_arg");
+    signal.connect(callable.clone(), 0);
+    assert!(signal.is_connected(callable.clone()));
+    assert_eq!(signal.connections().len(), 1);
+
+    signal.emit(&[Variant::from(987)]);
+    assert!(receiver.bind().used[1].get());
+
+    signal.disconnect(callable.clone());
+    assert!(!signal.is_connected(callable));
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_typed_emit_to_func() {
+    let emitter = TypedEmitter::alloc_gd();
+    let receiver = Receiver::alloc_gd();
+
+    let signals = emitter.bind().signals();
+    signals
+        .typed_1_arg()
+        .connect_callable(receiver.callable("receive_1_arg"));
+    signals.typed_1_arg().emit(987);
+
+    assert!(receiver.bind().used[1].get());
+
+    receiver.free();
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+mod typed_closures {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[itest]
+    fn signal_typed_connect_closure() {
+        let emitter = TypedEmitter::alloc_gd();
+        let received = Arc::new(Mutex::new(None));
+
+        let received_clone = received.clone();
+        let signals = emitter.bind().signals();
+        signals
+            .typed_2_arg()
+            .connect(move |number: i64, text: GString| {
+                *received_clone.lock().unwrap() = Some((number, text.to_string()));
+            });
+        signals
+            .typed_2_arg()
+            .emit(-7, GString::from(SIGNAL_ARG_STRING));
+
+        let received = received.lock().unwrap().clone();
+        assert_eq!(received, Some((-7, SIGNAL_ARG_STRING.to_string())));
+
+        emitter.free();
+    }
+
+    #[itest]
+    fn signal_typed_connect_obj() {
+        let emitter = TypedEmitter::alloc_gd();
+        let receiver = Receiver::alloc_gd();
+
+        let signals = emitter.bind().signals();
+        signals
+            .typed_1_arg()
+            .connect_obj(&receiver, |this, arg1| this.receive_1_arg(arg1));
+        signals.typed_1_arg().emit(987);
+
+        assert!(receiver.bind().used[1].get());
+
+        receiver.free();
+        emitter.free();
+    }
+
+    #[itest]
+    fn signal_connect_owned_disconnects_on_drop() {
+        let emitter = TypedEmitter::alloc_gd();
+        let owner = Object::new_alloc();
+        let count = Arc::new(Mutex::new(0));
+
+        let count_clone = count.clone();
+        let signal = emitter.bind().signals().typed_1_arg();
+        let handle = signal.connect_owned(&owner, move |arg1| {
+            *count_clone.lock().unwrap() += arg1;
+        });
+
+        assert!(handle.is_connected());
+        signal.emit(2);
+        assert_eq!(*count.lock().unwrap(), 2);
+
+        drop(handle);
+        signal.emit(3);
+        assert_eq!(*count.lock().unwrap(), 2, "no call after handle is dropped");
+
+        owner.free();
+        emitter.free();
+    }
+
+    #[itest]
+    fn signal_connect_owned_disconnects_on_owner_free() {
+        let emitter = TypedEmitter::alloc_gd();
+        let owner = Object::new_alloc();
+        let count = Arc::new(Mutex::new(0));
+
+        let count_clone = count.clone();
+        let signal = emitter.bind().signals().typed_1_arg();
+        let handle = signal.connect_owned(&owner, move |arg1| {
+            *count_clone.lock().unwrap() += arg1;
+        });
+
+        owner.free();
+        assert!(!handle.is_connected());
+        assert!(signal.to_untyped().connections().is_empty());
+
+        signal.emit(5);
+        assert_eq!(*count.lock().unwrap(), 0, "no call after owner is freed");
+
+        // Dropping the handle of a dead connection is a no-op.
+        drop(handle);
+        emitter.free();
+    }
+
+    #[itest]
+    fn signal_typed_engine_class() {
+        let mut node = Node::new_alloc();
+        let child = Node::new_alloc();
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        // Signal declared by `Node` itself.
+        let received_clone = received.clone();
+        node.signals()
+            .child_entered_tree()
+            .connect(move |child: Option<Gd<Node>>| {
+                received_clone
+                    .lock()
+                    .unwrap()
+                    .push(child.map(|c| c.instance_id()));
+            });
+        node.signals()
+            .child_entered_tree()
+            .emit(Some(child.clone()));
+
+        // Signal inherited from `Object`.
+        let received_clone = received.clone();
+        node.signals().property_list_changed().connect(move || {
+            received_clone.lock().unwrap().push(None);
+        });
+        node.notify_property_list_changed();
+
+        let received = received.lock().unwrap().clone();
+        assert_eq!(received, vec![Some(child.instance_id()), None]);
+
+        child.free();
+        node.free();
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct TypedEmitter {
+    #[base]
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl TypedEmitter {
+    #[signal]
+    fn typed_1_arg(arg1: i64);
+    #[signal]
+    fn typed_2_arg(number: i64, text: GString);
+}
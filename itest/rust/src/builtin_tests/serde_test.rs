@@ -0,0 +1,168 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use godot::builtin::serde::{from_variant, to_variant};
+use godot::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::framework::itest;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct SaveGame {
+    level: u32,
+    player_name: String,
+    position: Vector3,
+    tint: Option<Color>,
+    inventory: Vec<Item>,
+    flags: BTreeMap<String, bool>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+enum Item {
+    Empty,
+    Potion(u8),
+    Weapon { name: String, damage: f32 },
+}
+
+/// Has the same name and fields as the builtin, but is a different type.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Color {
+    r: f32,
+    g: f32,
+    b: f32,
+    a: f32,
+}
+
+/// Refers to builtins through a reference and a smart pointer.
+#[derive(Serialize)]
+struct BorrowedWaypoint<'a> {
+    position: &'a Vector3,
+    tint: Box<godot::builtin::Color>,
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Waypoint {
+    position: Vector3,
+    tint: Box<godot::builtin::Color>,
+}
+
+fn roundtrip<T>(value: &T) -> Variant
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let variant = to_variant(value).expect("serialize");
+    let back: T = from_variant(&variant).expect("deserialize");
+
+    assert_eq!(&back, value);
+    variant
+}
+
+#[itest]
+fn serde_builtins_native() {
+    let vector = roundtrip(&Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(vector, Vector3::new(1.0, 2.0, 3.0).to_variant());
+
+    let color = godot::builtin::Color::from_rgba(0.5, 0.25, 1.0, 1.0);
+    assert_eq!(roundtrip(&color), color.to_variant());
+
+    let transform = Transform3D::IDENTITY.translated(Vector3::new(4.0, 5.0, 6.0));
+    assert_eq!(roundtrip(&transform), transform.to_variant());
+
+    let rects = vec![Rect2i::new(Vector2i::ZERO, Vector2i::ONE)];
+    assert_eq!(roundtrip(&rects), varray![rects[0]].to_variant());
+}
+
+#[itest]
+fn serde_builtins_behind_references() {
+    let position = Vector3::new(1.0, 2.0, 3.0);
+    let tint = godot::builtin::Color::from_rgb(0.0, 1.0, 0.0);
+
+    let borrowed = BorrowedWaypoint {
+        position: &position,
+        tint: Box::new(tint),
+    };
+    let variant = to_variant(&borrowed).expect("serialize");
+
+    let dict: Dictionary = variant.to();
+    assert_eq!(dict.get("position"), Some(position.to_variant()));
+    assert_eq!(dict.get("tint"), Some(tint.to_variant()));
+    assert_eq!(to_variant(&&position), Ok(position.to_variant()));
+
+    let back: Waypoint = from_variant(&variant).expect("deserialize");
+    assert_eq!(
+        back,
+        Waypoint {
+            position,
+            tint: Box::new(tint),
+        }
+    );
+}
+
+#[itest]
+fn serde_nested_containers() {
+    let nested: Vec<BTreeMap<String, Vec<i64>>> = vec![
+        BTreeMap::from([("a".to_string(), vec![1, 2]), ("b".to_string(), vec![])]),
+        BTreeMap::new(),
+    ];
+
+    let variant = roundtrip(&nested);
+
+    let mut first = Dictionary::new();
+    first.set("a", varray![1, 2]);
+    first.set("b", VariantArray::new());
+    assert_eq!(variant, varray![first, Dictionary::new()].to_variant());
+}
+
+#[itest]
+fn serde_user_struct() {
+    let save = SaveGame {
+        level: 3,
+        player_name: "Rusty".to_string(),
+        position: Vector3::new(1.0, 2.0, 3.0),
+        tint: Some(godot::builtin::Color::from_rgb(1.0, 0.0, 0.0)),
+        inventory: vec![
+            Item::Empty,
+            Item::Potion(2),
+            Item::Weapon {
+                name: "sword".to_string(),
+                damage: 7.5,
+            },
+        ],
+        flags: BTreeMap::from([("tutorial_done".to_string(), true)]),
+    };
+
+    let dict: Dictionary = roundtrip(&save).to();
+    assert_eq!(dict.get("level"), Some(3.to_variant()));
+    assert_eq!(
+        dict.get("position"),
+        Some(Vector3::new(1.0, 2.0, 3.0).to_variant())
+    );
+    assert_eq!(
+        dict.get("tint"),
+        Some(godot::builtin::Color::from_rgb(1.0, 0.0, 0.0).to_variant())
+    );
+
+    let inventory: VariantArray = dict.get("inventory").unwrap().to();
+    assert_eq!(inventory.get(0), "Empty".to_variant());
+    assert_eq!(inventory.get(1).get_type(), VariantType::Dictionary);
+}
+
+#[itest]
+fn serde_user_struct_named_like_builtin() {
+    let color = Color {
+        r: 0.5,
+        g: 0.25,
+        b: 1.0,
+        a: 1.0,
+    };
+
+    let variant = roundtrip(&color);
+    assert_eq!(variant.get_type(), VariantType::Dictionary);
+}
@@ -0,0 +1,175 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{Signal, TypedSignal, Variant};
+use godot::engine::Object;
+use godot::obj::Gd;
+use godot::prelude::*;
+use godot::task;
+
+use crate::framework::itest;
+
+/// Runs a task across several frames. Used by `SpecialTests.gd`, which can await frames in between.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct AsyncTaskTest {
+    stage: Rc<Cell<i64>>,
+}
+
+#[godot_api]
+impl AsyncTaskTest {
+    /// Stage 1 until the next frame, stage 2 until the timer elapsed, then stage 3.
+    #[func]
+    fn start(&self, timer_seconds: f64) {
+        let stage = self.stage.clone();
+
+        task::spawn(async move {
+            stage.set(1);
+            task::next_frame().await;
+
+            stage.set(2);
+            task::timer(timer_seconds).await;
+
+            stage.set(3);
+        });
+    }
+
+    #[func]
+    fn stage(&self) -> i64 {
+        self.stage.get()
+    }
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn poll_once<F: Future>(future: std::pin::Pin<&mut F>) -> Poll<F::Output> {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    future.poll(&mut Context::from_waker(&waker))
+}
+
+fn object_with_signal() -> (Gd<Object>, Signal) {
+    let mut object = Object::new_alloc();
+    object.add_user_signal("done".into());
+
+    let signal = Signal::from_object_signal(&object, "done");
+    (object, signal)
+}
+
+#[itest]
+fn async_signal_future_resolves_on_emit() {
+    let (object, signal) = object_with_signal();
+
+    let mut future = pin!(signal.to_future());
+    assert_eq!(poll_once(future.as_mut()), Poll::Pending);
+
+    signal.emit(&[7.to_variant(), "text".to_variant()]);
+    assert_eq!(
+        poll_once(future.as_mut()),
+        Poll::Ready(vec![7.to_variant(), "text".to_variant()])
+    );
+    assert!(signal.connections().is_empty());
+
+    object.free();
+}
+
+#[itest]
+fn async_signal_future_disconnects_on_drop() {
+    let (object, signal) = object_with_signal();
+
+    let future = signal.to_future();
+    assert_eq!(signal.connections().len(), 1);
+
+    drop(future);
+    assert!(signal.connections().is_empty());
+
+    object.free();
+}
+
+#[itest]
+fn async_typed_signal_future() {
+    let (object, _signal) = object_with_signal();
+    let typed = TypedSignal::<(i64, Variant)>::new(&object, "done");
+
+    let mut future = pin!(typed.to_future());
+    assert_eq!(poll_once(future.as_mut()), Poll::Pending);
+
+    typed.emit(-3, Variant::nil());
+    assert_eq!(
+        poll_once(future.as_mut()),
+        Poll::Ready((-3, Variant::nil()))
+    );
+
+    object.free();
+}
+
+#[itest]
+fn async_spawn_runs_until_first_await() {
+    let (object, signal) = object_with_signal();
+    let progress = Rc::new(Cell::new(0));
+
+    let handle = task::spawn({
+        let progress = progress.clone();
+        async move {
+            progress.set(1);
+            signal.to_future().await;
+            progress.set(2);
+        }
+    });
+
+    // The task runs synchronously up to the first pending await, and is resumed at the earliest in the next frame.
+    assert_eq!(progress.get(), 1);
+    assert!(handle.is_pending());
+
+    handle.cancel();
+    assert!(!handle.is_pending());
+    assert_eq!(progress.get(), 1);
+
+    object.free();
+}
+
+#[itest]
+fn async_spawn_completes_without_await() {
+    let handle = task::spawn(async {});
+
+    assert!(!handle.is_pending());
+}
+
+#[itest]
+fn async_next_frame_pending_within_frame() {
+    let mut future = pin!(task::next_frame());
+
+    // Only resolves once the frame counter advanced, which cannot happen during a test.
+    assert_eq!(poll_once(future.as_mut()), Poll::Pending);
+    assert_eq!(poll_once(future.as_mut()), Poll::Pending);
+}
+
+#[itest]
+fn async_timer_pending_until_timeout() {
+    let mut future = pin!(task::timer(10.0));
+
+    assert_eq!(poll_once(future.as_mut()), Poll::Pending);
+}
+
+#[itest]
+fn async_multi_frame_task_starts() {
+    let tester = AsyncTaskTest::new_gd();
+    tester.bind().start(10.0);
+
+    // Further stages are checked in SpecialTests.gd.
+    assert_eq!(tester.bind().stage(), 1);
+}
@@ -0,0 +1,39 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::editor::EditorAdditions;
+use godot::engine::{ClassDb, EditorInspectorPlugin, EditorPlugin};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+// Inspector plugins are stored in a static list of the editor inspector, so this works without a running editor.
+// Docks, bottom panels and icons need the editor UI and are not tested here.
+#[itest]
+fn editor_additions_inspector_plugin() {
+    // Editor classes are only available in editor builds of Godot.
+    if !ClassDb::singleton().class_exists("EditorPlugin".into()) {
+        return;
+    }
+
+    let mut plugin = EditorPlugin::new_alloc();
+    let inspector_plugin = EditorInspectorPlugin::new_gd();
+    let mut additions = EditorAdditions::new();
+
+    additions.add_inspector_plugin(&mut plugin, inspector_plugin.clone());
+
+    // Referenced by the test, `additions` and the editor inspector.
+    assert_eq!(inspector_plugin.get_reference_count(), 3);
+
+    additions.remove_all(&mut plugin);
+    assert_eq!(inspector_plugin.get_reference_count(), 1);
+
+    // Nothing left to remove.
+    additions.remove_all(&mut plugin);
+    assert_eq!(inspector_plugin.get_reference_count(), 1);
+
+    plugin.free();
+}
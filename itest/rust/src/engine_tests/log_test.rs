@@ -0,0 +1,49 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::Once;
+
+use godot::engine::ProjectSettings;
+use godot::log::LOG_LEVEL_SETTING;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+fn install_logger() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        godot::log::init_log().expect("no other logger installed");
+    });
+}
+
+#[itest]
+fn log_level_setting_registered() {
+    install_logger();
+
+    let settings = ProjectSettings::singleton();
+    assert!(settings.has_setting(LOG_LEVEL_SETTING.into()));
+    assert_eq!(
+        settings.get_setting(LOG_LEVEL_SETTING.into()),
+        3.to_variant()
+    );
+}
+
+#[itest]
+fn log_level_filtering() {
+    install_logger();
+
+    // The itest project does not override the setting, so the default level (info) applies.
+    assert!(log::log_enabled!(log::Level::Error));
+    assert!(log::log_enabled!(log::Level::Warn));
+    assert!(log::log_enabled!(log::Level::Info));
+    assert!(!log::log_enabled!(log::Level::Debug));
+    assert!(!log::log_enabled!(log::Level::Trace));
+
+    // Must not panic, neither for console nor debugger output; debug is filtered out.
+    log::info!(target: "itest", "forwarded info with {} argument", 1);
+    log::debug!(target: "itest", "filtered debug");
+}
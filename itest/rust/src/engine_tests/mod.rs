@@ -4,7 +4,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+#[cfg(since_api = "4.2")]
+mod async_test;
+mod editor_test;
 mod gfile_test;
+#[cfg(feature = "log")]
+mod log_test;
 mod native_structures_test;
 mod node_test;
 mod utilities_test;
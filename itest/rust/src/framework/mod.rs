@@ -103,6 +103,11 @@ pub fn passes_filter(filters: &[String], test_name: &str) -> bool {
 }
 
 pub fn expect_panic(context: &str, code: impl FnOnce() + std::panic::UnwindSafe) {
+    expect_panic_message(context, code);
+}
+
+/// Like [`expect_panic`], but returns the panic message, for tests that check its content.
+pub fn expect_panic_message(context: &str, code: impl FnOnce() + std::panic::UnwindSafe) -> String {
     use std::panic;
 
     // Exchange panic hook, to disable printing during expected panics
@@ -113,10 +118,17 @@ pub fn expect_panic(context: &str, code: impl FnOnce() + std::panic::UnwindSafe)
     let panic = panic::catch_unwind(code);
     panic::set_hook(prev_hook);
 
-    assert!(
-        panic.is_err(),
-        "code should have panicked but did not: {context}",
-    );
+    let Err(payload) = panic else {
+        panic!("code should have panicked but did not: {context}");
+    };
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else {
+        String::new()
+    }
 }
 
 /// Disable printing errors from Godot. Ideally we should catch and handle errors, ensuring they happen when
@@ -0,0 +1,128 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::prelude::*;
+
+trait Health {
+    fn get_hitpoints(&self) -> i32;
+
+    fn deal_damage(&mut self, damage: i32);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct RefcHealth {
+    #[init(default = 100)]
+    hp: i32,
+}
+
+#[godot_dyn]
+impl Health for RefcHealth {
+    fn get_hitpoints(&self) -> i32 {
+        self.hp
+    }
+
+    fn deal_damage(&mut self, damage: i32) {
+        self.hp -= damage;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct NodeHealth {
+    #[init(default = 50)]
+    hp: i32,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_dyn]
+impl Health for NodeHealth {
+    fn get_hitpoints(&self) -> i32 {
+        self.hp
+    }
+
+    fn deal_damage(&mut self, damage: i32) {
+        self.hp = (self.hp - damage * 2).max(0);
+    }
+}
+
+// Inherits the `Health` impl of its Rust base class.
+#[derive(GodotClass)]
+#[class(init, base=NodeHealth)]
+struct BossHealth {
+    #[base]
+    base: Base<NodeHealth>,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[itest]
+fn dyn_gd_try_dyn() {
+    let obj = RefcHealth::new_gd().upcast::<RefCounted>();
+
+    let mut dyn_obj = obj
+        .try_dyn::<dyn Health>()
+        .expect("RefcHealth implements Health");
+
+    assert_eq!(dyn_obj.dyn_bind().get_hitpoints(), 100);
+    dyn_obj.dyn_bind_mut().deal_damage(30);
+    assert_eq!(dyn_obj.dyn_bind().get_hitpoints(), 70);
+
+    // Same object, also accessible through its concrete type.
+    let concrete = dyn_obj.into_gd().cast::<RefcHealth>();
+    assert_eq!(concrete.bind().hp, 70);
+}
+
+#[itest]
+fn dyn_gd_dispatch_by_dynamic_class() {
+    let nodes: [Gd<Node>; 2] = [
+        NodeHealth::alloc_gd().upcast(),
+        BossHealth::alloc_gd().upcast(),
+    ];
+
+    for node in nodes {
+        let mut dyn_node = node.try_dyn::<dyn Health>().expect("implements Health");
+        dyn_node.dyn_bind_mut().deal_damage(10);
+        assert_eq!(dyn_node.dyn_bind().get_hitpoints(), 30);
+
+        dyn_node.into_gd().free();
+    }
+}
+
+#[itest]
+fn dyn_gd_not_implemented() {
+    let node = Node::new_alloc();
+    let node = node
+        .try_dyn::<dyn Health>()
+        .expect_err("engine class does not implement Health");
+    node.free();
+
+    let obj = RefCounted::new();
+    assert!(obj.try_dyn::<dyn Health>().is_err());
+}
+
+#[itest]
+fn dyn_gd_into_dyn_upcast() {
+    let dyn_obj = RefcHealth::new_gd().into_dyn::<dyn Health>();
+    let dyn_obj = dyn_obj.upcast::<Object>();
+
+    let copy = dyn_obj.clone();
+    assert_eq!(copy.dyn_bind().get_hitpoints(), 100);
+    assert_eq!(*dyn_obj, *copy);
+}
+
+#[itest]
+fn dyn_gd_shared_binds() {
+    let obj = RefcHealth::new_gd();
+    let dyn_obj = obj.clone().into_dyn::<dyn Health>();
+
+    // Shared borrows can coexist across both pointers.
+    let guard = obj.bind();
+    assert_eq!(dyn_obj.dyn_bind().get_hitpoints(), guard.hp);
+}
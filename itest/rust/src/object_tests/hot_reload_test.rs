@@ -0,0 +1,61 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::obj::HotReload;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, hot_reload)]
+struct HotReloadState {
+    #[var]
+    health: i64,
+    #[export]
+    name: GString,
+    #[hot_reload]
+    counter: u32,
+    not_saved: i32,
+    #[base]
+    base: Base<RefCounted>,
+}
+
+#[itest]
+fn hot_reload_save_state() {
+    let mut obj = HotReloadState::new_gd();
+    {
+        let mut state = obj.bind_mut();
+        state.health = 20;
+        state.name = "player".into();
+        state.counter = 3;
+        state.not_saved = 5;
+    }
+
+    let saved = obj.bind().save_state();
+    assert_eq!(
+        saved,
+        dict! { "health": 20, "name": "player", "counter": 3 }
+    );
+}
+
+#[itest]
+fn hot_reload_restore_state() {
+    let mut obj = HotReloadState::new_gd();
+
+    // Entries with unknown keys or incompatible types are ignored.
+    obj.bind_mut().restore_state(dict! {
+        "health": 42,
+        "name": 7,
+        "counter": 9,
+        "unknown": true,
+    });
+
+    let state = obj.bind();
+    assert_eq!(state.health, 42);
+    assert_eq!(state.name, GString::new());
+    assert_eq!(state.counter, 9);
+    assert_eq!(state.not_saved, 0);
+}
@@ -6,8 +6,13 @@
 
 mod base_test;
 mod class_rename_test;
+mod dyn_gd_test;
+#[cfg(since_api = "4.2")]
+mod hot_reload_test;
 mod object_test;
+mod onready_test;
 mod property_template_test;
 mod property_test;
 mod singleton_test;
 mod virtual_methods_test;
+mod weak_gd_test;
@@ -17,7 +17,7 @@ use godot::obj::{Base, Gd, Inherits, InstanceId, RawGd, UserClass};
 use godot::prelude::meta::GodotType;
 use godot::sys::{self, GodotFfi};
 
-use crate::framework::{expect_panic, itest, TestContext};
+use crate::framework::{expect_panic, expect_panic_message, itest, TestContext};
 
 // TODO:
 // * make sure that ptrcalls are used when possible (ie. when type info available; maybe GDScript integration test)
@@ -268,6 +268,40 @@ fn object_user_free_during_bind() {
     obj.free(); // now succeeds
 }
 
+#[cfg(debug_assertions)]
+#[itest]
+fn object_user_bind_conflict_reports_origin() {
+    let obj = RefcPayload::new_gd();
+    let (guard, bind_line) = (obj.bind(), line!());
+
+    let mut copy = obj.clone();
+    let message = expect_panic_message("bind_mut() while bound", move || {
+        let _ = copy.bind_mut();
+    });
+
+    assert!(message.contains("Outstanding borrows:"), "{message}");
+    assert!(message.contains("- bind() of "), "{message}");
+    assert!(
+        message.contains(&format!("{}:{bind_line}:", file!())),
+        "{message}"
+    );
+
+    // Released guards are no longer reported.
+    drop(guard);
+    let (_guard, second_bind_line) = (obj.bind(), line!());
+
+    let mut copy = obj.clone();
+    let message = expect_panic_message("bind_mut() while bound again", move || {
+        let _ = copy.bind_mut();
+    });
+
+    assert!(
+        message.contains(&format!(":{second_bind_line}:")),
+        "{message}"
+    );
+    assert!(!message.contains(&format!(":{bind_line}:")), "{message}");
+}
+
 #[itest(skip)] // This deliberately crashes the engine. Un-skip to manually test this.
 fn object_user_dynamic_free_during_bind() {
     // Note: we could also test if GDScript can access free() when an object is bound, to check whether the panic is handled or crashes
@@ -965,11 +999,108 @@ fn double_use_reference() {
     emitter.free();
 }
 
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct ReentrantEmitter {
+    #[base]
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl ReentrantEmitter {
+    #[signal]
+    fn reentered();
+
+    #[func]
+    fn emit_reentered(&mut self) {
+        self.base.emit_signal("reentered".into(), &[]);
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct ReentrantReceiver {
+    emitter: Option<Gd<ReentrantEmitter>>,
+    message: String,
+}
+
+#[godot_api]
+impl ReentrantReceiver {
+    #[func]
+    fn on_reentered(&mut self) {
+        let mut emitter = self.emitter.clone().unwrap();
+        self.message = expect_panic_message("bind_mut() during #[func] call", move || {
+            let _ = emitter.bind_mut();
+        });
+    }
+}
+
+/// Tests that a borrow conflict caused by re-entrancy names the `#[func]` holding the first borrow.
+#[itest]
+fn object_user_bind_conflict_reports_func() {
+    let emitter = ReentrantEmitter::alloc_gd();
+    let mut receiver = ReentrantReceiver::alloc_gd();
+    receiver.bind_mut().emitter = Some(emitter.clone());
+
+    let mut emitter_obj = emitter.clone().upcast::<Object>();
+    emitter_obj.connect("reentered".into(), receiver.callable("on_reentered"));
+    emitter_obj.call("emit_reentered".into(), &[]);
+
+    let message = std::mem::take(&mut receiver.bind_mut().message);
+    assert!(message.contains("already bound"), "{message}");
+    if cfg!(debug_assertions) {
+        assert!(
+            message.contains("- bind_mut() of ") && message.contains("for `emit_reentered`"),
+            "{message}"
+        );
+        assert!(message.contains("current thread"), "{message}");
+    }
+
+    emitter.free();
+    receiver.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 // There isn't a good way to test editor plugins, but we can at least declare one to ensure that the macro
 // compiles.
 #[cfg(since_api = "4.1")]
 #[derive(GodotClass)]
-#[class(base = EditorPlugin, editor_plugin)]
+#[class(base = godot::engine::EditorPlugin, editor_plugin)]
 struct CustomEditorPlugin;
+
+// Same for a plugin that adds an inspector plugin with a custom property editor.
+#[cfg(since_api = "4.1")]
+#[derive(GodotClass)]
+#[class(tool, init, base = godot::engine::EditorPlugin, editor_plugin)]
+struct InspectingEditorPlugin {
+    additions: godot::editor::EditorAdditions,
+    #[base]
+    base: Base<godot::engine::EditorPlugin>,
+}
+
+#[cfg(since_api = "4.1")]
+#[godot_api]
+impl godot::engine::IEditorPlugin for InspectingEditorPlugin {
+    fn enter_tree(&mut self) {
+        self.additions
+            .add_inspector_plugin(&mut self.base, CustomInspectorPlugin::new_gd());
+    }
+
+    fn exit_tree(&mut self) {
+        self.additions.remove_all(&mut self.base);
+    }
+}
+
+#[cfg(since_api = "4.1")]
+#[derive(GodotClass)]
+#[class(tool, init, base = godot::engine::EditorInspectorPlugin)]
+struct CustomInspectorPlugin;
+
+#[cfg(since_api = "4.1")]
+#[godot_api]
+impl godot::engine::IEditorInspectorPlugin for CustomInspectorPlugin {
+    fn can_handle(&self, object: Gd<Object>) -> bool {
+        object.is_class("Node2D".into())
+    }
+}
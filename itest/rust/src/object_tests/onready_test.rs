@@ -0,0 +1,249 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::{expect_panic, expect_panic_message, itest, TestContext};
+use godot::engine::INode;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnReadyWithReady {
+    #[init(node = "Child")]
+    child: OnReady<Gd<Node>>,
+
+    #[init(default = OnReady::new(|| 42))]
+    auto: OnReady<i32>,
+
+    manual: OnReady<i32>,
+
+    seen_in_ready: Option<(StringName, i32)>,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for OnReadyWithReady {
+    fn ready(&mut self) {
+        self.seen_in_ready = Some((self.child.get_name(), *self.auto));
+        self.manual.init(7);
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnReadyWithoutReady {
+    #[init(node = "Child")]
+    child: OnReady<Gd<Node>>,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for OnReadyWithoutReady {}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnReadyReadsSelf {
+    #[init(default = 5)]
+    level: i32,
+
+    // The initializer binds the object, which must not be borrowed while it runs.
+    #[init(default = OnReady::from_base_fn(|base| base.clone().cast::<OnReadyReadsSelf>().bind().level * 10))]
+    score: OnReady<i32>,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for OnReadyReadsSelf {}
+
+// No #[godot_api] impl at all; ready() is provided by the derive.
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnReadyWithoutInterface {
+    #[init(default = OnReady::from_base_fn(|base| base.get_child_count()))]
+    child_count: OnReady<i32>,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnReadyPanicking {
+    #[init(default = OnReady::new(|| 1))]
+    first: OnReady<i32>,
+
+    #[init(default = OnReady::new(|| -> i32 { panic!("OnReady initializer failed on purpose") }))]
+    failing: OnReady<i32>,
+
+    #[init(default = OnReady::new(|| 3))]
+    last: OnReady<i32>,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for OnReadyPanicking {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+fn add_child_named(parent: &mut Gd<Node>, name: &str) {
+    let mut child = Node::new_alloc();
+    child.set_name(name.into());
+    parent.add_child(child);
+}
+
+#[itest]
+fn onready_initialized_before_ready(test_context: &TestContext) {
+    let obj = OnReadyWithReady::alloc_gd();
+    let mut node = obj.clone().upcast::<Node>();
+    add_child_named(&mut node, "Child");
+
+    assert!(!obj.bind().child.is_initialized());
+    assert!(!obj.bind().auto.is_initialized());
+
+    let mut tree = test_context.scene_tree.clone();
+    tree.add_child(node.clone());
+
+    {
+        let guard = obj.bind();
+        assert_eq!(guard.seen_in_ready, Some(("Child".into(), 42)));
+        assert_eq!(guard.child.get_name(), StringName::from("Child"));
+        assert_eq!(*guard.manual, 7);
+    }
+
+    tree.remove_child(node.clone());
+    node.free();
+}
+
+#[itest]
+fn onready_without_user_ready(test_context: &TestContext) {
+    let obj = OnReadyWithoutReady::alloc_gd();
+    let mut node = obj.clone().upcast::<Node>();
+    add_child_named(&mut node, "Child");
+
+    let mut tree = test_context.scene_tree.clone();
+    tree.add_child(node.clone());
+
+    assert_eq!(obj.bind().child.get_name(), StringName::from("Child"));
+
+    tree.remove_child(node.clone());
+    node.free();
+}
+
+#[itest]
+fn onready_without_interface_impl(test_context: &TestContext) {
+    let obj = OnReadyWithoutInterface::alloc_gd();
+    let mut node = obj.clone().upcast::<Node>();
+    add_child_named(&mut node, "Child");
+
+    let mut tree = test_context.scene_tree.clone();
+    tree.add_child(node.clone());
+
+    assert_eq!(*obj.bind().child_count, 1);
+
+    tree.remove_child(node.clone());
+    node.free();
+}
+
+#[itest]
+fn onready_initializer_panics(test_context: &TestContext) {
+    let obj = OnReadyPanicking::alloc_gd();
+    let node = obj.clone().upcast::<Node>();
+
+    // The panic is caught and reported by the ready() callback.
+    let mut tree = test_context.scene_tree.clone();
+    tree.add_child(node.clone());
+
+    {
+        let guard = obj.bind();
+        assert_eq!(*guard.first, 1);
+        assert_eq!(format!("{:?}", guard.failing), "OnReady(failed)");
+        assert_eq!(format!("{:?}", guard.last), "OnReady(pending)");
+    }
+
+    expect_panic("deref OnReady with panicked initializer", || {
+        let _unused: i32 = *obj.bind().failing;
+    });
+
+    tree.remove_child(node.clone());
+    node.free();
+}
+
+#[itest]
+fn onready_initializer_accesses_object(test_context: &TestContext) {
+    let obj = OnReadyReadsSelf::alloc_gd();
+    let node = obj.clone().upcast::<Node>();
+
+    let mut tree = test_context.scene_tree.clone();
+    tree.add_child(node.clone());
+
+    assert_eq!(*obj.bind().score, 50);
+
+    tree.remove_child(node.clone());
+    node.free();
+}
+
+#[itest]
+fn onready_missing_node() {
+    let obj = OnReadyWithoutReady::alloc_gd();
+    let instance_id = obj.instance_id();
+
+    let message = expect_panic_message("OnReady node at missing path", move || {
+        let base = Gd::<Node>::from_instance_id(instance_id);
+        let mut value = OnReady::<Gd<Node2D>>::node("Missing/Child");
+        value.init_auto(&base);
+    });
+
+    assert_eq!(
+        message,
+        "OnReady: class `OnReadyWithoutReady` has no child node of type `Node2D` at path `Missing/Child`"
+    );
+
+    obj.free();
+}
+
+#[itest]
+fn onready_manual() {
+    let mut value = OnReady::<i32>::manual();
+    assert!(!value.is_initialized());
+
+    value.init(3);
+    *value += 1;
+    assert_eq!(*value, 4);
+
+    expect_panic("OnReady::init() twice", || {
+        let mut value = OnReady::manual();
+        value.init(3);
+        value.init(5);
+    });
+}
+
+#[itest]
+fn onready_access_before_init() {
+    expect_panic("deref uninitialized OnReady", || {
+        let value = OnReady::new(|| 1);
+        let _unused: i32 = *value;
+    });
+}
+
+#[itest]
+fn onready_init_auto_once() {
+    let node = Node::new_alloc();
+    let mut value = OnReady::from_base_fn(|base| base.get_class());
+
+    // Second call has no effect.
+    value.init_auto(&node);
+    value.init_auto(&node);
+    assert_eq!(*value, GString::from("Node"));
+
+    node.free();
+}
@@ -452,6 +452,32 @@ fn export_resource() {
     class.free();
 }
 
+#[itest]
+fn engine_property_names() {
+    use godot::engine::node_2d::properties;
+
+    let mut node = Node2D::new_alloc();
+
+    node.set_property(properties::POSITION, Vector2::new(1.0, 2.0));
+    assert_eq!(node.get_position(), Vector2::new(1.0, 2.0));
+    assert_eq!(
+        node.get_property(properties::POSITION),
+        Vector2::new(1.0, 2.0)
+    );
+
+    // Inherited from Node, through CanvasItem.
+    node.set_property(properties::NAME, StringName::from("Renamed"));
+    assert_eq!(node.get_name(), StringName::from("Renamed"));
+
+    assert_eq!(properties::POSITION.as_str(), "position");
+    assert_eq!(
+        properties::POSITION.to_node_path(),
+        NodePath::from("position")
+    );
+
+    node.free();
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
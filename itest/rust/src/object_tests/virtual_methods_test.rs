@@ -20,7 +20,7 @@ use godot::engine::resource_loader::CacheMode;
 use godot::engine::{
     BoxMesh, INode, INode2D, IPrimitiveMesh, IRefCounted, IResourceFormatLoader, IRigidBody2D,
     InputEvent, InputEventAction, Node, Node2D, PrimitiveMesh, RefCounted, ResourceFormatLoader,
-    ResourceLoader, Viewport, Window,
+    ResourceLoader, RigidBody2D, Viewport, Window,
 };
 use godot::obj::{Base, Gd, UserClass};
 use godot::private::class_macros::assert_eq_approx;
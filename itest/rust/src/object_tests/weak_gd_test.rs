@@ -0,0 +1,95 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::{expect_panic, itest};
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct WeakParent {
+    child: Option<Gd<WeakChild>>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct WeakChild {
+    parent: Option<WeakGd<WeakParent>>,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[itest]
+fn weak_gd_refcounted() {
+    let obj = RefCounted::new();
+    let weak = obj.downgrade();
+    assert_eq!(obj.get_reference_count(), 1);
+    assert_eq!(weak.instance_id(), obj.instance_id());
+
+    let upgraded = weak.upgrade().expect("object is alive");
+    assert_eq!(upgraded, obj);
+    assert_eq!(obj.get_reference_count(), 2);
+
+    drop(upgraded);
+    drop(obj);
+    assert!(weak.upgrade().is_none());
+    assert!(!weak.is_alive());
+}
+
+#[itest]
+fn weak_gd_manual() {
+    let node = Node::new_alloc();
+    let weak = node.downgrade();
+    assert!(weak.is_alive());
+    assert_eq!(weak.upgrade(), Some(node.clone()));
+
+    node.free();
+    assert!(weak.upgrade().is_none());
+}
+
+#[itest]
+fn weak_gd_from_instance_id_type_check() {
+    let node = Node3D::new_alloc();
+    let id = node.instance_id();
+
+    // Base and own classes are compatible, unrelated classes are not.
+    assert!(WeakGd::<Node>::from_instance_id(id).upgrade().is_some());
+    assert!(WeakGd::<Node3D>::from_instance_id(id).upgrade().is_some());
+    assert!(WeakGd::<Node2D>::from_instance_id(id).upgrade().is_none());
+    assert!(WeakGd::<RefCounted>::from_instance_id(id)
+        .upgrade()
+        .is_none());
+
+    node.free();
+    assert!(WeakGd::<Node>::from_instance_id(id).upgrade().is_none());
+}
+
+#[itest]
+fn weak_gd_breaks_cycle() {
+    let mut parent = WeakParent::new_gd();
+    let mut child = WeakChild::new_gd();
+
+    parent.bind_mut().child = Some(child.clone());
+    child.bind_mut().parent = Some(parent.downgrade());
+
+    let back_pointer = child.bind().parent.unwrap();
+    assert_eq!(back_pointer.upgrade(), Some(parent.clone()));
+
+    // The child's back-pointer does not keep the parent alive.
+    drop(parent);
+    assert!(back_pointer.upgrade().is_none());
+    assert_eq!(child.get_reference_count(), 1);
+}
+
+#[itest]
+fn weak_gd_downgrade_dead() {
+    let node = Node::new_alloc();
+    let dead = node.clone();
+    node.free();
+
+    expect_panic("downgrade() on dead object", move || {
+        let _ = dead.downgrade();
+    });
+}
@@ -0,0 +1,221 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builder::ClassBuilder;
+use godot::engine::global::PropertyHint;
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+/// Shape of the class, as it could be read from a data file.
+const FIELDS: [(&str, VariantType); 2] =
+    [("health", VariantType::Int), ("title", VariantType::String)];
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct BuilderClass {
+    fields: Dictionary,
+    #[base]
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl IRefCounted for BuilderClass {
+    fn register_class(builder: &mut ClassBuilder<Self>) {
+        for (name, variant_type) in FIELDS {
+            let getter = format!("get_{name}");
+            let setter = format!("set_{name}");
+
+            builder
+                .method(&getter, move |this: &Self, _args: &[Variant]| {
+                    this.fields.get(name).unwrap_or_default()
+                })
+                .returns(variant_type)
+                .done();
+
+            builder
+                .method_mut(&setter, move |this: &mut Self, args: &[Variant]| {
+                    this.fields.set(name, args[0].clone());
+                    Variant::nil()
+                })
+                .param("value", variant_type)
+                .done();
+
+            builder
+                .property(name, variant_type)
+                .getter(&getter)
+                .setter(&setter)
+                .hint(PropertyHint::PROPERTY_HINT_NONE, "")
+                .done();
+        }
+
+        builder
+            .method("add", |_this: &Self, args: &[Variant]| {
+                (args[0].to::<i64>() + args[1].to::<i64>()).to_variant()
+            })
+            .param("a", VariantType::Int)
+            .param_with_default("b", VariantType::Int, 10.to_variant())
+            .returns(VariantType::Int)
+            .done();
+
+        builder
+            .method("half", |_this: &Self, args: &[Variant]| {
+                (args[0].to::<f64>() / 2.0).to_variant()
+            })
+            .param("value", VariantType::Float)
+            .returns(VariantType::Float)
+            .done();
+
+        builder
+            .method("count_args", |_this: &Self, args: &[Variant]| {
+                (args.len() as i64).to_variant()
+            })
+            .param("first", VariantType::Nil)
+            .varargs()
+            .done();
+
+        builder
+            .signal("field_changed")
+            .param("name", VariantType::String)
+            .done();
+
+        builder
+            .constant("MAX_FIELDS", 16)
+            .enum_constants("Mode", &[("MODE_READ", 0), ("MODE_WRITE", 1)]);
+    }
+}
+
+#[itest]
+fn class_builder_methods() {
+    let mut obj = BuilderClass::new_gd().upcast::<Object>();
+
+    assert_eq!(obj.call("add".into(), &[2.to_variant()]), 12.to_variant());
+    assert_eq!(
+        obj.call("add".into(), &[2.to_variant(), 3.to_variant()]),
+        5.to_variant()
+    );
+    assert_eq!(
+        obj.call(
+            "count_args".into(),
+            &[Variant::nil(), "a".to_variant(), 3.to_variant()]
+        ),
+        3.to_variant()
+    );
+}
+
+#[itest]
+fn class_builder_method_converts_args() {
+    let mut obj = BuilderClass::new_gd().upcast::<Object>();
+
+    // Like for typed parameters in GDScript, an int is accepted where a float is declared.
+    assert_eq!(obj.call("half".into(), &[3.to_variant()]), 1.5.to_variant());
+    assert_eq!(
+        obj.call("half".into(), &[3.0.to_variant()]),
+        1.5.to_variant()
+    );
+}
+
+#[itest]
+fn class_builder_properties() {
+    let obj = BuilderClass::new_gd();
+    let mut object = obj.clone().upcast::<Object>();
+
+    object.set("health".into(), 80.to_variant());
+    object.set("title".into(), "Knight".to_variant());
+
+    assert_eq!(object.get("health".into()), 80.to_variant());
+    assert_eq!(object.get("title".into()), "Knight".to_variant());
+    assert_eq!(obj.bind().fields.len(), 2);
+}
+
+#[itest]
+fn class_builder_method_shared_borrow() {
+    let obj = BuilderClass::new_gd();
+    let mut object = obj.clone().upcast::<Object>();
+    object.set("health".into(), 30.to_variant());
+
+    // Like a `&self` #[func], a method declared with `method()` can be called while the instance is borrowed, e.g. re-entrantly.
+    let _guard = obj.bind();
+    assert_eq!(object.call("get_health".into(), &[]), 30.to_variant());
+}
+
+#[itest]
+fn class_builder_signals_and_constants() {
+    let obj = BuilderClass::new_gd().upcast::<Object>();
+    assert!(obj.has_signal("field_changed".into()));
+
+    let class_name = BuilderClass::class_name().to_string_name();
+    let class_db = ClassDb::singleton();
+
+    assert_eq!(
+        class_db.class_get_integer_constant(class_name.clone(), "MAX_FIELDS".into()),
+        16
+    );
+    assert_eq!(
+        class_db.class_get_integer_constant(class_name.clone(), "MODE_WRITE".into()),
+        1
+    );
+    assert!(class_db.class_has_enum(class_name, "Mode".into()));
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct BuilderVirtualNode {
+    ready_calls: u32,
+    #[base]
+    base: Base<Node>,
+}
+
+#[cfg(since_api = "4.2")]
+#[godot_api]
+impl INode for BuilderVirtualNode {
+    fn register_class(builder: &mut ClassBuilder<Self>) {
+        builder.virtual_method("_ready", |this: &mut Self| {
+            this.ready_calls += 1;
+        });
+    }
+}
+
+/// Inherits the builder-declared virtual method of its Rust base class.
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base=BuilderVirtualNode)]
+struct BuilderVirtualChild {
+    #[base]
+    base: Base<BuilderVirtualNode>,
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn class_builder_virtual_method() {
+    use godot::engine::notify::NodeNotification;
+
+    let obj = BuilderVirtualNode::alloc_gd();
+    let mut node = obj.clone().upcast::<Node>();
+
+    node.notify(NodeNotification::Ready);
+    assert_eq!(obj.bind().ready_calls, 1);
+
+    node.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn class_builder_virtual_method_inherited() {
+    use godot::engine::notify::NodeNotification;
+
+    let obj = BuilderVirtualChild::alloc_gd();
+    let mut node = obj.clone().upcast::<Node>();
+
+    node.notify(NodeNotification::Ready);
+    assert_eq!(obj.upcast::<BuilderVirtualNode>().bind().ready_calls, 1);
+
+    node.free();
+}
@@ -0,0 +1,51 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, is_abstract, base=Node)]
+struct AbstractEnemy {
+    #[base]
+    base: Base<Node>,
+}
+
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, hidden, icon = "res://icons/enemy.svg", base=RefCounted)]
+struct HiddenHelper {
+    #[base]
+    base: Base<RefCounted>,
+}
+
+#[itest]
+fn class_metadata_abstract() {
+    let class_db = ClassDb::singleton();
+    let class_name = AbstractEnemy::class_name().to_string_name();
+
+    assert!(class_db.class_exists(class_name.clone()));
+    assert!(!class_db.can_instantiate(class_name));
+
+    // Rust code can still create instances, e.g. for subclasses that are constructed manually.
+    let obj = AbstractEnemy::alloc_gd();
+    obj.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn class_metadata_hidden() {
+    let class_db = ClassDb::singleton();
+    let class_name = HiddenHelper::class_name().to_string_name();
+
+    // Hidden classes are registered as usual, only not listed in the editor.
+    assert!(class_db.class_exists(class_name.clone()));
+    assert!(class_db.can_instantiate(class_name));
+
+    let obj = HiddenHelper::new_gd();
+    assert_eq!(obj.get_class(), GString::from("HiddenHelper"));
+}
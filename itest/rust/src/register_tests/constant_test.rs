@@ -171,6 +171,7 @@ godot::sys::plugin_add!(
             generated_register_fn: ::godot::private::ErasedRegisterFn {
                 raw: ::godot::private::callbacks::register_user_binds::<HasOtherConstants>,
             },
+            docs: None,
         },
         init_level: HasOtherConstants::INIT_LEVEL,
     }
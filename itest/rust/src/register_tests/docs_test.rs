@@ -0,0 +1,114 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::prelude::*;
+
+/// Class with **documented** members.
+///
+/// See [`Gd`] and [the book](https://godot-rust.github.io/book).
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DocumentedClass {
+    /// Current `health`, between 0 and 100.
+    #[var]
+    health: i64,
+
+    #[export]
+    undocumented: f32,
+
+    // Not a property, thus not documented in Godot.
+    #[allow(dead_code)]
+    hidden: bool,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl DocumentedClass {
+    /// Heals by `amount` & returns the new health.
+    #[func]
+    fn heal(&mut self, amount: i64) -> i64 {
+        self.health += amount;
+        self.health
+    }
+
+    /// Adds `child` below this node.
+    #[func]
+    fn adopt(&mut self, child: Gd<Node>) {
+        self.base.add_child(child);
+    }
+
+    /// Emitted when health drops to <= 0.
+    #[signal]
+    fn died();
+
+    /// Maximum health.
+    #[constant]
+    const MAX_HEALTH: i64 = 100;
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct UndocumentedClass {
+    #[var]
+    value: i64,
+}
+
+#[godot_api]
+impl UndocumentedClass {
+    #[func]
+    fn value(&self) -> i64 {
+        self.value
+    }
+}
+
+fn find_class_xml(class_name: &str) -> Option<String> {
+    let header = format!(r#"<class name="{class_name}" "#);
+
+    godot::private::gather_xml_docs()
+        .into_iter()
+        .find(|xml| xml.contains(&header))
+}
+
+#[itest]
+fn docs_class_xml() {
+    let xml = find_class_xml("DocumentedClass").expect("docs registered for DocumentedClass");
+
+    assert!(xml.contains(r#"inherits="Node""#));
+    assert!(xml
+        .contains("<brief_description>Class with [b]documented[/b] members.</brief_description>"));
+    assert!(xml.contains(
+        "<description>See [code]Gd[/code] and [url=https://godot-rust.github.io/book]the book[/url].</description>"
+    ));
+    assert!(xml.contains(
+        r#"<member name="health" type="int">Current [code]health[/code], between 0 and 100.</member>"#
+    ));
+    assert!(xml.contains(r#"<member name="undocumented" type="float"></member>"#));
+    assert!(!xml.contains(r#"name="hidden""#));
+}
+
+#[itest]
+fn docs_impl_xml() {
+    let xml = find_class_xml("DocumentedClass").expect("docs registered for DocumentedClass");
+
+    assert!(xml.contains(
+        r#"<method name="heal"><return type="int" /><param index="0" name="amount" type="int" /><description>Heals by [code]amount[/code] &amp; returns the new health.</description></method>"#
+    ));
+    assert!(xml.contains(
+        r#"<method name="adopt"><return type="void" /><param index="0" name="child" type="Node" /><description>Adds [code]child[/code] below this node.</description></method>"#
+    ));
+    assert!(xml.contains(
+        r#"<signal name="died"><description>Emitted when health drops to &lt;= 0.</description></signal>"#
+    ));
+    assert!(xml.contains(r#"<constant name="MAX_HEALTH" value="100">Maximum health.</constant>"#));
+}
+
+#[itest]
+fn docs_skip_undocumented_class() {
+    assert!(find_class_xml("UndocumentedClass").is_none());
+}
@@ -249,6 +249,100 @@ impl IRefCounted for GdSelfReference {
     }
 }
 
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncDefaults;
+
+#[godot_api]
+impl FuncDefaults {
+    #[func]
+    fn spawn(
+        &self,
+        count: i32,
+        #[opt(default = 1.5)] speed: f32,
+        #[opt(default = GString::from("enemy"))] kind: GString,
+    ) -> GString {
+        format!("{count} {kind} at {speed}").into()
+    }
+
+    #[func(gd_self)]
+    fn spawn_gd_self(_this: Gd<Self>, #[opt(default = 7)] count: i32) -> i32 {
+        count
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncVarargs;
+
+#[godot_api]
+impl FuncVarargs {
+    #[func]
+    fn join_all(&self, separator: GString, args: &[Variant]) -> GString {
+        let parts: Vec<String> = args.iter().map(Variant::to_string).collect();
+        parts.join(&separator.to_string()).into()
+    }
+
+    #[func]
+    fn count_varargs(args: &[Variant]) -> i64 {
+        args.len() as i64
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncResults;
+
+#[godot_api]
+impl FuncResults {
+    #[func]
+    fn parse_int(&self, text: GString) -> Result<i64, std::num::ParseIntError> {
+        text.to_string().parse()
+    }
+
+    #[func(on_error = error_code)]
+    fn parse_int_or_code(text: GString) -> Result<i64, std::num::ParseIntError> {
+        text.to_string().parse()
+    }
+
+    #[func]
+    fn check_positive(&self, number: i64) -> Result<(), String> {
+        if number > 0 {
+            Ok(())
+        } else {
+            Err(format!("{number} is not positive"))
+        }
+    }
+
+    #[func]
+    fn load_level(&self, text: GString) -> Result<i64, LevelError> {
+        text.to_string()
+            .parse()
+            .map_err(|cause| LevelError { cause })
+    }
+}
+
+fn error_code(_err: &std::num::ParseIntError) -> Variant {
+    (-1).to_variant()
+}
+
+#[derive(Debug)]
+struct LevelError {
+    cause: std::num::ParseIntError,
+}
+
+impl std::fmt::Display for LevelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid level number")
+    }
+}
+
+impl std::error::Error for LevelError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.cause)
+    }
+}
+
 /// Checks at runtime if a class has a given method through [ClassDb].
 fn class_has_method<T: GodotClass>(name: &str) -> bool {
     ClassDb::singleton()
@@ -303,3 +397,109 @@ fn cfg_removes_or_keeps_signals() {
     ));
     assert!(!class_has_signal::<GdSelfReference>("cfg_removes_signal"));
 }
+
+#[itest]
+fn func_default_args() {
+    let mut object = Gd::from_object(FuncDefaults).upcast::<RefCounted>();
+
+    let all = object.call(
+        "spawn".into(),
+        &[3.to_variant(), 2.0.to_variant(), "boss".to_variant()],
+    );
+    assert_eq!(all, "3 boss at 2".to_variant());
+
+    let one_default = object.call("spawn".into(), &[3.to_variant(), 2.0.to_variant()]);
+    assert_eq!(one_default, "3 enemy at 2".to_variant());
+
+    let all_defaults = object.call("spawn".into(), &[3.to_variant()]);
+    assert_eq!(all_defaults, "3 enemy at 1.5".to_variant());
+
+    let gd_self = object.call("spawn_gd_self".into(), &[]);
+    assert_eq!(gd_self, 7.to_variant());
+}
+
+#[itest]
+fn func_default_args_registered() {
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(FuncDefaults::class_name().to_string_name())
+        .no_inheritance(true)
+        .done();
+
+    let spawn = methods
+        .iter_shared()
+        .find(|method| method.get("name") == Some("spawn".to_variant()))
+        .expect("method `spawn` registered");
+
+    let default_args = spawn.get("default_args").expect("default_args key");
+    assert_eq!(default_args, varray![1.5, "enemy"].to_variant());
+}
+
+#[itest]
+fn func_varargs() {
+    let mut object = Gd::from_object(FuncVarargs).upcast::<RefCounted>();
+
+    let joined = object.call(
+        "join_all".into(),
+        &[
+            "-".to_variant(),
+            1.to_variant(),
+            "a".to_variant(),
+            2.5.to_variant(),
+        ],
+    );
+    assert_eq!(joined, "1-a-2.5".to_variant());
+
+    let no_varargs = object.call("join_all".into(), &["-".to_variant()]);
+    assert_eq!(no_varargs, "".to_variant());
+
+    let count = object.call("count_varargs".into(), &[1.to_variant(), 2.to_variant()]);
+    assert_eq!(count, 2.to_variant());
+}
+
+#[itest]
+fn func_result_ok() {
+    let mut object = Gd::from_object(FuncResults).upcast::<RefCounted>();
+
+    let parsed = object.call("parse_int".into(), &["42".to_variant()]);
+    assert_eq!(parsed, 42.to_variant());
+
+    let parsed = object.call("parse_int_or_code".into(), &["7".to_variant()]);
+    assert_eq!(parsed, 7.to_variant());
+
+    let checked = object.call("check_positive".into(), &[1.to_variant()]);
+    assert_eq!(checked, Variant::nil());
+}
+
+#[itest]
+fn func_result_err() {
+    let mut object = Gd::from_object(FuncResults).upcast::<RefCounted>();
+
+    // Errors are reported as script errors, and the caller receives nil or the mapped value.
+    let parsed = object.call("parse_int".into(), &["not a number".to_variant()]);
+    assert_eq!(parsed, Variant::nil());
+
+    let parsed = object.call("parse_int_or_code".into(), &["not a number".to_variant()]);
+    assert_eq!(parsed, (-1).to_variant());
+
+    let checked = object.call("check_positive".into(), &[(-5).to_variant()]);
+    assert_eq!(checked, Variant::nil());
+
+    let level = object.call("load_level".into(), &["x".to_variant()]);
+    assert_eq!(level, Variant::nil());
+}
+
+#[itest]
+fn func_result_error_chain() {
+    use godot::private::{FuncError, FuncErrorChain as _, FuncErrorDisplay as _};
+
+    // Same dispatch as generated for #[func] methods returning Result.
+    let cause = "x".parse::<i64>().unwrap_err();
+    let nested = LevelError { cause };
+    assert_eq!(
+        (&&FuncError(&nested)).describe(),
+        "invalid level number\n  caused by: invalid digit found in string"
+    );
+
+    let plain = String::from("not positive");
+    assert_eq!((&&FuncError(&plain)).describe(), "not positive");
+}
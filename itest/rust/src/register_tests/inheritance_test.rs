@@ -0,0 +1,167 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::{itest, TestContext};
+use godot::engine::{ClassDb, INode};
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct InheritEnemy {
+    #[init(default = 100)]
+    health: i32,
+    ready_calls: i32,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl InheritEnemy {
+    #[func]
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+    }
+}
+
+#[godot_api]
+impl INode for InheritEnemy {
+    fn ready(&mut self) {
+        self.ready_calls += 1;
+    }
+
+    fn to_string(&self) -> GString {
+        format!("InheritEnemy(health={})", self.health).into()
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=InheritEnemy)]
+struct InheritBoss {
+    #[init(default = 1)]
+    phase: i32,
+
+    #[base]
+    base: Base<InheritEnemy>,
+}
+
+#[godot_api]
+impl InheritBoss {
+    #[func]
+    fn enrage(&mut self) {
+        self.phase += 1;
+        self.base.bind_mut().health += 50;
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[itest]
+fn inheritance_registered_parent() {
+    let class_db = ClassDb::singleton();
+    let parent = class_db.get_parent_class(InheritBoss::class_name().to_string_name());
+
+    assert_eq!(parent, InheritEnemy::class_name().to_string_name());
+}
+
+#[itest]
+fn inheritance_upcast_cast() {
+    let boss = InheritBoss::alloc_gd();
+    assert_eq!(boss.bind().phase, 1);
+
+    let enemy: Gd<InheritEnemy> = boss.clone().upcast();
+    assert_eq!(enemy.bind().health, 100);
+
+    let node: Gd<Node> = boss.clone().upcast();
+    assert!(node.clone().try_cast::<InheritEnemy>().is_some());
+
+    let boss_again = node.cast::<InheritBoss>();
+    assert_eq!(boss_again, boss);
+
+    // A plain enemy is not a boss.
+    let plain = InheritEnemy::alloc_gd();
+    let plain_node: Gd<Node> = plain.clone().upcast();
+    assert!(plain_node.try_cast::<InheritBoss>().is_none());
+
+    plain.free();
+    boss.free();
+}
+
+#[itest]
+fn inheritance_parent_state() {
+    let mut boss = InheritBoss::alloc_gd();
+
+    // Engine methods are reachable through Deref.
+    boss.set_name("Boss".into());
+    assert_eq!(boss.get_name(), StringName::from("Boss"));
+
+    boss.bind_mut().enrage();
+    assert_eq!(boss.bind().phase, 2);
+    assert_eq!(boss.clone().upcast::<InheritEnemy>().bind().health, 150);
+
+    boss.free();
+}
+
+#[itest]
+fn inheritance_parent_func() {
+    let boss = InheritBoss::alloc_gd();
+
+    let mut object = boss.clone().upcast::<Object>();
+    object.call("take_damage".into(), &[30.to_variant()]);
+    object.call("enrage".into(), &[]);
+
+    let enemy = boss.clone().upcast::<InheritEnemy>();
+    assert_eq!(enemy.bind().health, 120);
+    assert_eq!(boss.bind().phase, 2);
+
+    boss.free();
+}
+
+#[itest]
+fn inheritance_parent_virtuals(test_context: &TestContext) {
+    let boss = InheritBoss::alloc_gd();
+    assert_eq!(boss.to_string(), "InheritEnemy(health=100)");
+
+    let mut tree = test_context.scene_tree.clone();
+    tree.add_child(boss.clone().upcast());
+
+    let enemy = boss.clone().upcast::<InheritEnemy>();
+    assert_eq!(enemy.bind().ready_calls, 1);
+
+    tree.remove_child(boss.clone().upcast());
+    boss.free();
+}
+
+#[itest]
+fn inheritance_local_classes() {
+    // Classes declared inside a function resolve their Rust base like any other local item.
+    #[derive(GodotClass)]
+    #[class(init, base=Node)]
+    struct InheritLocalBase {
+        #[init(default = 7)]
+        value: i32,
+
+        #[base]
+        base: Base<Node>,
+    }
+
+    #[derive(GodotClass)]
+    #[class(init, base=InheritLocalBase)]
+    struct InheritLocalDerived {
+        #[base]
+        base: Base<InheritLocalBase>,
+    }
+
+    let derived = InheritLocalDerived::alloc_gd();
+
+    let parent = derived.clone().upcast::<InheritLocalBase>();
+    assert_eq!(parent.bind().value, 7);
+
+    let node: Gd<Node> = derived.clone().upcast();
+    assert!(node.try_cast::<InheritLocalDerived>().is_some());
+
+    derived.free();
+}
@@ -4,11 +4,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod class_builder_test;
+mod class_metadata_test;
 mod constant_test;
 mod derive_variant_test;
+mod docs_test;
 mod func_test;
 mod gdscript_ffi_test;
+mod inheritance_test;
 mod option_ffi_test;
+mod panic_report_test;
+mod project_settings_test;
 mod var_test;
 
 pub use gdscript_ffi_test::gen_ffi;
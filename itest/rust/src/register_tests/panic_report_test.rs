@@ -0,0 +1,112 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::{Arc, Mutex};
+
+use crate::framework::{itest, suppress_godot_print};
+use godot::panic_report::{self, PanicReport, PanicReportConfig};
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct PanickingObj {
+    #[base]
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl PanickingObj {
+    #[func]
+    fn fail(&self, count: i64, direction: GString) {
+        panic!("cannot move {count} steps {direction}");
+    }
+
+    #[func]
+    fn fail_static() {
+        panic!("static failure");
+    }
+}
+
+/// Calls `method` through Godot and returns the report of the resulting panic.
+fn capture_report(obj: &Gd<PanickingObj>, method: &str, args: VariantArray) -> PanicReport {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+
+    let sink = reports.clone();
+    panic_report::set_config(PanicReportConfig {
+        on_panic: Some(Arc::new(move |report: &PanicReport| {
+            sink.lock().unwrap().push(report.clone());
+        })),
+        ..Default::default()
+    });
+
+    let mut obj = obj.clone();
+    suppress_godot_print(|| {
+        obj.callv(StringName::from(method), args.clone());
+    });
+
+    panic_report::set_config(PanicReportConfig::default());
+
+    let mut reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 1, "exactly one panic should be reported");
+    reports.remove(0)
+}
+
+#[itest]
+fn panic_report_method() {
+    let obj = PanickingObj::new_gd();
+    let report = capture_report(&obj, "fail", varray![3, "up"]);
+
+    assert_eq!(report.message, "cannot move 3 steps up");
+    assert!(
+        report.file.ends_with("panic_report_test.rs"),
+        "{}",
+        report.file
+    );
+    assert_eq!(report.function_name(), "PanickingObj::fail");
+
+    let call = report.call.expect("call info");
+    assert_eq!(call.class_name, "PanickingObj");
+    assert_eq!(call.method_name, "fail");
+    assert_eq!(call.instance_id, Some(obj.instance_id()));
+    assert_eq!(call.args, Some(vec!["3".to_string(), "up".to_string()]));
+}
+
+#[itest]
+fn panic_report_static_method() {
+    let obj = PanickingObj::new_gd();
+    let report = capture_report(&obj, "fail_static", varray![]);
+
+    assert_eq!(report.message, "static failure");
+
+    let call = report.call.expect("call info");
+    assert_eq!(call.method_name, "fail_static");
+    assert_eq!(call.instance_id, None);
+}
+
+#[itest]
+fn panic_report_hook_can_reconfigure() {
+    let calls = Arc::new(Mutex::new(0));
+
+    let sink = calls.clone();
+    panic_report::set_config(PanicReportConfig {
+        on_panic: Some(Arc::new(move |_report: &PanicReport| {
+            *sink.lock().unwrap() += 1;
+
+            // Would deadlock if the configuration were still locked.
+            panic_report::set_config(PanicReportConfig::default());
+        })),
+        ..Default::default()
+    });
+
+    let mut obj = PanickingObj::new_gd();
+    suppress_godot_print(|| {
+        obj.callv(StringName::from("fail_static"), varray![]);
+        obj.callv(StringName::from("fail_static"), varray![]);
+    });
+
+    // The first panic's hook has removed itself.
+    assert_eq!(*calls.lock().unwrap(), 1);
+}
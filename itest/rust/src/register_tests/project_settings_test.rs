@@ -0,0 +1,115 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::global::PropertyHint;
+use godot::engine::ProjectSettings;
+use godot::prelude::*;
+use godot::settings::ProjectSettingsGroup;
+
+#[derive(ProjectSettings, PartialEq, Debug)]
+struct ItestSettings {
+    #[setting(path = "itest/settings/max_agents", default = 64, range = (1, 512))]
+    max_agents: i32,
+
+    #[setting(path = "itest/settings/speed", default = 2.5, range = (0.0, 10.0, 0.5, or_greater), basic)]
+    speed: f64,
+
+    #[setting(path = "itest/settings/title", restart_if_changed)]
+    title: GString,
+}
+
+const PATHS: [&str; 3] = [
+    "itest/settings/max_agents",
+    "itest/settings/speed",
+    "itest/settings/title",
+];
+
+fn clear_settings() {
+    let mut settings = ProjectSettings::singleton();
+    for path in PATHS {
+        // Assigning nil erases the setting.
+        settings.set_setting(path.into(), Variant::nil());
+    }
+}
+
+#[itest]
+fn project_settings_infos() {
+    let infos = ItestSettings::setting_infos();
+    assert_eq!(infos.len(), 3);
+
+    let max_agents = &infos[0];
+    assert_eq!(max_agents.path, "itest/settings/max_agents");
+    assert_eq!(max_agents.default_value, 64.to_variant());
+    assert_eq!(max_agents.hint_info.hint, PropertyHint::PROPERTY_HINT_RANGE);
+    assert_eq!(max_agents.hint_info.hint_string, GString::from("1,512"));
+    assert!(!max_agents.restart_if_changed);
+    assert!(!max_agents.basic);
+
+    let speed = &infos[1];
+    assert_eq!(
+        speed.hint_info.hint_string,
+        GString::from("0,10,0.5,or_greater")
+    );
+    assert!(speed.basic);
+
+    let title = &infos[2];
+    assert_eq!(title.default_value, GString::new().to_variant());
+    assert_eq!(title.hint_info.hint, PropertyHint::PROPERTY_HINT_NONE);
+    assert!(title.restart_if_changed);
+}
+
+#[itest]
+fn project_settings_defaults() {
+    clear_settings();
+
+    // Not yet registered: getters fall back to defaults.
+    assert_eq!(ItestSettings::max_agents(), 64);
+
+    ItestSettings::register();
+
+    let settings = ProjectSettings::singleton();
+    assert!(settings.has_setting("itest/settings/speed".into()));
+    assert_eq!(
+        settings.get_setting("itest/settings/speed".into()),
+        2.5.to_variant()
+    );
+
+    let loaded = ItestSettings::load();
+    assert_eq!(
+        loaded,
+        ItestSettings {
+            max_agents: 64,
+            speed: 2.5,
+            title: GString::new(),
+        }
+    );
+
+    clear_settings();
+}
+
+#[itest]
+fn project_settings_typed_access() {
+    clear_settings();
+    ItestSettings::register();
+
+    let mut settings = ProjectSettings::singleton();
+    settings.set_setting("itest/settings/max_agents".into(), 128.to_variant());
+    settings.set_setting("itest/settings/title".into(), "Agents".to_variant());
+
+    assert_eq!(ItestSettings::max_agents(), 128);
+    assert_eq!(ItestSettings::title(), GString::from("Agents"));
+
+    // Registering again keeps values that are already present.
+    ItestSettings::register();
+    assert_eq!(ItestSettings::max_agents(), 128);
+
+    // Values of another type fall back to the default.
+    settings.set_setting("itest/settings/max_agents".into(), "many".to_variant());
+    assert_eq!(ItestSettings::max_agents(), 64);
+
+    clear_settings();
+}